@@ -0,0 +1,21 @@
+//! The timing harness shared by the benches, which run without the unstable `test` crate.
+
+use std::hint::black_box;
+use std::time::Instant;
+
+/// Runs `f` into a cleared buffer `iters` times, and prints the mean time per run.
+pub fn time<F: FnMut(&mut String)>(name: &str, iters: u32, mut f: F) {
+    let mut buf = String::new();
+    let start = Instant::now();
+    for _ in 0..iters {
+        buf.clear();
+        f(&mut buf);
+        black_box(&buf);
+    }
+    let elapsed = start.elapsed();
+    println!(
+        "{:<24} {:>8.1} ns/iter",
+        name,
+        elapsed.as_nanos() as f64 / iters as f64
+    );
+}
@@ -0,0 +1,33 @@
+//! Compares rendering through a prebuilt [`FieldFormat`] against spelling out the decorations in
+//! a format string on every call.
+//!
+//! Run with `cargo bench --bench field_format`.
+
+mod common;
+
+use std::fmt::Write;
+use std::hint::black_box;
+
+use common::time;
+use fmtor::{FieldFormat, FmtOr};
+
+const ITERS: u32 = 1_000_000;
+
+fn main() {
+    let values = [Some(42u32), None, Some(7)];
+    let field = FieldFormat::new("count=", "; ");
+
+    time("render_or", ITERS, |buf| {
+        for opt in black_box(&values) {
+            write!(buf, "{}", field.render_or(opt, "-")).unwrap();
+        }
+    });
+    time("format_args", ITERS, |buf| {
+        for opt in black_box(&values) {
+            match opt {
+                Some(_) => write!(buf, "count={}; ", opt.fmt_or("-")).unwrap(),
+                None => write!(buf, "{}", opt.fmt_or("-")).unwrap(),
+            }
+        }
+    });
+}
@@ -0,0 +1,64 @@
+//! Compares the core wrappers against matching on the option by hand.
+//!
+//! Run with `cargo bench --bench fmt_or`.
+//!
+//! Every wrapper impl is already `#[inline]`, and with it the wrappers run within noise of the
+//! hand-written `match` for both present and missing values, with and without a width.
+//! `#[inline(always)]` made no measurable difference, so it isn't used.
+
+mod common;
+
+use std::fmt::Write;
+use std::hint::black_box;
+
+use common::time;
+use fmtor::FmtOr;
+
+const ITERS: u32 = 1_000_000;
+
+macro_rules! bench_spec {
+    ($label:literal, $values:expr, $spec:literal) => {
+        let values = $values;
+        time(concat!("by hand ", $label), ITERS, |buf| {
+            for opt in black_box(&values) {
+                match opt {
+                    Some(t) => write!(buf, $spec, t).unwrap(),
+                    None => write!(buf, $spec, black_box("x")).unwrap(),
+                }
+            }
+        });
+        time(concat!("fmt_or ", $label), ITERS, |buf| {
+            for opt in black_box(&values) {
+                write!(buf, $spec, opt.fmt_or("x")).unwrap();
+            }
+        });
+        time(concat!("fmt_or_else ", $label), ITERS, |buf| {
+            for opt in black_box(&values) {
+                write!(buf, $spec, opt.fmt_or_else(|| "x")).unwrap();
+            }
+        });
+        time(concat!("by hand empty ", $label), ITERS, |buf| {
+            for opt in black_box(&values) {
+                match opt {
+                    Some(t) => write!(buf, $spec, t).unwrap(),
+                    None => write!(buf, $spec, black_box("")).unwrap(),
+                }
+            }
+        });
+        time(concat!("fmt_or_empty ", $label), ITERS, |buf| {
+            for opt in black_box(&values) {
+                write!(buf, $spec, opt.fmt_or_empty()).unwrap();
+            }
+        });
+    };
+}
+
+fn main() {
+    let some = [Some(0x42u32), Some(7), Some(0xffff)];
+    let none = [None::<u32>; 3];
+
+    bench_spec!("Some {}", some, "{}");
+    bench_spec!("None {}", none, "{}");
+    bench_spec!("Some {:>8}", some, "{:>8}");
+    bench_spec!("None {:>8}", none, "{:>8}");
+}
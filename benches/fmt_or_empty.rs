@@ -0,0 +1,34 @@
+//! Compares `fmt_or_empty` on missing values with and without a width, against `fmt_or("")`.
+//!
+//! Run with `cargo bench --bench fmt_or_empty`.
+
+mod common;
+
+use std::fmt::Write;
+use std::hint::black_box;
+
+use common::time;
+use fmtor::FmtOr;
+
+const ITERS: u32 = 1_000_000;
+
+fn main() {
+    // A hot logging loop where most fields are missing.
+    let values = [None, None, Some(7u32), None, None, None];
+
+    time("fmt_or(\"\")", ITERS, |buf| {
+        for opt in black_box(&values) {
+            write!(buf, "{}", opt.fmt_or("")).unwrap();
+        }
+    });
+    time("fmt_or_empty", ITERS, |buf| {
+        for opt in black_box(&values) {
+            write!(buf, "{}", opt.fmt_or_empty()).unwrap();
+        }
+    });
+    time("fmt_or_empty {:4}", ITERS, |buf| {
+        for opt in black_box(&values) {
+            write!(buf, "{:4}", opt.fmt_or_empty()).unwrap();
+        }
+    });
+}
@@ -0,0 +1,48 @@
+//! Compares a shared, pre-rendered fallback against `fmt_or` with the same fallback.
+//!
+//! Run with `cargo bench --bench shared_fallback --features alloc`.
+
+mod common;
+
+use std::fmt::{self, Display, Formatter, Write};
+use std::hint::black_box;
+
+use common::time;
+use fmtor::{FmtOr, FmtOrShared, SharedFallback};
+
+const ITERS: u32 = 100_000;
+
+/// A fallback which takes several writes to render, like most real ones.
+struct Missing {
+    column: &'static str,
+    since: u64,
+}
+impl Display for Missing {
+    fn fmt(&self, out: &mut Formatter) -> fmt::Result {
+        write!(out, "<{} not reported since {}s>", self.column, self.since)
+    }
+}
+
+const MISSING: Missing = Missing {
+    column: "latency",
+    since: 86_400,
+};
+
+fn main() {
+    // A row of a mostly empty table.
+    let mut cells = [None; 64];
+    cells[5] = Some(42u32);
+    cells[40] = Some(7);
+
+    time("fmt_or", ITERS, |buf| {
+        for cell in black_box(&cells) {
+            write!(buf, "{}", cell.fmt_or(MISSING)).unwrap();
+        }
+    });
+    let shared = SharedFallback::new(MISSING);
+    time("fmt_or_shared", ITERS, |buf| {
+        for cell in black_box(&cells) {
+            write!(buf, "{}", cell.fmt_or_shared(&shared)).unwrap();
+        }
+    });
+}
@@ -0,0 +1,12 @@
+//! ANSI escape sequences and control characters used by the terminal adapters.
+
+/// Selects a red foreground.
+pub(crate) const RED: &str = "\x1b[31m";
+/// Selects a green foreground.
+pub(crate) const GREEN: &str = "\x1b[32m";
+/// Selects a yellow foreground.
+pub(crate) const YELLOW: &str = "\x1b[33m";
+/// Resets all attributes.
+pub(crate) const RESET: &str = "\x1b[0m";
+/// Rings the terminal bell.
+pub(crate) const BEL: char = '\x07';
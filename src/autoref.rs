@@ -0,0 +1,129 @@
+//! Choosing between [`Display`] and [`Debug`] by what the value implements.
+//!
+//! Without specialization, a generic method can't check whether `T: Display`. Instead, the
+//! [`fmt_display_or_debug_or!`] macro uses autoref-based specialization: method lookup tries the
+//! [`DisplayProbe`] impl on `&&Probe` first, and only finds the [`DebugProbe`] impl on `&Probe`
+//! after auto-deref if `T` isn't [`Display`]. This only works where the concrete type is known,
+//! which is why it has to be a macro rather than a method on [`FmtOr`].
+//!
+//! [`fmt_display_or_debug_or!`]: crate::fmt_display_or_debug_or
+//! [`FmtOr`]: crate::FmtOr
+
+#[cfg(test)]
+mod tests;
+
+use core::fmt::{Debug, Display, Formatter, Result};
+use core::marker::PhantomData;
+
+/// Selects [`Display`] for [`MaybeFormatDisplayOrDebug`].
+#[derive(Copy, Clone, Debug)]
+pub struct UseDisplay;
+/// Selects [`Debug`] for [`MaybeFormatDisplayOrDebug`].
+#[derive(Copy, Clone, Debug)]
+pub struct UseDebug;
+
+/// The type returned from [`fmt_display_or_debug_or!`]
+///
+/// `K` is [`UseDisplay`] or [`UseDebug`], chosen by the macro.
+///
+/// [`fmt_display_or_debug_or!`]: crate::fmt_display_or_debug_or
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatDisplayOrDebug<'t, T, U, K>(&'t Option<T>, U, PhantomData<K>);
+
+#[doc(hidden)]
+pub struct Probe<'t, T>(pub &'t Option<T>);
+
+#[doc(hidden)]
+pub trait DisplayProbe<'t, T> {
+    fn __fmtor_select<U: Display>(
+        &self,
+        fallback: U,
+    ) -> MaybeFormatDisplayOrDebug<'t, T, U, UseDisplay>;
+}
+
+impl<'t, T: Display> DisplayProbe<'t, T> for &Probe<'t, T> {
+    #[inline]
+    fn __fmtor_select<U: Display>(
+        &self,
+        fallback: U,
+    ) -> MaybeFormatDisplayOrDebug<'t, T, U, UseDisplay> {
+        MaybeFormatDisplayOrDebug(self.0, fallback, PhantomData)
+    }
+}
+
+#[doc(hidden)]
+pub trait DebugProbe<'t, T> {
+    fn __fmtor_select<U: Display>(
+        &self,
+        fallback: U,
+    ) -> MaybeFormatDisplayOrDebug<'t, T, U, UseDebug>;
+}
+
+impl<'t, T: Debug> DebugProbe<'t, T> for Probe<'t, T> {
+    #[inline]
+    fn __fmtor_select<U: Display>(
+        &self,
+        fallback: U,
+    ) -> MaybeFormatDisplayOrDebug<'t, T, U, UseDebug> {
+        MaybeFormatDisplayOrDebug(self.0, fallback, PhantomData)
+    }
+}
+
+/// Format an option's value with [`Display`] if it implements it, or with [`Debug`] otherwise,
+/// or display the given value instead.
+///
+/// This expands to a [`MaybeFormatDisplayOrDebug`], which implements [`Display`]. The choice is
+/// made from the concrete type of the option at the call site, so inside a generic function
+/// over `T` it is made from the bounds on `T`. The option must be a place such as a variable or
+/// field, since the wrapper borrows it. The fallback is formatted with [`Display`] either way.
+///
+/// # Example
+///
+/// ```rust
+/// use fmtor::fmt_display_or_debug_or;
+///
+/// #[derive(Debug)]
+/// struct Point(i32, i32);
+///
+/// let name = Some("origin");
+/// let point = Some(Point(0, 0));
+///
+/// assert_eq!("origin", format!("{}", fmt_display_or_debug_or!(name, "-")));
+/// assert_eq!("Point(0, 0)", format!("{}", fmt_display_or_debug_or!(point, "-")));
+/// ```
+#[macro_export]
+macro_rules! fmt_display_or_debug_or {
+    ($opt:expr, $fallback:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::__private::{DebugProbe as _, DisplayProbe as _};
+        (&&$crate::__private::Probe(&$opt)).__fmtor_select($fallback)
+    }};
+}
+
+impl<'t, T, U> Display for MaybeFormatDisplayOrDebug<'t, T, U, UseDisplay>
+where
+    T: Display,
+    U: Display,
+{
+    #[inline]
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) => Display::fmt(t, out),
+            None => Display::fmt(&self.1, out),
+        }
+    }
+}
+
+impl<'t, T, U> Display for MaybeFormatDisplayOrDebug<'t, T, U, UseDebug>
+where
+    T: Debug,
+    U: Display,
+{
+    #[inline]
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) => Debug::fmt(t, out),
+            None => Display::fmt(&self.1, out),
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use crate::fmt_display_or_debug_or;
+
+use std::fmt::{Display, Formatter, Result};
+
+#[derive(Debug)]
+struct DebugOnly(#[allow(dead_code)] u8);
+
+struct DisplayOnly;
+impl Display for DisplayOnly {
+    fn fmt(&self, out: &mut Formatter) -> Result {
+        out.write_str("shown")
+    }
+}
+
+#[derive(Debug)]
+struct Both;
+impl Display for Both {
+    fn fmt(&self, out: &mut Formatter) -> Result {
+        out.write_str("display")
+    }
+}
+
+#[test]
+fn test_display_or_debug() {
+    let display = Some(DisplayOnly);
+    let debug = Some(DebugOnly(3));
+    let both = Some(Both);
+    assert_eq!(
+        "shown",
+        format!("{}", fmt_display_or_debug_or!(display, "-"))
+    );
+    assert_eq!(
+        "DebugOnly(3)",
+        format!("{}", fmt_display_or_debug_or!(debug, "-"))
+    );
+    assert_eq!(
+        "display",
+        format!("{}", fmt_display_or_debug_or!(both, "-"))
+    );
+}
+
+#[test]
+fn test_display_or_debug_fallback() {
+    let display: Option<DisplayOnly> = None;
+    let debug: Option<DebugOnly> = None;
+    assert_eq!("-", format!("{}", fmt_display_or_debug_or!(display, "-")));
+    assert_eq!(
+        "  -",
+        format!("{:>3}", fmt_display_or_debug_or!(debug, "-"))
+    );
+}
+
+#[test]
+fn test_display_or_debug_spec() {
+    let s = Some("x");
+    let v = Some(vec![1]);
+    assert_eq!("x  ", format!("{:3}", fmt_display_or_debug_or!(s, "-")));
+    assert_eq!(
+        "[\n    1,\n]",
+        format!("{:#}", fmt_display_or_debug_or!(v, "-"))
+    );
+}
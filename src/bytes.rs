@@ -0,0 +1,161 @@
+//! Adapters for optional byte slices.
+
+#[cfg(test)]
+mod tests;
+
+use core::fmt::{Display, Formatter, Result, Write};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// The type returned from [`FmtOrBytes::fmt_hexdump_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatHexdump<'t, U>(Option<&'t [u8]>, usize, U);
+/// The type returned from [`FmtOrBytes::fmt_bitgrid_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatBitgrid<'t, U>(Option<&'t [u8]>, usize, U);
+
+/// An extension trait for [`Option<S>`] where `S` is any byte slice-like type, such as `&[u8]`,
+/// `[u8; N]` or `Vec<u8>`.
+///
+/// Unless noted otherwise, the wrappers returned from these methods implement [`Display`] only.
+/// Like [`FmtOr::fmt_or`], the fallback is formatted with the full format spec.
+///
+/// [`FmtOr::fmt_or`]: crate::FmtOr::fmt_or
+#[allow(clippy::needless_lifetimes)] // They're nice to see in docs
+pub trait FmtOrBytes: sealed::Sealed {
+    /// Format the bytes as a classic hex dump, or display the given value instead.
+    ///
+    /// Each line holds `bytes_per_line` bytes: the offset of the first byte as eight hex digits,
+    /// the bytes in hex, and an ASCII gutter between `|` bars where non-printable bytes are shown
+    /// as `.`. The hex column of a short last line is padded so the gutters line up. Lines are
+    /// separated by `\n` with none at the end, and an empty slice renders nothing. Since the result
+    /// spans several lines, the format spec is ignored for a present value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes_per_line` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrBytes;
+    ///
+    /// let packet = Some(b"GET /\r\n");
+    ///
+    /// assert_eq!(
+    ///     "00000000  47 45 54 20  |GET |\n00000004  2f 0d 0a     |/..|",
+    ///     format!("{}", packet.fmt_hexdump_or(4, "no packet"))
+    /// );
+    /// ```
+    fn fmt_hexdump_or<'t, U>(
+        &'t self,
+        bytes_per_line: usize,
+        fallback: U,
+    ) -> MaybeFormatHexdump<'t, U>
+    where
+        U: Display;
+    /// Format the bits of the bytes as a grid of `█` for each one and a space for each zero, or
+    /// display the given value instead.
+    ///
+    /// Bits are taken from the most significant bit of the first byte onwards, `width` to a row.
+    /// Rows are separated by `\n` with none at the end, and the last row may be short. Since the
+    /// result spans several lines, the format spec is ignored for a present value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrBytes;
+    ///
+    /// let glyph = Some([0b1001_0110]);
+    ///
+    /// assert_eq!("█  █\n ██ ", format!("{}", glyph.fmt_bitgrid_or(4, "")));
+    /// ```
+    fn fmt_bitgrid_or<'t, U>(&'t self, width: usize, fallback: U) -> MaybeFormatBitgrid<'t, U>
+    where
+        U: Display;
+}
+
+impl<S> sealed::Sealed for Option<S> {}
+
+impl<S: AsRef<[u8]>> FmtOrBytes for Option<S> {
+    #[inline]
+    fn fmt_hexdump_or<U>(&self, bytes_per_line: usize, fallback: U) -> MaybeFormatHexdump<'_, U>
+    where
+        U: Display,
+    {
+        assert!(bytes_per_line > 0, "bytes_per_line must not be zero");
+        MaybeFormatHexdump(self.as_ref().map(AsRef::as_ref), bytes_per_line, fallback)
+    }
+    #[inline]
+    fn fmt_bitgrid_or<U>(&self, width: usize, fallback: U) -> MaybeFormatBitgrid<'_, U>
+    where
+        U: Display,
+    {
+        assert!(width > 0, "width must not be zero");
+        MaybeFormatBitgrid(self.as_ref().map(AsRef::as_ref), width, fallback)
+    }
+}
+
+impl<'t, U> Display for MaybeFormatHexdump<'t, U>
+where
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        let bytes = match self.0 {
+            Some(bytes) => bytes,
+            None => return Display::fmt(&self.2, out),
+        };
+        let per_line = self.1;
+        for (i, line) in bytes.chunks(per_line).enumerate() {
+            if i > 0 {
+                out.write_char('\n')?;
+            }
+            write!(out, "{:08x} ", i * per_line)?;
+            for byte in line {
+                write!(out, " {:02x}", byte)?;
+            }
+            for _ in line.len()..per_line {
+                out.write_str("   ")?;
+            }
+            out.write_str("  |")?;
+            for &byte in line {
+                let c = if byte == b' ' || byte.is_ascii_graphic() {
+                    byte as char
+                } else {
+                    '.'
+                };
+                out.write_char(c)?;
+            }
+            out.write_char('|')?;
+        }
+        Ok(())
+    }
+}
+
+impl<'t, U> Display for MaybeFormatBitgrid<'t, U>
+where
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        let bytes = match self.0 {
+            Some(bytes) => bytes,
+            None => return Display::fmt(&self.2, out),
+        };
+        let bits = bytes
+            .iter()
+            .flat_map(|byte| (0..8).rev().map(move |i| byte >> i & 1 == 1));
+        for (i, bit) in bits.enumerate() {
+            if i > 0 && i % self.1 == 0 {
+                out.write_char('\n')?;
+            }
+            out.write_char(if bit { '█' } else { ' ' })?;
+        }
+        Ok(())
+    }
+}
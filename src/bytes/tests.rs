@@ -0,0 +1,60 @@
+use crate::FmtOrBytes;
+
+#[test]
+fn test_hexdump() {
+    let bytes: Vec<u8> = (b'A'..b'A' + 18).chain(vec![0x00, 0xff]).collect();
+    assert_eq!(20, bytes.len());
+    let dump = format!("{}", Some(&bytes[..]).fmt_hexdump_or(16, "n/a"));
+    let lines: Vec<_> = dump.lines().collect();
+    assert_eq!(
+        [
+            "00000000  41 42 43 44 45 46 47 48 49 4a 4b 4c 4d 4e 4f 50  |ABCDEFGHIJKLMNOP|",
+            "00000010  51 52 00 ff                                      |QR..|",
+        ],
+        lines[..]
+    );
+    assert!(!dump.ends_with('\n'));
+}
+
+#[test]
+fn test_hexdump_missing() {
+    assert_eq!("", format!("{}", Some([0u8; 0]).fmt_hexdump_or(16, "n/a")));
+    assert_eq!(
+        "  n/a",
+        format!("{:>5}", None::<&[u8]>.fmt_hexdump_or(16, "n/a"))
+    );
+    assert_eq!(
+        "00000000  00  |.|",
+        format!("{:>40}", Some(vec![0u8]).fmt_hexdump_or(1, "n/a"))
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_hexdump_zero_width() {
+    let _ = Some([1u8]).fmt_hexdump_or(0, "");
+}
+
+#[test]
+fn test_bitgrid() {
+    let smiley = [
+        0b0000_0000,
+        0b0110_0110,
+        0b0000_0000,
+        0b0100_0010,
+        0b0011_1100,
+    ];
+    assert_eq!(
+        "        \n ██  ██ \n        \n █    █ \n  ████  ",
+        format!("{}", Some(&smiley[..]).fmt_bitgrid_or(8, "n/a"))
+    );
+    assert_eq!(
+        "████ \n    █\n████ \n ",
+        format!("{}", Some([0xf0, 0x7c]).fmt_bitgrid_or(5, "n/a"))
+    );
+    assert_eq!("", format!("{}", Some([0u8; 0]).fmt_bitgrid_or(8, "n/a")));
+    assert_eq!(
+        "n/a  ",
+        format!("{:5}", None::<&[u8]>.fmt_bitgrid_or(8, "n/a"))
+    );
+}
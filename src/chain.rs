@@ -0,0 +1,196 @@
+//! Combinators trying more options before falling back.
+
+#[cfg(test)]
+mod tests;
+
+use core::fmt::{
+    Binary, Debug, Display, Formatter, LowerExp, LowerHex, Octal, Pointer, Result, UpperExp,
+    UpperHex,
+};
+
+use crate::{
+    MaybeFormat, MaybeFormatDebugNone, MaybeFormatDefault, MaybeFormatDyn, MaybeFormatEither,
+    MaybeFormatEmpty, MaybeFormatOr, MaybeFormatOrArgs, MaybeFormatOrChar, MaybeFormatOrDebug,
+    MaybeFormatOrDefaultDisplay, MaybeFormatOrDynAll, MaybeFormatOrEager, MaybeFormatOrElse,
+    MaybeFormatOrElseCached, MaybeFormatOrElseCtx, MaybeFormatOrElseOnce, MaybeFormatOrError,
+    MaybeFormatOrHex, MaybeFormatOrMapOpt, MaybeFormatOrNum, MaybeFormatOrRef, MaybeFormatOrWith,
+    MaybeFormatSame, MaybeFormatTypedNone,
+};
+
+/// The type returned from the `or_opt` methods on the wrappers, formatting the first option present.
+///
+/// Options are tried in the order they were added: the option of the wrapper `or_opt` was first
+/// called on, then each `or_opt` argument in turn. If every option is missing, the fallback of the
+/// first wrapper is displayed, unless it was replaced with [`or`](MaybeFormatOrOpt::or). Like the
+/// other wrappers, the chain implements every formatting trait the value does.
+///
+/// # Example
+///
+/// ```rust
+/// use fmtor::FmtOr;
+///
+/// let primary: Option<u32> = None;
+/// let secondary = None;
+/// let tertiary = Some(42);
+///
+/// let chain = primary.fmt_or_empty().or_opt(&secondary).or_opt(&tertiary).or("n/a");
+/// assert_eq!("2a", format!("{:x}", chain));
+/// assert_eq!("n/a", format!("{:x}", primary.fmt_or_empty().or_opt(&secondary).or("n/a")));
+/// ```
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatOrOpt<'o, W: Candidate>(W, &'o Option<W::Value>);
+/// The type returned from [`MaybeFormatOrOpt::or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatChainOr<W, U>(W, U);
+
+/// A wrapper which may hold a value to format.
+///
+/// This lets the chained wrappers find the first present value.
+#[doc(hidden)]
+pub trait Candidate {
+    /// The type of the wrapped value.
+    type Value;
+    /// Returns the first present value of the wrapper, if any.
+    fn present(&self) -> Option<&Self::Value>;
+}
+
+macro_rules! impl_candidate {
+    ($([$($g:tt)*] $Ty:ty),*$(,)?) => {$(
+        impl<$($g)*> Candidate for $Ty {
+            type Value = T;
+            #[inline]
+            fn present(&self) -> Option<&T> {
+                self.0.as_ref()
+            }
+        }
+
+        impl<$($g)*> $Ty {
+            /// Try another option if this one is missing, before falling back.
+            ///
+            /// The returned wrapper formats the first present value out of this wrapper's option
+            /// and `other`, in that order. If both are missing, this wrapper's fallback is used.
+            /// See [`MaybeFormatOrOpt`] for chaining further.
+            #[inline]
+            pub fn or_opt<'o>(self, other: &'o Option<T>) -> MaybeFormatOrOpt<'o, Self> {
+                MaybeFormatOrOpt(self, other)
+            }
+        }
+    )*};
+}
+
+impl_candidate!(
+    ['t, T] MaybeFormat<'t, T>,
+    ['t, T, U] MaybeFormatOr<'t, T, U>,
+    ['t, T, F] MaybeFormatOrElse<'t, T, F>,
+    ['t, T, U] MaybeFormatOrDefaultDisplay<'t, T, U>,
+    ['t, T] MaybeFormatTypedNone<'t, T>,
+    ['t, T] MaybeFormatDefault<'t, T>,
+    ['t, T] MaybeFormatSame<'t, T>,
+    ['t, T, F, U] MaybeFormatOrElseCached<'t, T, F, U>,
+    ['t, 'u, T] MaybeFormatOrDynAll<'t, 'u, T>,
+    ['t, T, G] MaybeFormatOrWith<'t, T, G>,
+    ['t, T, N] MaybeFormatOrNum<'t, T, N>,
+    ['t, T, U] MaybeFormatOrDebug<'t, T, U>,
+    ['t, T] MaybeFormatOrArgs<'t, T>,
+    ['t, T] MaybeFormatOrChar<'t, T>,
+    ['t, T, U: ?Sized] MaybeFormatOrRef<'t, T, U>,
+    ['t, T] MaybeFormatOrError<'t, T>,
+    ['t, T, F] MaybeFormatOrElseCtx<'t, T, F>,
+    ['t, T] MaybeFormatEmpty<'t, T>,
+    ['t, T, N] MaybeFormatOrHex<'t, T, N>,
+    ['t, T, U] MaybeFormatOrEager<'t, T, U>,
+    ['t, T] MaybeFormatDebugNone<'t, T>,
+    ['t, T, F] MaybeFormatOrElseOnce<'t, T, F>,
+    ['t, T, B, G] MaybeFormatOrMapOpt<'t, T, B, G>,
+    ['t, T, U] MaybeFormatDyn<'t, T, U>,
+    ['t, T, P, A] MaybeFormatEither<'t, T, P, A>,
+);
+
+impl<'o, W: Candidate> MaybeFormatOrOpt<'o, W> {
+    /// Try another option if the ones before it are missing, before falling back.
+    #[inline]
+    pub fn or_opt<'p>(self, other: &'p Option<W::Value>) -> MaybeFormatOrOpt<'p, Self> {
+        MaybeFormatOrOpt(self, other)
+    }
+    /// Display the given value instead of the original fallback if every option is missing.
+    #[inline]
+    pub fn or<U: Display>(self, fallback: U) -> MaybeFormatChainOr<Self, U> {
+        MaybeFormatChainOr(self, fallback)
+    }
+}
+
+impl<W: Candidate, U> MaybeFormatChainOr<W, U> {
+    /// Try another option if the ones before it are missing, before falling back.
+    #[inline]
+    pub fn or_opt(self, other: &Option<W::Value>) -> MaybeFormatOrOpt<'_, Self> {
+        MaybeFormatOrOpt(self, other)
+    }
+}
+
+impl<'o, W: Candidate> Candidate for MaybeFormatOrOpt<'o, W> {
+    type Value = W::Value;
+    #[inline]
+    fn present(&self) -> Option<&W::Value> {
+        self.0.present().or(self.1.as_ref())
+    }
+}
+
+impl<W: Candidate, U> Candidate for MaybeFormatChainOr<W, U> {
+    type Value = W::Value;
+    #[inline]
+    fn present(&self) -> Option<&W::Value> {
+        self.0.present()
+    }
+}
+
+impl<'o, W: Candidate + Copy> Copy for MaybeFormatOrOpt<'o, W> {}
+impl<'o, W: Candidate + Clone> Clone for MaybeFormatOrOpt<'o, W> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), self.1)
+    }
+}
+
+impl<W: Copy, U: Copy> Copy for MaybeFormatChainOr<W, U> {}
+impl<W: Clone, U: Clone> Clone for MaybeFormatChainOr<W, U> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), self.1.clone())
+    }
+}
+
+macro_rules! impl_fmt_traits {
+    ($($Trait:ident),*$(,)?) => {$(
+
+impl<'o, W> $Trait for MaybeFormatOrOpt<'o, W>
+where
+    W: Candidate + $Trait,
+    W::Value: $Trait,
+{
+    #[inline]
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.present() {
+            Some(t) => <W::Value as $Trait>::fmt(t, out),
+            // Every option is missing, so the inner wrapper displays its fallback.
+            None => <W as $Trait>::fmt(&self.0, out),
+        }
+    }
+}
+
+impl<W, U> $Trait for MaybeFormatChainOr<W, U>
+where
+    W: Candidate,
+    W::Value: $Trait,
+    U: Display,
+{
+    #[inline]
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.present() {
+            Some(t) => <W::Value as $Trait>::fmt(t, out),
+            None => Display::fmt(&self.1, out),
+        }
+    }
+}
+
+    )*}
+} // macro_rules! impl_fmt_traits
+
+impl_fmt_traits!(Binary, Debug, Display, LowerExp, LowerHex, Octal, Pointer, UpperExp, UpperHex);
@@ -0,0 +1,67 @@
+use crate::FmtOr;
+
+#[test]
+fn test_or_opt() {
+    let first = None::<u32>;
+    let second = None;
+    let third = Some(255);
+    let chain = first.fmt_or_empty().or_opt(&second).or_opt(&third);
+    assert_eq!("255", format!("{}", chain));
+    assert_eq!("ff", format!("{:x}", chain));
+    assert_eq!("0xFF", format!("{:#X}", chain));
+    assert_eq!("  11111111", format!("{:>10b}", chain));
+    assert_eq!("255", format!("{}", chain.or("n/a")));
+
+    let first = Some(1);
+    assert_eq!(
+        "1",
+        format!("{}", first.fmt_or("-").or_opt(&second).or_opt(&third))
+    );
+    assert_eq!(
+        "4",
+        format!("{}", None.fmt_or("-").or_opt(&Some(4)).or_opt(&third))
+    );
+}
+
+#[test]
+fn test_or_opt_fallback() {
+    let none = None::<u32>;
+    assert_eq!("-", format!("{:x}", none.fmt_or("-").or_opt(&none)));
+    assert_eq!("", format!("{}", none.fmt_or_empty().or_opt(&none)));
+    assert_eq!(
+        "n/a",
+        format!("{}", none.fmt_or_empty().or_opt(&none).or("n/a"))
+    );
+    assert_eq!(
+        "  n/a",
+        format!("{:>5x}", none.fmt_or("-").or_opt(&none).or("n/a"))
+    );
+    assert_eq!(
+        "lazy",
+        format!(
+            "{}",
+            none.fmt_or_else(|| "lazy").or_opt(&none).or_opt(&none)
+        )
+    );
+    assert_eq!(
+        "0x1",
+        format!(
+            "{:#x}",
+            none.fmt_or("-").or_opt(&none).or("n/a").or_opt(&Some(1))
+        )
+    );
+}
+
+#[test]
+fn test_fmt_or_opt() {
+    let none = None::<u32>;
+    assert_eq!("b", format!("{:x}", none.fmt_or_opt(&Some(0xB))));
+    assert_eq!("0xB", format!("{:#X}", none.fmt_or_opt(&Some(0xB))));
+    assert_eq!("1", format!("{:x}", Some(1).fmt_or_opt(&Some(0xB))));
+    assert_eq!("", format!("{:x}", none.fmt_or_opt(&None)));
+    assert_eq!("    ", format!("{:>4x}", none.fmt_or_opt(&None)));
+    assert_eq!(
+        format!("{:o}", none.fmt_or_empty().or_opt(&Some(8))),
+        format!("{:o}", none.fmt_or_opt(&Some(8)))
+    );
+}
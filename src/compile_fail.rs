@@ -0,0 +1,102 @@
+//! Misuse which must keep failing to compile, checked by the doctests below.
+//!
+//! The fallback of [`fmt_or`](crate::FmtOr::fmt_or) only needs [`Display`](core::fmt::Display),
+//! whichever trait the value is formatted with:
+//!
+//! ```rust
+//! use fmtor::FmtOr;
+//!
+//! assert_eq!("-", format!("{:x}", None::<u32>.fmt_or("-")));
+//! ```
+//!
+//! A fallback which does not implement `Display` is rejected:
+//!
+//! ```compile_fail,E0277
+//! use fmtor::FmtOr;
+//!
+//! struct NoDisplay;
+//!
+//! let _ = None::<u32>.fmt_or(NoDisplay);
+//! ```
+//!
+//! ```compile_fail,E0277
+//! use fmtor::FmtOr;
+//!
+//! struct NoDisplay;
+//!
+//! let _ = format!("{}", None::<u32>.fmt_or_else(|| NoDisplay));
+//! ```
+//!
+//! ```compile_fail,E0277
+//! use fmtor::FmtOr;
+//!
+//! struct NoDisplay;
+//!
+//! let _ = None::<u32>.fmt_or_ref(&NoDisplay);
+//! ```
+//!
+//! A value which does not implement the requested trait is rejected, even though the fallback
+//! would display fine:
+//!
+//! ```compile_fail,E0277
+//! use fmtor::FmtOr;
+//!
+//! let _ = format!("{:x}", Some("text").fmt_or("-"));
+//! ```
+//!
+//! ```compile_fail,E0277
+//! use fmtor::FmtOr;
+//!
+//! let _ = format!("{:b}", Some(1.5).fmt_or_empty());
+//! ```
+//!
+//! The fallback of [`fmt_or_num`](crate::FmtOr::fmt_or_num) must implement the requested trait
+//! too:
+//!
+//! ```compile_fail,E0277
+//! use fmtor::FmtOr;
+//!
+//! let _ = format!("{:x}", None::<u32>.fmt_or_num("0"));
+//! ```
+//!
+//! ```compile_fail,E0277
+//! use fmtor::FmtOr;
+//!
+//! let _ = None::<u32>.fmt_or_hex(1.5);
+//! ```
+//!
+//! [`FmtOr`](crate::FmtOr) and [`FmtOrStr`](crate::FmtOrStr) are sealed, so they can't be
+//! implemented outside of this crate:
+//!
+//! ```compile_fail,E0277
+//! struct Cell;
+//! impl fmtor::FmtOr<u32> for Cell {}
+//! ```
+//!
+//! ```compile_fail,E0277
+//! struct Name;
+//! impl fmtor::FmtOrStr<String> for Name {}
+//! ```
+//!
+//! Every wrapper is `#[must_use]`, as it does nothing until it is formatted:
+//!
+//! ```compile_fail
+//! #![deny(unused_must_use)]
+//! use fmtor::FmtOr;
+//!
+//! None::<u32>.fmt_or_hex(0);
+//! ```
+//!
+//! ```compile_fail
+//! #![deny(unused_must_use)]
+//! use fmtor::FmtOrNum;
+//!
+//! Some(1.5).fmt_ltr_number_or("-");
+//! ```
+//!
+//! ```compile_fail
+//! #![deny(unused_must_use)]
+//! use fmtor::FmtOr;
+//!
+//! Some(3).into_fmt_or("x");
+//! ```
@@ -0,0 +1,124 @@
+//! Counting missing values across many wrappers, and bytes written by one.
+
+#[cfg(test)]
+mod tests;
+
+use core::cell::Cell;
+use core::fmt::{Alignment, Display, Formatter, Result, Write};
+
+use crate::pad::pad;
+use crate::{FmtOr, MaybeFormatOr};
+
+/// The type returned from [`FmtOr::fmt_or_counting`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatCounting<'t, 'c, T, U>(
+    pub(crate) &'t Option<T>,
+    pub(crate) U,
+    pub(crate) &'c Cell<usize>,
+);
+
+impl<'t, 'c, T, U: Copy> Copy for MaybeFormatCounting<'t, 'c, T, U> {}
+impl<'t, 'c, T, U: Clone> Clone for MaybeFormatCounting<'t, 'c, T, U> {
+    fn clone(&self) -> Self {
+        Self(self.0, self.1.clone(), self.2)
+    }
+}
+
+/// Passes writes through while counting the bytes.
+struct Tee<'w> {
+    out: &'w mut dyn Write,
+    written: usize,
+}
+
+impl Write for Tee<'_> {
+    fn write_str(&mut self, s: &str) -> Result {
+        self.written += s.len();
+        self.out.write_str(s)
+    }
+}
+
+impl<'t, 'c, T, U> Display for MaybeFormatCounting<'t, 'c, T, U>
+where
+    T: Display,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        pad(out, Alignment::Left, |w: &mut dyn Write| {
+            let mut tee = Tee { out: w, written: 0 };
+            match self.0 {
+                Some(t) => write!(tee, "{}", t)?,
+                None => write!(tee, "{}", self.1)?,
+            }
+            self.2.set(tee.written);
+            Ok(())
+        })
+    }
+}
+
+/// Wraps options like [`FmtOr::fmt_or`] while counting how many were missing.
+///
+/// This is useful for summary lines such as "rendered 5 fields, 2 missing". Values are counted
+/// when they are wrapped, so the counts don't depend on how many times each wrapper is formatted.
+///
+/// # Example
+///
+/// ```rust
+/// use fmtor::MissingCounter;
+///
+/// let name = Some("alice");
+/// let email: Option<&str> = None;
+///
+/// let counter = MissingCounter::new();
+/// let line = format!(
+///     "{} <{}>",
+///     counter.wrap(&name, "?"),
+///     counter.wrap(&email, "?"),
+/// );
+///
+/// assert_eq!("alice <?>", line);
+/// assert_eq!(1, counter.count());
+/// assert_eq!(2, counter.total());
+/// ```
+#[derive(Debug, Default)]
+pub struct MissingCounter {
+    missing: Cell<usize>,
+    total: Cell<usize>,
+}
+
+impl MissingCounter {
+    /// Creates a counter with nothing counted yet.
+    pub const fn new() -> Self {
+        MissingCounter {
+            missing: Cell::new(0),
+            total: Cell::new(0),
+        }
+    }
+    /// Counts `opt`, then returns the same wrapper as [`FmtOr::fmt_or`].
+    #[inline]
+    pub fn wrap<'t, T, U>(&self, opt: &'t Option<T>, fallback: U) -> MaybeFormatOr<'t, T, U>
+    where
+        U: Display,
+    {
+        self.total.set(self.total.get() + 1);
+        if opt.is_none() {
+            self.missing.set(self.missing.get() + 1);
+        }
+        opt.fmt_or(fallback)
+    }
+    /// Returns how many of the wrapped options were missing.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.missing.get()
+    }
+    /// Returns how many options have been wrapped, present or not.
+    #[inline]
+    pub fn total(&self) -> usize {
+        self.total.get()
+    }
+    /// Sets both counts back to zero.
+    #[inline]
+    pub fn reset(&self) {
+        self.missing.set(0);
+        self.total.set(0);
+    }
+}
@@ -0,0 +1,65 @@
+use std::cell::Cell;
+
+use crate::{FmtOr, MissingCounter};
+
+#[test]
+fn test_missing_counter() {
+    let fields = [Some(1), None, Some(3), None, None];
+    let counter = MissingCounter::new();
+    let rendered: Vec<String> = fields
+        .iter()
+        .map(|field| format!("{:x}", counter.wrap(field, "-")))
+        .collect();
+    assert_eq!(["1", "-", "3", "-", "-"], &rendered[..]);
+    assert_eq!(3, counter.count());
+    assert_eq!(5, counter.total());
+    assert_eq!(
+        "rendered 5 fields, 3 missing",
+        format!(
+            "rendered {} fields, {} missing",
+            counter.total(),
+            counter.count()
+        )
+    );
+}
+
+#[test]
+fn test_missing_counter_counts_once() {
+    let counter = MissingCounter::default();
+    let missing: Option<u8> = None;
+    let wrapped = counter.wrap(&missing, "-");
+    assert_eq!("-", format!("{}", wrapped));
+    assert_eq!("-", format!("{}", wrapped));
+    assert_eq!(1, counter.count());
+    counter.reset();
+    assert_eq!(0, counter.count());
+    assert_eq!(0, counter.total());
+}
+
+#[test]
+fn test_counting() {
+    let written = Cell::new(0);
+    let missing = None::<&str>;
+    assert_eq!("x", format!("{}", missing.fmt_or_counting("x", &written)));
+    assert_eq!(1, written.get());
+    assert_eq!(
+        "n/a  ",
+        format!("{:5}", missing.fmt_or_counting("n/a", &written))
+    );
+    assert_eq!(3, written.get());
+
+    let present = Some("héllo");
+    assert_eq!(
+        "héllo",
+        format!("{}", present.fmt_or_counting("x", &written))
+    );
+    assert_eq!(6, written.get());
+    assert_eq!(
+        "*héllo*",
+        format!("{:*^7}", present.fmt_or_counting("x", &written))
+    );
+    assert_eq!(6, written.get());
+
+    assert_eq!("", format!("{}", None::<u8>.fmt_or_counting("", &written)));
+    assert_eq!(0, written.get());
+}
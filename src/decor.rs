@@ -0,0 +1,944 @@
+//! Adapters which decorate any optional [`Display`] value.
+
+#[cfg(test)]
+mod tests;
+
+use core::fmt::{Alignment, Debug, Display, Formatter, Result, Write};
+
+#[cfg(feature = "std")]
+use crate::pad::CharCount;
+use crate::pad::{pad, pad_aligned};
+use crate::FmtOr;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Where a value came from, for use with [`FmtOrDecor::fmt_or_source`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Source {
+    /// Displays as `[db]`
+    Db,
+    /// Displays as `[cache]`
+    Cache,
+    /// Displays as `[net]`
+    Network,
+    /// Displays as `[config]`
+    Config,
+    /// Displays the given tag inside brackets
+    Custom(&'static str),
+}
+
+impl Display for Source {
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        let tag = match self {
+            Source::Db => "db",
+            Source::Cache => "cache",
+            Source::Network => "net",
+            Source::Config => "config",
+            Source::Custom(tag) => tag,
+        };
+        out.write_char('[')?;
+        out.write_str(tag)?;
+        out.write_char(']')
+    }
+}
+
+/// The type returned from [`FmtOrDecor::fmt_or_source`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatSource<'t, T, U>(&'t Option<T>, Source, U);
+/// The type returned from [`FmtOrDecor::fmt_task_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatTask<'t, T, U>(&'t Option<T>, bool, U);
+/// The type returned from [`FmtOrDecor::fmt_or_tier`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatTier<'t, T, U>(&'t Option<T>, Tier, [U; 3]);
+/// The type returned from [`FmtOrDecor::fmt_or_annotate_with`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatAnnotate<'t, T, G, U>(&'t Option<T>, G, U);
+/// The type returned from [`FmtOrDecor::fmt_aligned_kv_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatAlignedKv<'t, T, U>(&'t Option<T>, &'t str, usize, U);
+/// The type returned from [`FmtOrDecor::fmt_smart_align_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatSmartAlign<'t, T, U>(&'t Option<T>, usize, U);
+/// The type returned from [`FmtOrDecor::fmt_or_split_align`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatSplitAlign<'t, T, U>(&'t Option<T>, U);
+/// The type returned from [`FmtOrDecor::fmt_display_with_debug_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatDisplayWithDebug<'t, T, U>(&'t Option<T>, U);
+/// The type returned from [`FmtOrDecor::fmt_term_fit_or`]
+#[cfg(feature = "std")]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatTermFit<'t, T, U>(&'t Option<T>, usize, U);
+/// The type returned from [`fmt_settings_block_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct SettingsBlock<'t, V, U>(&'t [(&'t str, Option<V>)], usize, U);
+/// The type returned from [`fmt_tree_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct Tree<'t, V, U>(&'t [(&'t str, Option<V>)], U);
+/// The type returned from [`FieldFormat::render_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatField<'t, T, U>(&'t Option<T>, FieldFormat<'t>, U);
+
+/// An extension trait for [`Option<T>`] which adds decorations around a present value.
+///
+/// Unless noted otherwise, the wrappers returned from these methods implement [`Display`] only.
+/// Like [`FmtOr::fmt_or`], the fallback is formatted with the full format spec, while a present
+/// value is padded to the requested width together with its decorations.
+///
+/// This trait is sealed, so methods behind features, such as
+/// [`fmt_term_fit_or`](FmtOrDecor::fmt_term_fit_or), don't break other implementations.
+///
+/// [`FmtOr::fmt_or`]: crate::FmtOr::fmt_or
+#[allow(clippy::needless_lifetimes)] // They're nice to see in docs
+pub trait FmtOrDecor<T>: sealed::Sealed {
+    /// Format the value prefixed by a tag naming its [`Source`], or display the given value instead.
+    ///
+    /// The fallback is not tagged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::{FmtOrDecor, Source};
+    ///
+    /// let hit = Some(42);
+    /// let miss: Option<u32> = None;
+    ///
+    /// assert_eq!("[cache] 42", format!("{}", hit.fmt_or_source(Source::Cache, "miss")));
+    /// assert_eq!("miss", format!("{}", miss.fmt_or_source(Source::Cache, "miss")));
+    /// ```
+    fn fmt_or_source<'t, U>(&'t self, source: Source, fallback: U) -> MaybeFormatSource<'t, T, U>
+    where
+        T: Display,
+        U: Display;
+    /// Format the value as a task list item, or display the given value as an unfinished item instead.
+    ///
+    /// A present value is shown as `[✓] value` when `done` is `true` and as `[ ] value` otherwise.
+    /// A missing value can never be done, so it is always shown as `[ ] fallback` regardless of `done`.
+    /// Since the checkbox is part of both cases, the whole line is padded to the requested width.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrDecor;
+    ///
+    /// assert_eq!("[✓] write docs", format!("{}", Some("write docs").fmt_task_or(true, "?")));
+    /// assert_eq!("[ ] write docs", format!("{}", Some("write docs").fmt_task_or(false, "?")));
+    /// assert_eq!("[ ] ?", format!("{}", None::<&str>.fmt_task_or(true, "?")));
+    /// ```
+    fn fmt_task_or<'t, U>(&'t self, done: bool, fallback: U) -> MaybeFormatTask<'t, T, U>
+    where
+        T: Display,
+        U: Display;
+    /// Format the value tagged by its cache [`Tier`], or display the fallback for that tier instead.
+    ///
+    /// A present value is shown as-is when [`Tier::Fresh`], and followed by ` (stale)` or
+    /// ` (missing)` for the other tiers. A missing value displays `fallback_fresh`,
+    /// `fallback_stale` or `fallback_missing` according to the tier, formatted with the full
+    /// format spec like [`FmtOr::fmt_or`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::{FmtOrDecor, Tier};
+    ///
+    /// let hit = Some(42);
+    /// let miss: Option<u32> = None;
+    ///
+    /// assert_eq!("42", format!("{}", hit.fmt_or_tier(Tier::Fresh, "loading", "refreshing", "gone")));
+    /// assert_eq!("42 (stale)", format!("{}", hit.fmt_or_tier(Tier::Stale, "loading", "refreshing", "gone")));
+    /// assert_eq!("refreshing", format!("{}", miss.fmt_or_tier(Tier::Stale, "loading", "refreshing", "gone")));
+    /// ```
+    ///
+    /// [`FmtOr::fmt_or`]: crate::FmtOr::fmt_or
+    fn fmt_or_tier<'t, U>(
+        &'t self,
+        tier: Tier,
+        fallback_fresh: U,
+        fallback_stale: U,
+        fallback_missing: U,
+    ) -> MaybeFormatTier<'t, T, U>
+    where
+        T: Display,
+        U: Display;
+    /// Format the value followed by an annotation computed from it, or display the given value instead.
+    ///
+    /// A present value is shown as `value (annotation)`. The closure runs during each formatting
+    /// operation, and never runs when the value is missing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrDecor;
+    ///
+    /// let parity = |n: &u32| if n % 2 == 0 { "even" } else { "odd" };
+    ///
+    /// assert_eq!("42 (even)", format!("{}", Some(42).fmt_or_annotate_with(parity, "-")));
+    /// assert_eq!("-", format!("{}", None.fmt_or_annotate_with(parity, "-")));
+    /// ```
+    fn fmt_or_annotate_with<'t, G, A, U>(
+        &'t self,
+        annotate: G,
+        fallback: U,
+    ) -> MaybeFormatAnnotate<'t, T, G, U>
+    where
+        T: Display,
+        G: Fn(&T) -> A,
+        A: Display,
+        U: Display;
+    /// Format the value after a key padded to `key_width`, or display the given value after the key instead.
+    ///
+    /// The key is followed by spaces up to `key_width` chars and then `: `, so that the colons
+    /// of several lines with the same `key_width` line up. A key longer than `key_width` is not
+    /// cut. The key is shown for a missing value too, and the whole line is padded to the
+    /// requested width.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrDecor;
+    ///
+    /// let name = Some("fmtor");
+    /// let license: Option<&str> = None;
+    ///
+    /// assert_eq!("name   : fmtor", format!("{}", name.fmt_aligned_kv_or("name", 7, "-")));
+    /// assert_eq!("license: -", format!("{}", license.fmt_aligned_kv_or("license", 7, "-")));
+    /// ```
+    fn fmt_aligned_kv_or<'t, U>(
+        &'t self,
+        key: &'t str,
+        key_width: usize,
+        fallback: U,
+    ) -> MaybeFormatAlignedKv<'t, T, U>
+    where
+        T: Display,
+        U: Display;
+    /// Format the value padded to `width`, aligned by the kind of `T`, or display the given value
+    /// instead.
+    ///
+    /// Numbers are right-aligned and everything else is left-aligned, as is the fallback, which
+    /// suits table columns. The alignment comes from the [`SmartAlign`] impl of `T`. The format
+    /// spec is ignored; the value is padded to `width` with its plain [`Display`] output.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrDecor;
+    ///
+    /// assert_eq!("|   42|", format!("|{}|", Some(42).fmt_smart_align_or(5, "-")));
+    /// assert_eq!("|abc  |", format!("|{}|", Some("abc").fmt_smart_align_or(5, "-")));
+    /// assert_eq!("|-    |", format!("|{}|", None::<u8>.fmt_smart_align_or(5, "-")));
+    /// ```
+    fn fmt_smart_align_or<'t, U>(
+        &'t self,
+        width: usize,
+        fallback: U,
+    ) -> MaybeFormatSmartAlign<'t, T, U>
+    where
+        T: Display + SmartAlign,
+        U: Display;
+    /// Format the value right-aligned, or display the given value left-aligned instead.
+    ///
+    /// Both are padded to the width of the format spec, but the alignment of the format spec is
+    /// ignored. This suits a column of numbers with a placeholder such as `—` for missing entries.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrDecor;
+    ///
+    /// assert_eq!("|    42|", format!("|{:6}|", Some(42).fmt_or_split_align("—")));
+    /// assert_eq!("|—     |", format!("|{:>6}|", None::<u8>.fmt_or_split_align("—")));
+    /// ```
+    fn fmt_or_split_align<'t, U>(&'t self, fallback: U) -> MaybeFormatSplitAlign<'t, T, U>
+    where
+        T: Display,
+        U: Display;
+    /// Format the value with [`Display`] followed by its [`Debug`] form in parentheses, or display
+    /// the given value instead.
+    ///
+    /// This helps spot mismatches between the two impls of a type. The whole text is padded to
+    /// the requested width.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrDecor;
+    ///
+    /// assert_eq!(r#"hi ("hi")"#, format!("{}", Some("hi").fmt_display_with_debug_or("-")));
+    /// assert_eq!("1.5 (1.5)", format!("{}", Some(1.5).fmt_display_with_debug_or("-")));
+    /// ```
+    fn fmt_display_with_debug_or<'t, U>(
+        &'t self,
+        fallback: U,
+    ) -> MaybeFormatDisplayWithDebug<'t, T, U>
+    where
+        T: Display + Debug,
+        U: Display;
+    /// Format the value cut to fit the width of the terminal, or display the given value instead.
+    ///
+    /// The width is read from the `COLUMNS` environment variable when this method is called, or is
+    /// 80 if that is unset or invalid. A value longer than the width is cut and ends with `…`,
+    /// which counts toward the width. The fallback is not cut.
+    ///
+    /// Requires the `std` feature.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrDecor;
+    ///
+    /// let status = Some("all systems nominal");
+    ///
+    /// println!("{}", status.fmt_term_fit_or("no status"));
+    /// ```
+    #[cfg(feature = "std")]
+    fn fmt_term_fit_or<'t, U>(&'t self, fallback: U) -> MaybeFormatTermFit<'t, T, U>
+    where
+        T: Display,
+        U: Display;
+}
+
+impl<T> sealed::Sealed for Option<T> {}
+
+impl<T> FmtOrDecor<T> for Option<T> {
+    #[inline]
+    fn fmt_or_source<U>(&self, source: Source, fallback: U) -> MaybeFormatSource<'_, T, U>
+    where
+        T: Display,
+        U: Display,
+    {
+        MaybeFormatSource(self, source, fallback)
+    }
+    #[inline]
+    fn fmt_task_or<U>(&self, done: bool, fallback: U) -> MaybeFormatTask<'_, T, U>
+    where
+        T: Display,
+        U: Display,
+    {
+        MaybeFormatTask(self, done, fallback)
+    }
+    #[inline]
+    fn fmt_or_tier<U>(
+        &self,
+        tier: Tier,
+        fallback_fresh: U,
+        fallback_stale: U,
+        fallback_missing: U,
+    ) -> MaybeFormatTier<'_, T, U>
+    where
+        T: Display,
+        U: Display,
+    {
+        MaybeFormatTier(
+            self,
+            tier,
+            [fallback_fresh, fallback_stale, fallback_missing],
+        )
+    }
+    #[inline]
+    fn fmt_or_annotate_with<G, A, U>(
+        &self,
+        annotate: G,
+        fallback: U,
+    ) -> MaybeFormatAnnotate<'_, T, G, U>
+    where
+        T: Display,
+        G: Fn(&T) -> A,
+        A: Display,
+        U: Display,
+    {
+        MaybeFormatAnnotate(self, annotate, fallback)
+    }
+    #[inline]
+    fn fmt_aligned_kv_or<'t, U>(
+        &'t self,
+        key: &'t str,
+        key_width: usize,
+        fallback: U,
+    ) -> MaybeFormatAlignedKv<'t, T, U>
+    where
+        T: Display,
+        U: Display,
+    {
+        MaybeFormatAlignedKv(self, key, key_width, fallback)
+    }
+    #[inline]
+    fn fmt_smart_align_or<U>(&self, width: usize, fallback: U) -> MaybeFormatSmartAlign<'_, T, U>
+    where
+        T: Display + SmartAlign,
+        U: Display,
+    {
+        MaybeFormatSmartAlign(self, width, fallback)
+    }
+    #[inline]
+    fn fmt_or_split_align<U>(&self, fallback: U) -> MaybeFormatSplitAlign<'_, T, U>
+    where
+        T: Display,
+        U: Display,
+    {
+        MaybeFormatSplitAlign(self, fallback)
+    }
+    #[inline]
+    fn fmt_display_with_debug_or<U>(&self, fallback: U) -> MaybeFormatDisplayWithDebug<'_, T, U>
+    where
+        T: Display + Debug,
+        U: Display,
+    {
+        MaybeFormatDisplayWithDebug(self, fallback)
+    }
+    #[cfg(feature = "std")]
+    #[inline]
+    fn fmt_term_fit_or<U>(&self, fallback: U) -> MaybeFormatTermFit<'_, T, U>
+    where
+        T: Display,
+        U: Display,
+    {
+        let columns = std::env::var("COLUMNS").ok();
+        MaybeFormatTermFit(self, parse_columns(columns.as_deref()), fallback)
+    }
+}
+
+impl<'t, T, U> Display for MaybeFormatSource<'t, T, U>
+where
+    T: Display,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) => pad(out, Alignment::Left, |w: &mut dyn Write| {
+                write!(w, "{} {}", self.1, t)
+            }),
+            None => Display::fmt(&self.2, out),
+        }
+    }
+}
+
+impl<'t, T, U> Display for MaybeFormatTask<'t, T, U>
+where
+    T: Display,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        pad(out, Alignment::Left, |w: &mut dyn Write| match self.0 {
+            Some(t) if self.1 => write!(w, "[✓] {}", t),
+            Some(t) => write!(w, "[ ] {}", t),
+            None => write!(w, "[ ] {}", self.2),
+        })
+    }
+}
+
+/// How recently a cached value was refreshed, for use with [`FmtOrDecor::fmt_or_tier`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Tier {
+    /// The value is up to date.
+    Fresh,
+    /// The value is out of date but still usable.
+    Stale,
+    /// The value has expired and should not be relied on.
+    Missing,
+}
+
+impl<'t, T, U> Display for MaybeFormatTier<'t, T, U>
+where
+    T: Display,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        let (index, tag) = match self.1 {
+            Tier::Fresh => (0, ""),
+            Tier::Stale => (1, " (stale)"),
+            Tier::Missing => (2, " (missing)"),
+        };
+        match self.0 {
+            Some(t) => pad(out, Alignment::Left, |w: &mut dyn Write| {
+                write!(w, "{}{}", t, tag)
+            }),
+            None => Display::fmt(&self.2[index], out),
+        }
+    }
+}
+
+impl<'t, T, G, A, U> Display for MaybeFormatAnnotate<'t, T, G, U>
+where
+    T: Display,
+    G: Fn(&T) -> A,
+    A: Display,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) => {
+                let annotation = (self.1)(t);
+                pad(out, Alignment::Left, |w: &mut dyn Write| {
+                    write!(w, "{} ({})", t, annotation)
+                })
+            }
+            None => Display::fmt(&self.2, out),
+        }
+    }
+}
+
+impl<'t, T, U> Display for MaybeFormatAlignedKv<'t, T, U>
+where
+    T: Display,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        pad(out, Alignment::Left, |w: &mut dyn Write| {
+            write!(w, "{:<width$}: ", self.1, width = self.2)?;
+            match self.0 {
+                Some(t) => write!(w, "{}", t),
+                None => write!(w, "{}", self.3),
+            }
+        })
+    }
+}
+
+/// Marks whether a type should be aligned like a number, for use with
+/// [`FmtOrDecor::fmt_smart_align_or`].
+///
+/// This is implemented for all the integer and float types, which are numeric, and for `str`,
+/// `char`, `bool` and references to any implementing type, which are not.
+pub trait SmartAlign {
+    /// Whether the type is right-aligned as a number.
+    const NUMERIC: bool;
+}
+
+macro_rules! impl_smart_align {
+    ($numeric:expr => $($T:ty),*$(,)?) => {$(
+        impl SmartAlign for $T {
+            const NUMERIC: bool = $numeric;
+        }
+    )*};
+}
+
+impl_smart_align!(true => i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+impl_smart_align!(false => str, char, bool);
+
+impl<T: SmartAlign + ?Sized> SmartAlign for &T {
+    const NUMERIC: bool = T::NUMERIC;
+}
+
+impl<'t, T, U> Display for MaybeFormatSmartAlign<'t, T, U>
+where
+    T: Display + SmartAlign,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        let width = self.1;
+        match self.0 {
+            Some(t) if T::NUMERIC => write!(out, "{:>width$}", t, width = width),
+            Some(t) => write!(out, "{:<width$}", t, width = width),
+            None => write!(out, "{:<width$}", self.2, width = width),
+        }
+    }
+}
+
+impl<'t, T, U> Display for MaybeFormatSplitAlign<'t, T, U>
+where
+    T: Display,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) => pad_aligned(out, Alignment::Right, |w: &mut dyn Write| {
+                write!(w, "{}", t)
+            }),
+            None => pad_aligned(out, Alignment::Left, |w: &mut dyn Write| {
+                write!(w, "{}", self.1)
+            }),
+        }
+    }
+}
+
+impl<'t, T, U> Display for MaybeFormatDisplayWithDebug<'t, T, U>
+where
+    T: Display + Debug,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) => pad(out, Alignment::Left, |w: &mut dyn Write| {
+                write!(w, "{} ({:?})", t, t)
+            }),
+            None => Display::fmt(&self.1, out),
+        }
+    }
+}
+
+/// Parses the value of `COLUMNS`, defaulting to 80 columns.
+#[cfg(feature = "std")]
+fn parse_columns(columns: Option<&str>) -> usize {
+    columns
+        .and_then(|c| c.trim().parse().ok())
+        .filter(|&c| c > 0)
+        .unwrap_or(80)
+}
+
+/// Passes up to `remaining` chars through and drops the rest.
+#[cfg(feature = "std")]
+struct Truncate<'w> {
+    out: &'w mut dyn Write,
+    remaining: usize,
+}
+
+#[cfg(feature = "std")]
+impl Write for Truncate<'_> {
+    fn write_str(&mut self, s: &str) -> Result {
+        for c in s.chars() {
+            if self.remaining == 0 {
+                break;
+            }
+            self.out.write_char(c)?;
+            self.remaining -= 1;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'t, T, U> Display for MaybeFormatTermFit<'t, T, U>
+where
+    T: Display,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        let t = match self.0 {
+            Some(t) => t,
+            None => return Display::fmt(&self.2, out),
+        };
+        let columns = self.1;
+        let mut count = CharCount(0);
+        write!(count, "{}", t)?;
+        if count.0 <= columns {
+            return pad(out, Alignment::Left, |w: &mut dyn Write| write!(w, "{}", t));
+        }
+        pad(out, Alignment::Left, |w: &mut dyn Write| {
+            let mut cut = Truncate {
+                out: &mut *w,
+                remaining: columns - 1,
+            };
+            write!(cut, "{}", t)?;
+            w.write_char('…')
+        })
+    }
+}
+
+/// Format a block of settings, one `key  value` line each, with the given value shown for
+/// missing settings.
+///
+/// Keys are padded to `key_width` chars and followed by two spaces, so that the values line up.
+/// Lines are separated by `\n` with none at the end. Since the result spans several lines, the
+/// format spec is ignored. The returned wrapper implements [`Display`] only.
+///
+/// # Example
+///
+/// ```rust
+/// let settings = [("host", Some("localhost")), ("port", None), ("user", Some("admin"))];
+///
+/// assert_eq!(
+///     "host    localhost\nport    (unset)\nuser    admin",
+///     format!("{}", fmtor::fmt_settings_block_or(&settings, 6, "(unset)"))
+/// );
+/// ```
+pub fn fmt_settings_block_or<'t, V, U>(
+    pairs: &'t [(&'t str, Option<V>)],
+    key_width: usize,
+    fallback: U,
+) -> SettingsBlock<'t, V, U>
+where
+    V: Display,
+    U: Display,
+{
+    SettingsBlock(pairs, key_width, fallback)
+}
+
+impl<'t, V, U> Display for SettingsBlock<'t, V, U>
+where
+    V: Display,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        for (i, (key, value)) in self.0.iter().enumerate() {
+            if i > 0 {
+                out.write_char('\n')?;
+            }
+            write!(out, "{:<width$}  ", key, width = self.1)?;
+            match value {
+                Some(v) => write!(out, "{}", v)?,
+                None => write!(out, "{}", self.2)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Format a list of fields as the branches of a tree, one `├─ key: value` line each, with the
+/// given value shown as the leaf of missing fields.
+///
+/// The last branch uses `└─` instead. Lines are separated by `\n` with none at the end. Since the
+/// result spans several lines, the format spec is ignored. The returned wrapper implements
+/// [`Display`] only.
+///
+/// # Example
+///
+/// ```rust
+/// let fields = [("name", Some("fmtor")), ("license", None), ("edition", Some("2018"))];
+///
+/// assert_eq!(
+///     "├─ name: fmtor\n├─ license: ?\n└─ edition: 2018",
+///     format!("{}", fmtor::fmt_tree_or(&fields, "?"))
+/// );
+/// ```
+pub fn fmt_tree_or<'t, V, U>(pairs: &'t [(&'t str, Option<V>)], fallback: U) -> Tree<'t, V, U>
+where
+    V: Display,
+    U: Display,
+{
+    Tree(pairs, fallback)
+}
+
+impl<'t, V, U> Display for Tree<'t, V, U>
+where
+    V: Display,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        let last = self.0.len().saturating_sub(1);
+        for (i, (key, value)) in self.0.iter().enumerate() {
+            if i > 0 {
+                out.write_char('\n')?;
+            }
+            let connector = if i == last { "└─" } else { "├─" };
+            write!(out, "{} {}: ", connector, key)?;
+            match value {
+                Some(v) => write!(out, "{}", v)?,
+                None => write!(out, "{}", self.1)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A prefix and suffix built once and reused to decorate many optional values.
+///
+/// This is intended for hot loops, such as logging, where the same field is rendered over and over.
+/// The decorations are only written when the value is present; the fallback is displayed as-is.
+///
+/// # Example
+///
+/// ```rust
+/// use fmtor::FieldFormat;
+///
+/// const USER: FieldFormat = FieldFormat::new("user=", ";");
+///
+/// assert_eq!("user=alice;", format!("{}", USER.render_or(&Some("alice"), "-")));
+/// assert_eq!("-", format!("{}", USER.render_or(&None::<&str>, "-")));
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct FieldFormat<'a> {
+    prefix: &'a str,
+    suffix: &'a str,
+}
+
+impl<'a> FieldFormat<'a> {
+    /// Creates a field which writes `prefix` before and `suffix` after each present value.
+    pub const fn new(prefix: &'a str, suffix: &'a str) -> Self {
+        Self { prefix, suffix }
+    }
+    /// Format the value between the prefix and suffix, or display the given value instead.
+    ///
+    /// Like the adapters on [`FmtOrDecor`], a present value and its decorations are padded to the
+    /// requested width as a whole, while the fallback is formatted with the full format spec.
+    pub fn render_or<'t, T, U>(&self, opt: &'t Option<T>, fallback: U) -> MaybeFormatField<'t, T, U>
+    where
+        'a: 't,
+        T: Display,
+        U: Display,
+    {
+        MaybeFormatField(opt, *self, fallback)
+    }
+}
+
+impl<'t, T, U> Display for MaybeFormatField<'t, T, U>
+where
+    T: Display,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) => pad(out, Alignment::Left, |w: &mut dyn Write| {
+                w.write_str(self.1.prefix)?;
+                write!(w, "{}", t)?;
+                w.write_str(self.1.suffix)
+            }),
+            None => Display::fmt(&self.2, out),
+        }
+    }
+}
+
+/// A compact presence dashboard, packing several optional values into one status string.
+///
+/// Each entry is shown as its letter if the value is present, or as `-` if it is missing. The
+/// marker for missing values can be changed with [`absent`](StatusLine::absent). Up to `N` entries
+/// can be added, and the whole line is padded to the requested width.
+///
+/// # Example
+///
+/// ```rust
+/// use fmtor::StatusLine;
+///
+/// let (auth, cache, db, queue) = (Some("token"), None::<u32>, Some(5432), None::<&str>);
+///
+/// let status = StatusLine::<4>::new()
+///     .entry('A', &auth)
+///     .entry('C', &cache)
+///     .entry('D', &db)
+///     .entry('Q', &queue);
+/// assert_eq!("A-D-", format!("{}", status));
+/// assert_eq!("A.D.", format!("{}", status.absent('.')));
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct StatusLine<const N: usize> {
+    entries: [(char, bool); N],
+    len: usize,
+    absent: char,
+}
+
+impl<const N: usize> StatusLine<N> {
+    /// Creates an empty status line.
+    pub const fn new() -> Self {
+        Self {
+            entries: [('-', false); N],
+            len: 0,
+            absent: '-',
+        }
+    }
+    /// Adds a position showing `letter` if the value is present.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the line already has `N` entries.
+    pub fn entry<T>(mut self, letter: char, opt: &Option<T>) -> Self {
+        assert!(self.len < N, "StatusLine is full");
+        self.entries[self.len] = (letter, opt.is_some());
+        self.len += 1;
+        self
+    }
+    /// Sets the marker shown for missing values, instead of `-`.
+    pub fn absent(mut self, marker: char) -> Self {
+        self.absent = marker;
+        self
+    }
+}
+
+impl<const N: usize> Default for StatusLine<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Display for StatusLine<N> {
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        pad(out, Alignment::Left, |w: &mut dyn Write| {
+            for &(letter, present) in &self.entries[..self.len] {
+                w.write_char(if present { letter } else { self.absent })?;
+            }
+            Ok(())
+        })
+    }
+}
+
+/// A record of named optional fields, displayed as `name=value` pairs separated by `, `.
+///
+/// Each field is shown like [`FmtOr::fmt_or`], with its own fallback for a missing value. With
+/// [`omit_missing`](FieldSet::omit_missing), missing fields are left out entirely instead. Up to
+/// `N` fields can be added, and the whole record is padded to the requested width.
+///
+/// # Example
+///
+/// ```rust
+/// use fmtor::FieldSet;
+///
+/// let (user, pid, host) = (Some("alice"), None::<u32>, Some("db1"));
+///
+/// let record = FieldSet::<3>::new()
+///     .field("user", &user, "?")
+///     .field("pid", &pid, "-")
+///     .field("host", &host, "?");
+/// assert_eq!("user=alice, pid=-, host=db1", format!("{}", record));
+/// assert_eq!("user=alice host=db1", format!("{}", record.omit_missing(true).separator(" ")));
+/// ```
+///
+/// [`FmtOr::fmt_or`]: crate::FmtOr::fmt_or
+#[derive(Copy, Clone)]
+pub struct FieldSet<'a, const N: usize> {
+    fields: [(&'a str, Option<&'a dyn Display>, &'a str); N],
+    len: usize,
+    sep: &'a str,
+    omit_missing: bool,
+}
+
+impl<'a, const N: usize> FieldSet<'a, N> {
+    /// Creates an empty record.
+    pub const fn new() -> Self {
+        Self {
+            fields: [("", None, ""); N],
+            len: 0,
+            sep: ", ",
+            omit_missing: false,
+        }
+    }
+    /// Adds a field shown as `name=value`, or as `name=fallback` if the value is missing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the record already has `N` fields.
+    pub fn field<T: Display>(
+        mut self,
+        name: &'a str,
+        opt: &'a Option<T>,
+        fallback: &'a str,
+    ) -> Self {
+        assert!(self.len < N, "FieldSet is full");
+        self.fields[self.len] = (name, opt.as_ref().map(|t| t as &dyn Display), fallback);
+        self.len += 1;
+        self
+    }
+    /// Sets whether missing fields are left out, instead of shown with their fallback.
+    pub fn omit_missing(mut self, omit: bool) -> Self {
+        self.omit_missing = omit;
+        self
+    }
+    /// Sets the separator written between fields, instead of `, `.
+    pub fn separator(mut self, sep: &'a str) -> Self {
+        self.sep = sep;
+        self
+    }
+}
+
+impl<'a, const N: usize> Default for FieldSet<'a, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, const N: usize> Display for FieldSet<'a, N> {
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        pad(out, Alignment::Left, |w: &mut dyn Write| {
+            let shown = self.fields[..self.len]
+                .iter()
+                .filter(|(_, value, _)| value.is_some() || !self.omit_missing);
+            for (i, (name, value, fallback)) in shown.enumerate() {
+                if i > 0 {
+                    w.write_str(self.sep)?;
+                }
+                write!(w, "{}={}", name, value.fmt_or(fallback))?;
+            }
+            Ok(())
+        })
+    }
+}
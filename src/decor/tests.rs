@@ -0,0 +1,333 @@
+use crate::{
+    fmt_settings_block_or, fmt_tree_or, FieldFormat, FieldSet, FmtOr, FmtOrDecor, Source,
+    StatusLine, Tier,
+};
+
+#[test]
+fn test_source() {
+    let value = Some("alice");
+    assert_eq!(
+        "[db] alice",
+        format!("{}", value.fmt_or_source(Source::Db, "?"))
+    );
+    assert_eq!(
+        "[cache] alice",
+        format!("{}", value.fmt_or_source(Source::Cache, "?"))
+    );
+    assert_eq!(
+        "[ldap] alice",
+        format!("{}", value.fmt_or_source(Source::Custom("ldap"), "?"))
+    );
+    assert_eq!(
+        "?",
+        format!("{}", None::<&str>.fmt_or_source(Source::Db, "?"))
+    );
+}
+
+#[test]
+fn test_source_width() {
+    assert_eq!(
+        "[db] 7   ",
+        format!("{:9}", Some(7).fmt_or_source(Source::Db, "?"))
+    );
+    assert_eq!(
+        "        ?",
+        format!("{:>9}", None::<u8>.fmt_or_source(Source::Db, "?"))
+    );
+}
+
+#[test]
+fn test_task() {
+    let task = Some("ship it");
+    assert_eq!("[✓] ship it", format!("{}", task.fmt_task_or(true, "n/a")));
+    assert_eq!("[ ] ship it", format!("{}", task.fmt_task_or(false, "n/a")));
+    assert_eq!(
+        "[ ] n/a",
+        format!("{}", None::<&str>.fmt_task_or(true, "n/a"))
+    );
+    assert_eq!(
+        "[ ] n/a",
+        format!("{}", None::<&str>.fmt_task_or(false, "n/a"))
+    );
+    assert_eq!(
+        "[ ] n/a  ",
+        format!("{:9}", None::<&str>.fmt_task_or(false, "n/a"))
+    );
+}
+
+#[test]
+fn test_tier() {
+    let fmt = |opt: Option<u32>, tier| format!("{}", opt.fmt_or_tier(tier, "new", "old", "gone"));
+    assert_eq!("7", fmt(Some(7), Tier::Fresh));
+    assert_eq!("7 (stale)", fmt(Some(7), Tier::Stale));
+    assert_eq!("7 (missing)", fmt(Some(7), Tier::Missing));
+    assert_eq!("new", fmt(None, Tier::Fresh));
+    assert_eq!("old", fmt(None, Tier::Stale));
+    assert_eq!("gone", fmt(None, Tier::Missing));
+    assert_eq!(
+        "  gone",
+        format!(
+            "{:>6}",
+            None::<u8>.fmt_or_tier(Tier::Missing, "new", "old", "gone")
+        )
+    );
+}
+
+#[test]
+fn test_annotate_with() {
+    use std::cell::Cell;
+
+    let calls = Cell::new(0);
+    let parity = |n: &i32| {
+        calls.set(calls.get() + 1);
+        if n % 2 == 0 {
+            "even"
+        } else {
+            "odd"
+        }
+    };
+    assert_eq!(
+        "42 (even)",
+        format!("{}", Some(42).fmt_or_annotate_with(parity, "n/a"))
+    );
+    assert_eq!(
+        "7 (odd)",
+        format!("{}", Some(7).fmt_or_annotate_with(parity, "n/a"))
+    );
+    assert_eq!(
+        "n/a",
+        format!("{}", None.fmt_or_annotate_with(parity, "n/a"))
+    );
+    assert_eq!(2, calls.get());
+    assert_eq!(
+        "7 (odd)   ",
+        format!("{:10}", Some(7).fmt_or_annotate_with(parity, "n/a"))
+    );
+    assert_eq!(3, calls.get());
+}
+
+#[test]
+fn test_aligned_kv() {
+    let host = Some("localhost");
+    let port = Some(8080);
+    let user: Option<&str> = None;
+    let lines = [
+        format!("{}", host.fmt_aligned_kv_or("host", 8, "-")),
+        format!("{}", port.fmt_aligned_kv_or("port", 8, "-")),
+        format!("{}", user.fmt_aligned_kv_or("username", 8, "-")),
+    ];
+    assert_eq!(
+        ["host    : localhost", "port    : 8080", "username: -"],
+        lines
+    );
+    assert_eq!(
+        "verbosity: 2",
+        format!("{}", Some(2).fmt_aligned_kv_or("verbosity", 4, "-"))
+    );
+    assert_eq!(
+        "id: -  ",
+        format!("{:7}", None::<u8>.fmt_aligned_kv_or("id", 2, "-"))
+    );
+}
+
+#[test]
+fn test_field_format() {
+    let field = FieldFormat::new("id=", " ");
+    for opt in &[Some(7), Some(-12345), None] {
+        let uncached = match opt {
+            Some(_) => format!("id={} ", opt.fmt_or("")),
+            None => format!("{}", opt.fmt_or("?")),
+        };
+        assert_eq!(uncached, format!("{}", field.render_or(opt, "?")));
+    }
+    assert_eq!("id=7    ", format!("{:8}", field.render_or(&Some(7), "?")));
+    assert_eq!(
+        "       ?",
+        format!("{:>8}", field.render_or(&None::<u8>, "?"))
+    );
+}
+
+#[test]
+fn test_smart_align() {
+    let numbers = [Some(7), Some(-1200), None];
+    let column: Vec<_> = numbers
+        .iter()
+        .map(|n| format!("{}", n.fmt_smart_align_or(6, "n/a")))
+        .collect();
+    assert_eq!(["     7", " -1200", "n/a   "], column[..]);
+
+    let names = [Some("ann"), None, Some("bartholomew")];
+    let column: Vec<_> = names
+        .iter()
+        .map(|n| format!("{}", n.fmt_smart_align_or(6, "?")))
+        .collect();
+    assert_eq!(["ann   ", "?     ", "bartholomew"], column[..]);
+
+    assert_eq!(
+        "   2.5",
+        format!("{:.2}", Some(2.5f32).fmt_smart_align_or(6, "-"))
+    );
+}
+
+#[test]
+fn test_split_align() {
+    let fmt = |opt: Option<i32>| format!("{:10}", opt.fmt_or_split_align("—"));
+    assert_eq!("        42", fmt(Some(42)));
+    assert_eq!("—         ", fmt(None));
+    assert_eq!(
+        "        -7",
+        format!("{:<10}", Some(-7).fmt_or_split_align("—"))
+    );
+    assert_eq!(
+        "n/a*******",
+        format!("{:*^10}", None::<u8>.fmt_or_split_align("n/a"))
+    );
+    assert_eq!("42", format!("{}", Some(42).fmt_or_split_align("—")));
+}
+
+#[test]
+fn test_settings_block() {
+    let settings = [
+        ("threads", Some(8)),
+        ("timeout", None),
+        ("retries", Some(3)),
+        ("log_level_override", None),
+    ];
+    assert_eq!(
+        "threads   8\ntimeout   -\nretries   3\nlog_level_override  -",
+        format!("{}", fmt_settings_block_or(&settings, 8, "-"))
+    );
+    assert_eq!(
+        "",
+        format!("{}", fmt_settings_block_or::<u8, _>(&[], 8, "-"))
+    );
+}
+
+#[test]
+fn test_tree() {
+    let fields = [("cpu", Some(4)), ("gpu", None), ("ram", Some(16))];
+    assert_eq!(
+        "├─ cpu: 4\n├─ gpu: (none)\n└─ ram: 16",
+        format!("{}", fmt_tree_or(&fields, "(none)"))
+    );
+    assert_eq!(
+        "└─ only: (none)",
+        format!("{}", fmt_tree_or(&[("only", None::<u8>)], "(none)"))
+    );
+    assert_eq!("", format!("{}", fmt_tree_or::<u8, _>(&[], "(none)")));
+}
+
+#[test]
+fn test_display_with_debug() {
+    struct Celsius(f32);
+    impl std::fmt::Display for Celsius {
+        fn fmt(&self, out: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(out, "{}°C", self.0)
+        }
+    }
+    impl std::fmt::Debug for Celsius {
+        fn fmt(&self, out: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(out, "Celsius({:?})", self.0)
+        }
+    }
+    assert_eq!(
+        "21.5°C (Celsius(21.5))",
+        format!("{}", Some(Celsius(21.5)).fmt_display_with_debug_or("n/a"))
+    );
+    assert_eq!(
+        "n/a",
+        format!("{}", None::<Celsius>.fmt_display_with_debug_or("n/a"))
+    );
+    assert_eq!(
+        "a (\"a\")  ",
+        format!("{:9}", Some("a").fmt_display_with_debug_or("n/a"))
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_term_fit() {
+    use super::{parse_columns, MaybeFormatTermFit};
+
+    assert_eq!(80, parse_columns(None));
+    assert_eq!(80, parse_columns(Some("")));
+    assert_eq!(80, parse_columns(Some("wide")));
+    assert_eq!(80, parse_columns(Some("0")));
+    assert_eq!(120, parse_columns(Some("120\n")));
+
+    let line = Some("the quick brown fox");
+    let fit = |columns| format!("{}", MaybeFormatTermFit(&line, columns, "-"));
+    assert_eq!("the quick brown fox", fit(80));
+    assert_eq!("the quick brown fox", fit(19));
+    assert_eq!("the quick brown f…", fit(18));
+    assert_eq!("…", fit(1));
+    assert_eq!(
+        "-",
+        format!("{}", MaybeFormatTermFit(&None::<&str>, 1, "-"))
+    );
+    assert_eq!("-", format!("{}", None::<&str>.fmt_term_fit_or("-")));
+}
+
+#[test]
+fn test_status_line() {
+    let present = Some(1);
+    let missing = None::<u32>;
+    let status = StatusLine::<8>::new()
+        .entry('A', &present)
+        .entry('B', &missing)
+        .entry('C', &present)
+        .entry('D', &missing);
+    assert_eq!("A-C-", format!("{}", status));
+    assert_eq!("  A-C-", format!("{:>6}", status));
+    assert_eq!("A_C_", format!("{}", status.absent('_')));
+    assert_eq!("", format!("{}", StatusLine::<2>::default()));
+    assert_eq!(
+        "--",
+        format!(
+            "{}",
+            StatusLine::<2>::new()
+                .entry('X', &missing)
+                .entry('Y', &None::<&str>)
+        )
+    );
+}
+
+#[test]
+#[should_panic(expected = "StatusLine is full")]
+fn test_status_line_full() {
+    let _ = StatusLine::<1>::new()
+        .entry('A', &Some(1))
+        .entry('B', &Some(2));
+}
+
+#[test]
+fn test_field_set() {
+    let (user, pid, host) = (Some("alice"), None::<u32>, Some(5));
+    let record = FieldSet::<3>::new()
+        .field("user", &user, "?")
+        .field("pid", &pid, "-")
+        .field("port", &host, "?");
+    assert_eq!("user=alice, pid=-, port=5", format!("{}", record));
+    assert_eq!(
+        "user=alice, port=5",
+        format!("{}", record.omit_missing(true))
+    );
+    assert_eq!(
+        "[user=alice|port=5   ]",
+        format!("[{:20}]", record.omit_missing(true).separator("|"))
+    );
+
+    let none = None::<u8>;
+    let missing = FieldSet::<1>::new().field("a", &none, "n/a");
+    assert_eq!("a=n/a", format!("{}", missing));
+    assert_eq!("", format!("{}", missing.omit_missing(true)));
+    assert_eq!("", format!("{}", FieldSet::<2>::default()));
+}
+
+#[test]
+#[should_panic(expected = "FieldSet is full")]
+fn test_field_set_full() {
+    let _ = FieldSet::<1>::new()
+        .field("a", &Some(1), "-")
+        .field("b", &Some(2), "-");
+}
@@ -0,0 +1,192 @@
+//! Formatting options nested to any depth.
+
+#[cfg(test)]
+mod tests;
+
+use core::fmt::{
+    Binary, Debug, Display, Formatter, LowerExp, LowerHex, Octal, Pointer, Result, UpperExp,
+    UpperHex,
+};
+use core::time::Duration;
+
+/// Marks a type as the innermost value of nested options for [`DeepFmtOr`].
+///
+/// Nesting is followed through [`Option`] until a type implementing this trait is reached. It is
+/// implemented for the primitive types, `str`, [`Duration`] and references to any of these, and
+/// can be implemented for your own types:
+///
+/// ```rust
+/// use fmtor::{DeepFmtOr, DeepLeaf};
+///
+/// struct Id(u32);
+/// impl std::fmt::Display for Id {
+///     fn fmt(&self, out: &mut std::fmt::Formatter) -> std::fmt::Result {
+///         write!(out, "#{}", self.0)
+///     }
+/// }
+/// impl DeepLeaf for Id {}
+///
+/// let id = Some(Some(Id(7)));
+/// assert_eq!("#7", format!("{}", id.deep_fmt_or("-")));
+/// ```
+pub trait DeepLeaf {}
+
+macro_rules! impl_deep_leaf {
+    ($($T:ty),*$(,)?) => {$(
+        impl DeepLeaf for $T {}
+    )*};
+}
+
+impl_deep_leaf!(
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    f32,
+    f64,
+    bool,
+    char,
+    str,
+    (),
+    Duration,
+);
+impl<T: DeepLeaf + ?Sized> DeepLeaf for &T {}
+impl<T: DeepLeaf + ?Sized> DeepLeaf for &mut T {}
+
+/// Finds the innermost value of nested options. This is an implementation detail of [`DeepFmtOr`].
+#[doc(hidden)]
+pub trait Deep {
+    type Leaf: ?Sized;
+    fn deep_leaf(&self) -> Option<&Self::Leaf>;
+}
+
+impl<T: DeepLeaf> Deep for T {
+    type Leaf = T;
+    #[inline]
+    fn deep_leaf(&self) -> Option<&T> {
+        Some(self)
+    }
+}
+
+impl<T: Deep> Deep for Option<T> {
+    type Leaf = T::Leaf;
+    #[inline]
+    fn deep_leaf(&self) -> Option<&T::Leaf> {
+        self.as_ref()?.deep_leaf()
+    }
+}
+
+/// The type returned from [`DeepFmtOr::deep_fmt_or`] and [`FmtOrFlatten::fmt_or_flat`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatDeep<'t, T: ?Sized, U>(Option<&'t T>, U);
+
+/// An extension trait for options nested to any depth, such as `Option<Option<Option<T>>>`.
+///
+/// The nesting is followed until a [`DeepLeaf`] type is reached, so the innermost type must
+/// implement [`DeepLeaf`]. For exactly two levels with any inner type, see [`FmtOrFlatten`].
+#[allow(clippy::needless_lifetimes)] // They're nice to see in docs
+pub trait DeepFmtOr {
+    /// The innermost type.
+    type Leaf: ?Sized;
+    /// Format the innermost value, if every level is present, or display the given value instead.
+    ///
+    /// The returned wrapper implements all the same format traits as the innermost type, just
+    /// like [`FmtOr::fmt_or`]. A [`None`] at any level displays the fallback.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::DeepFmtOr;
+    ///
+    /// let present = Some(Some(Some(0x42)));
+    /// let missing: Option<Option<Option<u32>>> = Some(None);
+    ///
+    /// assert_eq!("0x42", format!("{:#x}", present.deep_fmt_or("none")));
+    /// assert_eq!("none", format!("{:#x}", missing.deep_fmt_or("none")));
+    /// ```
+    ///
+    /// [`FmtOr::fmt_or`]: crate::FmtOr::fmt_or
+    fn deep_fmt_or<'t, U>(&'t self, fallback: U) -> MaybeFormatDeep<'t, Self::Leaf, U>
+    where
+        U: Display;
+}
+
+impl<T: Deep> DeepFmtOr for Option<T> {
+    type Leaf = T::Leaf;
+    #[inline]
+    fn deep_fmt_or<U>(&self, fallback: U) -> MaybeFormatDeep<'_, T::Leaf, U>
+    where
+        U: Display,
+    {
+        MaybeFormatDeep(self.deep_leaf(), fallback)
+    }
+}
+
+/// An extension trait for [`Option<Option<T>>`], such as an optional setting which may be
+/// explicitly unset.
+#[allow(clippy::needless_lifetimes)] // They're nice to see in docs
+pub trait FmtOrFlatten<T> {
+    /// Format the inner value, if both levels are present, or display the given value instead.
+    ///
+    /// `None` and `Some(None)` both display the given value. The returned wrapper implements all
+    /// the same format traits as `T`, just like [`FmtOr::fmt_or`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrFlatten;
+    ///
+    /// let set = Some(Some(0x42));
+    /// let unset: Option<Option<u32>> = Some(None);
+    /// let missing: Option<Option<u32>> = None;
+    ///
+    /// assert_eq!("0x42", format!("{:#x}", set.fmt_or_flat("-")));
+    /// assert_eq!("-", format!("{:#x}", unset.fmt_or_flat("-")));
+    /// assert_eq!("-", format!("{:#x}", missing.fmt_or_flat("-")));
+    /// ```
+    ///
+    /// [`FmtOr::fmt_or`]: crate::FmtOr::fmt_or
+    fn fmt_or_flat<'t, U>(&'t self, u: U) -> MaybeFormatDeep<'t, T, U>
+    where
+        U: Display;
+}
+
+impl<T> FmtOrFlatten<T> for Option<Option<T>> {
+    #[inline]
+    fn fmt_or_flat<U>(&self, u: U) -> MaybeFormatDeep<'_, T, U>
+    where
+        U: Display,
+    {
+        MaybeFormatDeep(self.as_ref().and_then(Option::as_ref), u)
+    }
+}
+
+macro_rules! impl_fmt_traits {
+    ($($Trait:ident),*$(,)?) => {$(
+
+impl<'t, T, U> $Trait for MaybeFormatDeep<'t, T, U>
+where
+    T: $Trait + ?Sized,
+    U: Display,
+{
+    #[inline]
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) => <T as $Trait>::fmt(t, out),
+            None => Display::fmt(&self.1, out),
+        }
+    }
+}
+
+    )*}
+} // macro_rules! impl_fmt_traits
+
+impl_fmt_traits!(Binary, Debug, Display, LowerExp, LowerHex, Octal, Pointer, UpperExp, UpperHex);
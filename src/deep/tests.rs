@@ -0,0 +1,49 @@
+use crate::{DeepFmtOr, FmtOrFlatten};
+
+#[test]
+fn test_deep_three_levels() {
+    let present: Option<Option<Option<u32>>> = Some(Some(Some(10)));
+    assert_eq!("10", format!("{}", present.deep_fmt_or("-")));
+    assert_eq!("0xa", format!("{:#x}", present.deep_fmt_or("-")));
+    assert_eq!("1010", format!("{:b}", present.deep_fmt_or("-")));
+    for missing in &[None, Some(None), Some(Some(None::<u32>))] {
+        assert_eq!("-", format!("{:x}", missing.deep_fmt_or("-")));
+    }
+}
+
+#[test]
+fn test_deep_four_levels() {
+    let present = Some(Some(Some(Some("deep"))));
+    assert_eq!(r#""deep""#, format!("{:?}", present.deep_fmt_or("-")));
+    assert_eq!("deep  ", format!("{:6}", present.deep_fmt_or("-")));
+    let missing: Option<Option<Option<Option<&str>>>> = Some(Some(None));
+    assert_eq!("     -", format!("{:>6}", missing.deep_fmt_or("-")));
+}
+
+#[test]
+fn test_deep_shallow() {
+    assert_eq!("1e3", format!("{:e}", Some(1000.0).deep_fmt_or("-")));
+    assert_eq!("-", format!("{:e}", None::<f64>.deep_fmt_or("-")));
+}
+
+#[test]
+fn test_flat() {
+    let set: Option<Option<u32>> = Some(Some(26));
+    let unset: Option<Option<u32>> = Some(None);
+    let missing: Option<Option<u32>> = None;
+
+    assert_eq!("26", format!("{}", set.fmt_or_flat("-")));
+    assert_eq!("26", format!("{:?}", set.fmt_or_flat("-")));
+    assert_eq!("0x1a", format!("{:#x}", set.fmt_or_flat("-")));
+    for opt in &[unset, missing] {
+        assert_eq!("-", format!("{}", opt.fmt_or_flat("-")));
+        assert_eq!("-", format!("{:?}", opt.fmt_or_flat("-")));
+        assert_eq!("-", format!("{:#x}", opt.fmt_or_flat("-")));
+        assert_eq!("  -", format!("{:>3x}", opt.fmt_or_flat("-")));
+    }
+
+    #[derive(Debug)]
+    struct NotDisplay;
+    let nested = Some(Some(NotDisplay));
+    assert_eq!("NotDisplay", format!("{:?}", nested.fmt_or_flat("-")));
+}
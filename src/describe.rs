@@ -0,0 +1,107 @@
+//! Tables of how an option renders under each formatting trait, for debugging.
+
+#[cfg(test)]
+mod tests;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{
+    Binary, Debug, Display, LowerExp, LowerHex, Octal, Pointer, UpperExp, UpperHex, Write,
+};
+
+use crate::FmtOr;
+
+/// A builder for a table of how an option renders with [`fmt_or`](FmtOr::fmt_or) under each
+/// formatting trait.
+///
+/// Not every type implements every trait, so each trait is added with its own method, which
+/// requires just that trait. Each row pairs the name of the trait with the rendering. The
+/// alternate flag is always given, as in `{:#x}`, so that radix prefixes show.
+///
+/// Requires the `alloc` feature.
+///
+/// # Example
+///
+/// ```rust
+/// use fmtor::Describe;
+///
+/// let table = Describe::new(&Some(1.5), "n/a").display().lower_exp().finish();
+///
+/// assert_eq!(vec![("Display", "1.5".to_string()), ("LowerExp", "1.5e0".to_string())], table);
+/// ```
+pub struct Describe<'t, T> {
+    opt: &'t Option<T>,
+    fallback: &'t str,
+    rows: Vec<(&'static str, String)>,
+}
+
+impl<'t, T> Describe<'t, T> {
+    /// Starts an empty table for `opt`, which renders `fallback` when the value is missing.
+    pub fn new(opt: &'t Option<T>, fallback: &'t str) -> Self {
+        Describe {
+            opt,
+            fallback,
+            rows: Vec::new(),
+        }
+    }
+    /// Returns the rows added so far, in the order they were added.
+    pub fn finish(self) -> Vec<(&'static str, String)> {
+        self.rows
+    }
+}
+
+macro_rules! describe_methods {
+    ($($method:ident $Trait:ident $spec:literal),*$(,)?) => {
+        impl<'t, T> Describe<'t, T> {$(
+            #[doc = concat!("Adds a row rendering with [`", stringify!($Trait), "`], as with `", $spec, "`.")]
+            pub fn $method(mut self) -> Self
+            where
+                T: $Trait,
+            {
+                let mut s = String::new();
+                write!(s, $spec, self.opt.fmt_or(self.fallback))
+                    .expect("a formatting trait implementation returned an error");
+                self.rows.push((stringify!($Trait), s));
+                self
+            }
+        )*}
+    };
+}
+
+describe_methods!(
+    binary Binary "{:#b}",
+    debug Debug "{:#?}",
+    display Display "{:#}",
+    lower_exp LowerExp "{:#e}",
+    lower_hex LowerHex "{:#x}",
+    octal Octal "{:#o}",
+    pointer Pointer "{:#p}",
+    upper_exp UpperExp "{:#E}",
+    upper_hex UpperHex "{:#X}",
+);
+
+/// Renders an integer option under [`Display`], [`Debug`], [`Binary`], [`Octal`], [`LowerHex`]
+/// and [`UpperHex`], in that order.
+///
+/// This is a shortcut for the matching [`Describe`] methods. Requires the `alloc` feature.
+///
+/// # Example
+///
+/// ```rust
+/// let table = fmtor::describe_numeric(&Some(10u32), "n/a");
+///
+/// assert_eq!(("LowerHex", "0xa".to_string()), table[4]);
+/// ```
+pub fn describe_numeric<T>(opt: &Option<T>, fallback: &str) -> Vec<(&'static str, String)>
+where
+    T: Display + Debug + Binary + Octal + LowerHex + UpperHex,
+{
+    Describe::new(opt, fallback)
+        .display()
+        .debug()
+        .binary()
+        .octal()
+        .lower_hex()
+        .upper_hex()
+        .finish()
+}
@@ -0,0 +1,50 @@
+use crate::{describe_numeric, Describe};
+
+fn owned(rows: &[(&'static str, &str)]) -> Vec<(&'static str, String)> {
+    rows.iter()
+        .map(|&(name, s)| (name, s.to_string()))
+        .collect()
+}
+
+#[test]
+fn test_describe_numeric() {
+    let expected = owned(&[
+        ("Display", "10"),
+        ("Debug", "10"),
+        ("Binary", "0b1010"),
+        ("Octal", "0o12"),
+        ("LowerHex", "0xa"),
+        ("UpperHex", "0xA"),
+    ]);
+    assert_eq!(expected, describe_numeric(&Some(10u32), "n/a"));
+}
+
+#[test]
+fn test_describe_numeric_missing() {
+    let table = describe_numeric(&None::<u32>, "n/a");
+    assert_eq!(6, table.len());
+    assert!(table.iter().all(|(_, s)| s == "n/a"), "{:?}", table);
+}
+
+#[test]
+fn test_describe_builder() {
+    let value = Some(10u32);
+    let table = Describe::new(&value, "-")
+        .upper_hex()
+        .lower_exp()
+        .upper_exp()
+        .finish();
+    assert_eq!(
+        owned(&[
+            ("UpperHex", "0xA"),
+            ("LowerExp", "1e1"),
+            ("UpperExp", "1E1")
+        ]),
+        table
+    );
+    assert!(Describe::new(&value, "-").finish().is_empty());
+
+    let missing: Option<&u32> = None;
+    let table = Describe::new(&missing, "null").pointer().finish();
+    assert_eq!(owned(&[("Pointer", "null")]), table);
+}
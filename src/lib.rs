@@ -36,20 +36,224 @@
 //! ```
 //!
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(doctest)]
+mod compile_fail;
 #[cfg(test)]
 mod tests;
 
+mod macros;
+
+mod ansi;
+mod autoref;
+mod bytes;
+mod chain;
+mod counter;
+mod decor;
+mod deep;
+#[cfg(feature = "alloc")]
+mod describe;
+mod null;
+mod num;
+mod opt;
+mod owned;
+mod pad;
+mod ptr;
+mod result;
+mod select;
+mod sentinel;
+#[cfg(feature = "alloc")]
+mod shared;
+mod slice;
+mod text;
+mod time;
+#[cfg(feature = "alloc")]
+mod to_string;
+mod tuple;
+
+pub use crate::autoref::{MaybeFormatDisplayOrDebug, UseDebug, UseDisplay};
+pub use crate::bytes::*;
+pub use crate::chain::*;
+pub use crate::counter::*;
+pub use crate::decor::*;
+pub use crate::deep::*;
+#[cfg(feature = "alloc")]
+pub use crate::describe::*;
+pub use crate::null::*;
+pub use crate::num::*;
+pub use crate::opt::*;
+pub use crate::owned::*;
+pub use crate::ptr::*;
+pub use crate::result::*;
+pub use crate::select::*;
+pub use crate::sentinel::*;
+#[cfg(feature = "alloc")]
+pub use crate::shared::*;
+pub use crate::slice::*;
+pub use crate::text::*;
+pub use crate::time::*;
+#[cfg(feature = "alloc")]
+pub use crate::to_string::*;
+pub use crate::tuple::*;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::autoref::{DebugProbe, DisplayProbe, Probe};
+}
+
+use core::any::type_name;
+use core::cell::{Cell, OnceCell};
+use core::fmt::Alignment;
 use core::fmt::{
-    Binary, Debug, Display, Formatter, LowerExp, LowerHex, Octal, Pointer, Result, UpperExp,
-    UpperHex,
+    Arguments, Binary, Debug, Display, Formatter, LowerExp, LowerHex, Octal, Pointer, Result,
+    UpperExp, UpperHex, Write,
 };
+use core::marker::PhantomData;
+
+use crate::pad::pad;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
 /// The type returned from [`FmtOr::fmt_or_empty`]
-#[derive(Eq, PartialEq)]
+///
+/// Wrappers are equal when their options are equal, and order like their options, with a missing
+/// value before any present one. This makes them usable as sort keys.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[must_use = "wrappers do nothing unless formatted"]
 pub struct MaybeFormat<'t, T>(&'t Option<T>);
 /// The type returned from [`FmtOr::fmt_or`]
+///
+/// Wrappers are equal when both their options and their fallbacks are equal, and hash both. They
+/// order by their options first, with a missing value before any present one, then by their
+/// fallbacks.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[must_use = "wrappers do nothing unless formatted"]
 pub struct MaybeFormatOr<'t, T, U>(&'t Option<T>, U);
 /// The type returned from [`FmtOr::fmt_or_else`]
+#[must_use = "wrappers do nothing unless formatted"]
 pub struct MaybeFormatOrElse<'t, T, F>(&'t Option<T>, F);
+/// The type returned from [`MaybeFormat::with_default`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatEmpty<'t, T>(&'t Option<T>, &'static str);
+/// The type returned from [`FmtOr::fmt_or_default_display`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatOrDefaultDisplay<'t, T, U>(&'t Option<T>, PhantomData<fn() -> U>);
+/// The type returned from [`FmtOr::fmt_or_typed_none`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatTypedNone<'t, T>(&'t Option<T>);
+/// The type returned from [`FmtOr::fmt_or_default`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatDefault<'t, T>(&'t Option<T>);
+/// The type returned from [`FmtOr::fmt_or_same`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatSame<'t, T>(&'t Option<T>, T);
+/// The type returned from [`FmtOr::fmt_or_else_cached`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatOrElseCached<'t, T, F, U>(&'t Option<T>, Cell<Option<F>>, OnceCell<U>);
+/// The type returned from [`FmtOr::fmt_or_else_once`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatOrElseOnce<'t, T, F>(&'t Option<T>, Cell<Option<F>>);
+/// The type returned from [`FmtOr::fmt_or_eager`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatOrEager<'t, T, U>(&'t Option<T>, Option<U>);
+/// The type returned from [`FmtOr::fmt_or_args`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatOrArgs<'t, T>(&'t Option<T>, Arguments<'t>);
+/// The type returned from [`FmtOr::fmt_or_debug`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatOrDebug<'t, T, U>(&'t Option<T>, U);
+/// The type returned from [`FmtOr::fmt_wrap`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatWrap<'t, T>(&'t Option<T>, &'t str, &'t str);
+/// The type returned from [`FmtOr::fmt_present`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatPresent<'t, T>(&'t Option<T>, &'t str);
+/// The type returned from [`FmtOr::fmt_map`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatMap<'t, T, G>(&'t Option<T>, G);
+/// The type returned from [`MaybeFormatMap::or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatMapOr<'t, T, G, U>(&'t Option<T>, G, U);
+/// The type returned from [`MaybeFormatMap::or_else`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatMapOrElse<'t, T, G, F>(&'t Option<T>, G, F);
+/// The type returned from [`FmtOr::fmt_or_num`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatOrNum<'t, T, N>(&'t Option<T>, N);
+/// The type returned from [`FmtOr::fmt_or_debug_none`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatDebugNone<'t, T>(&'t Option<T>);
+/// The type returned from [`FmtOr::fmt_or_error`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatOrError<'t, T>(&'t Option<T>);
+/// The type returned from [`FmtOr::fmt_set_or_unset`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatSetOrUnset<'t, T>(&'t Option<T>, &'t str, &'t str);
+/// The type returned from [`FmtOr::fmt_or_ref`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatOrRef<'t, T, U: ?Sized>(&'t Option<T>, &'t U);
+/// The type returned from [`FmtOr::fmt_or_hex`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatOrHex<'t, T, N>(&'t Option<T>, N);
+/// The type returned from [`FmtOr::fmt_or_char`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatOrChar<'t, T>(&'t Option<T>, char);
+/// The type returned from [`FmtOr::fmt_or_else_ctx`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatOrElseCtx<'t, T, F>(&'t Option<T>, F);
+/// The type returned from [`FmtOr::fmt_display_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatDisplayWith<'t, T, G>(&'t Option<T>, G, &'t str);
+/// The type returned from [`FmtOr::fmt_either`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatEither<'t, T, P, A>(&'t Option<T>, P, A);
+/// The type returned from [`FmtOr::fmt_or_with`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatOrWith<'t, T, G>(&'t Option<T>, G);
+/// The type returned from [`FmtOr::fmt_or_map_opt`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatOrMapOpt<'t, T, B, G>(&'t Option<T>, &'t Option<B>, G);
+/// The type returned from [`FmtOr::fmt_or_dyn_all`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatOrDynAll<'t, 'u, T>(&'t Option<T>, &'u dyn AllFmt);
+
+/// A type which implements all nine formatting traits, for use as a `&dyn AllFmt` fallback.
+///
+/// This is implemented for every type which implements [`Binary`], [`Debug`], [`Display`],
+/// [`LowerExp`], [`LowerHex`], [`Octal`], [`Pointer`], [`UpperExp`] and [`UpperHex`].
+///
+/// See [`FmtOr::fmt_or_dyn_all`].
+pub trait AllFmt:
+    Binary + Debug + Display + LowerExp + LowerHex + Octal + Pointer + UpperExp + UpperHex
+{
+}
+
+impl<T> AllFmt for T where
+    T: Binary
+        + Debug
+        + Display
+        + LowerExp
+        + LowerHex
+        + Octal
+        + Pointer
+        + UpperExp
+        + UpperHex
+        + ?Sized
+{
+}
+
+impl<'t, T> Copy for MaybeFormatEmpty<'t, T> {}
+impl<'t, T> Clone for MaybeFormatEmpty<'t, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
 
 impl<'t, T> Copy for MaybeFormat<'t, T> {}
 impl<'t, T> Clone for MaybeFormat<'t, T> {
@@ -72,124 +276,731 @@ impl<'t, T, F: Clone> Clone for MaybeFormatOrElse<'t, T, F> {
     }
 }
 
-/// An extension trait for [`Option<T>`]. The methods on this trait are the inteded way to use this crate.
-///
-/// # TLDR
-///
-/// The methods on this trait allow a missing [`Option<T>`] value to be formatted as if it
-/// were a `T`, however [`None`] is replaced by some other value.
-///
-/// # Details
+impl<'t, T, U> Copy for MaybeFormatOrDefaultDisplay<'t, T, U> {}
+impl<'t, T, U> Clone for MaybeFormatOrDefaultDisplay<'t, T, U> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'t, T> Copy for MaybeFormatTypedNone<'t, T> {}
+impl<'t, T> Clone for MaybeFormatTypedNone<'t, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'t, T> Copy for MaybeFormatDefault<'t, T> {}
+impl<'t, T> Clone for MaybeFormatDefault<'t, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'t, T: Copy> Copy for MaybeFormatSame<'t, T> {}
+impl<'t, T: Clone> Clone for MaybeFormatSame<'t, T> {
+    fn clone(&self) -> Self {
+        Self(self.0, self.1.clone())
+    }
+}
+
+impl<'t, T> Copy for MaybeFormatOrArgs<'t, T> {}
+impl<'t, T> Clone for MaybeFormatOrArgs<'t, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'t, T, U: Copy> Copy for MaybeFormatOrDebug<'t, T, U> {}
+impl<'t, T, U: Clone> Clone for MaybeFormatOrDebug<'t, T, U> {
+    fn clone(&self) -> Self {
+        Self(self.0, self.1.clone())
+    }
+}
+
+impl<'t, T> Copy for MaybeFormatWrap<'t, T> {}
+impl<'t, T> Clone for MaybeFormatWrap<'t, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'t, T> Copy for MaybeFormatPresent<'t, T> {}
+impl<'t, T> Clone for MaybeFormatPresent<'t, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'t, T, G: Copy> Copy for MaybeFormatMap<'t, T, G> {}
+impl<'t, T, G: Clone> Clone for MaybeFormatMap<'t, T, G> {
+    fn clone(&self) -> Self {
+        Self(self.0, self.1.clone())
+    }
+}
+
+impl<'t, T, G: Copy, U: Copy> Copy for MaybeFormatMapOr<'t, T, G, U> {}
+impl<'t, T, G: Clone, U: Clone> Clone for MaybeFormatMapOr<'t, T, G, U> {
+    fn clone(&self) -> Self {
+        Self(self.0, self.1.clone(), self.2.clone())
+    }
+}
+
+impl<'t, T, G: Copy, F: Copy> Copy for MaybeFormatMapOrElse<'t, T, G, F> {}
+impl<'t, T, G: Clone, F: Clone> Clone for MaybeFormatMapOrElse<'t, T, G, F> {
+    fn clone(&self) -> Self {
+        Self(self.0, self.1.clone(), self.2.clone())
+    }
+}
+
+impl<'t, T, G> MaybeFormatMap<'t, T, G> {
+    /// Display the given value instead of nothing if there is no value.
+    ///
+    /// See [`FmtOr::fmt_or`].
+    #[inline]
+    pub fn or<U: Display>(self, u: U) -> MaybeFormatMapOr<'t, T, G, U> {
+        MaybeFormatMapOr(self.0, self.1, u)
+    }
+    /// Run the closure and display its result instead of nothing if there is no value.
+    ///
+    /// See [`FmtOr::fmt_or_else`].
+    #[inline]
+    pub fn or_else<U: Display, F: Fn() -> U>(self, f: F) -> MaybeFormatMapOrElse<'t, T, G, F> {
+        MaybeFormatMapOrElse(self.0, self.1, f)
+    }
+}
+
+impl<'t, T, N: Copy> Copy for MaybeFormatOrNum<'t, T, N> {}
+impl<'t, T, N: Clone> Clone for MaybeFormatOrNum<'t, T, N> {
+    fn clone(&self) -> Self {
+        Self(self.0, self.1.clone())
+    }
+}
+
+impl<'t, T, U: Copy> Copy for MaybeFormatOrEager<'t, T, U> {}
+impl<'t, T, U: Clone> Clone for MaybeFormatOrEager<'t, T, U> {
+    fn clone(&self) -> Self {
+        Self(self.0, self.1.clone())
+    }
+}
+
+impl<'t, T> Copy for MaybeFormatDebugNone<'t, T> {}
+impl<'t, T> Clone for MaybeFormatDebugNone<'t, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'t, T> Copy for MaybeFormatOrError<'t, T> {}
+impl<'t, T> Clone for MaybeFormatOrError<'t, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'t, T> Copy for MaybeFormatSetOrUnset<'t, T> {}
+impl<'t, T> Clone for MaybeFormatSetOrUnset<'t, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'t, T, U: ?Sized> Copy for MaybeFormatOrRef<'t, T, U> {}
+impl<'t, T, U: ?Sized> Clone for MaybeFormatOrRef<'t, T, U> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'t, T, N: Copy> Copy for MaybeFormatOrHex<'t, T, N> {}
+impl<'t, T, N: Clone> Clone for MaybeFormatOrHex<'t, T, N> {
+    fn clone(&self) -> Self {
+        Self(self.0, self.1.clone())
+    }
+}
+
+impl<'t, T> Copy for MaybeFormatOrChar<'t, T> {}
+impl<'t, T> Clone for MaybeFormatOrChar<'t, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'t, T, F: Copy> Copy for MaybeFormatOrElseCtx<'t, T, F> {}
+impl<'t, T, F: Clone> Clone for MaybeFormatOrElseCtx<'t, T, F> {
+    fn clone(&self) -> Self {
+        Self(self.0, self.1.clone())
+    }
+}
+
+impl<'t, T, G: Copy> Copy for MaybeFormatDisplayWith<'t, T, G> {}
+impl<'t, T, G: Clone> Clone for MaybeFormatDisplayWith<'t, T, G> {
+    fn clone(&self) -> Self {
+        Self(self.0, self.1.clone(), self.2)
+    }
+}
+
+impl<'t, T, P: Copy, A: Copy> Copy for MaybeFormatEither<'t, T, P, A> {}
+impl<'t, T, P: Clone, A: Clone> Clone for MaybeFormatEither<'t, T, P, A> {
+    fn clone(&self) -> Self {
+        Self(self.0, self.1.clone(), self.2.clone())
+    }
+}
+
+impl<'t, T, G: Copy> Copy for MaybeFormatOrWith<'t, T, G> {}
+impl<'t, T, G: Clone> Clone for MaybeFormatOrWith<'t, T, G> {
+    fn clone(&self) -> Self {
+        Self(self.0, self.1.clone())
+    }
+}
+
+impl<'t, T, B, G: Copy> Copy for MaybeFormatOrMapOpt<'t, T, B, G> {}
+impl<'t, T, B, G: Clone> Clone for MaybeFormatOrMapOpt<'t, T, B, G> {
+    fn clone(&self) -> Self {
+        Self(self.0, self.1, self.2.clone())
+    }
+}
+
+impl<'t, 'u, T> Copy for MaybeFormatOrDynAll<'t, 'u, T> {}
+impl<'t, 'u, T> Clone for MaybeFormatOrDynAll<'t, 'u, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+/// A view of a wrapper whose [`Debug`] impl shows the wrapper itself, returned from the `inspect`
+/// methods.
 ///
-/// The methods on this trait return types which implement all the same format traits as `T`.
-/// They borrow the [`Option<T>`] for the duration of the formatting operation.
+/// The wrappers implement [`Debug`] as one of the formatting traits they forward, so `{:?}` on a
+/// wrapper formats the underlying value (or the fallback) rather than the wrapper. Use this to see
+/// what a wrapper holds instead.
 ///
-/// When the value is [`None`], the formatting is replaced with another, whose type and value are determined
-/// by the user. The replacement type, `U`, need only implement [`Display`]. Regardless of the desired format
-/// of T, the format of U will *always* be [`Display`]. This makes it simple to replace non-[`Display`] formats with
-/// the anticipated values.
+/// # Example
 ///
 /// ```rust
-/// fn fallable_box_maker() -> Option<Box<()>> { None }
-///
 /// use fmtor::FmtOr;
 ///
-/// let maybe_box = fallable_box_maker();
-/// println!("Got a fallable box at {:p}", maybe_box.fmt_or("Null"));
-/// ```
+/// let foo = Some(7);
 ///
-/// # More formatting logic
+/// assert_eq!("7", format!("{:?}", foo.fmt_or("-")));
+/// assert_eq!(
+///     "MaybeFormatOr(Some(7), \"-\")",
+///     format!("{:?}", foo.fmt_or("-").inspect())
+/// );
+/// assert_eq!(
+///     "MaybeFormatOrElse(Some(7), \"<closure>\")",
+///     format!("{:?}", foo.fmt_or_else(|| "-").inspect())
+/// );
+/// ```
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct Inspect<'w, W>(&'w W);
+
+/// Which branch a wrapper would format, as returned from the `resolve` methods.
 ///
-/// Here's a real-ish example with surrounding formatting logic. Formatting is applied to log messages
-/// so that they include the optional file name and line number when either the file or both are present.
-/// Having just a line number by itself wouldn't be all that helpful.
+/// This lets tests check which value a wrapper picks without formatting it.
 ///
-/// ```rust no_run
-/// // Be warned: this is a hypothetical code snippet that may not compile on current versions of amethyst.
-/// // The code is not compiled by doctest, and therefore should not be relied upon as working.
+/// # Example
 ///
-/// use fmtor::FmtOr;
+/// ```rust
+/// use fmtor::{FmtOr, Resolved};
 ///
-/// // Generates log messeges like
-/// // [INFO] src\game\states\init.rs:56 - Init starting
-/// # #[cfg(never)]
-/// amethyst::Logger::from_config_formatter(config, |out, msg, record| {
-///     out.finish(format_args!(
-///         "[{level}] {file}{colon}{line}{spacer}{message}",
-///         level = record.level(),
-///         file = record.file().unwrap_or(""), // <-- This could be .fmt_or(""), but is not necessary
-///         colon = if record.file().is_some() { ":" } else { "" },
-///         line = record.file().and(record.line()).fmt_or(""), // <-- This could not be .unwrap_or("") due to type error
-///         spacer = if record.file().is_some() { " - " } else { "" },
-///         message = msg
-///     ))
-/// })
-/// .start();
+/// assert_eq!(Resolved::Present(&5), Some(5).fmt_or("x").resolve());
+/// assert_eq!(Resolved::Absent(&"x"), None::<u32>.fmt_or("x").resolve());
+/// assert_eq!(Resolved::Absent("lazy"), None::<u32>.fmt_or_else(|| "lazy").resolve());
 /// ```
-///
-#[allow(clippy::needless_lifetimes)] // They're nice to see in docs
-pub trait FmtOr<T> {
-    /// Format the value, if there is one, or display an empty string instead.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Resolved<'t, T, U> {
+    /// The value is present and would be formatted.
+    Present(&'t T),
+    /// The value is missing and this fallback would be displayed.
+    Absent(U),
+}
+
+impl<'t, T> MaybeFormat<'t, T> {
+    /// Creates the wrapper returned from [`FmtOr::fmt_or_empty`], in a `const` context if need be.
+    #[inline]
+    pub const fn new(opt: &'t Option<T>) -> Self {
+        Self(opt)
+    }
+    /// Returns the wrapped option.
+    #[inline]
+    pub fn inner(&self) -> &'t Option<T> {
+        self.0
+    }
+    /// Returns `true` if the wrapped option holds a value.
+    #[inline]
+    pub fn is_present(&self) -> bool {
+        self.0.is_some()
+    }
+    /// Returns `true` if the wrapped option is [`None`], so the fallback would be shown.
+    #[inline]
+    pub fn is_absent(&self) -> bool {
+        self.0.is_none()
+    }
+    /// Returns which branch would be formatted, with an empty string as the fallback.
+    #[inline]
+    pub fn resolve(&self) -> Resolved<'t, T, &'static str> {
+        match self.0 {
+            Some(t) => Resolved::Present(t),
+            None => Resolved::Absent(""),
+        }
+    }
+    /// Returns a view of this wrapper which shows the wrapper itself when debug formatted.
+    pub fn inspect(&self) -> Inspect<'_, Self> {
+        Inspect(self)
+    }
+    /// Returns the wrapper as an opaque [`Display`] value, such as for a `Box<dyn Display>`.
+    ///
+    /// This hides the concrete wrapper type, and with it every formatting trait but [`Display`].
     ///
     /// # Example
     ///
     /// ```rust
     /// use fmtor::FmtOr;
+    /// use std::fmt::Display;
     ///
-    /// let foo = Some(0x42);
+    /// let foo = Some(1);
     /// let bar: Option<u32> = None;
     ///
-    /// assert_eq!(
-    ///     "0x42",
-    ///     format!("{:#x}", foo.fmt_or_empty())
-    /// );
-    /// assert_eq!(
-    ///     "",
-    ///     format!("{:#x}", bar.fmt_or_empty())
-    /// );
+    /// let cells: Vec<Box<dyn Display>> = vec![
+    ///     Box::new(foo.fmt_or_empty().display()),
+    ///     Box::new(bar.fmt_or("-").display()),
+    /// ];
+    /// assert_eq!("1 -", format!("{} {}", cells[0], cells[1]));
     /// ```
-    fn fmt_or_empty<'t>(&'t self) -> MaybeFormat<'t, T>;
-    /// Format the value, if there is one, or display the given value instead.
+    #[inline]
+    pub fn display(self) -> impl Display + 't
+    where
+        T: Display,
+    {
+        self
+    }
+    /// Display the given value instead of nothing if there is no value.
     ///
-    /// The given value must implement [`Display`]
-    /// regardless of which formatting is used on the original value.
+    /// `opt.fmt_or_empty().or(u)` is the same as `opt.fmt_or(u)`, which lets the fallback be
+    /// chosen after the wrapper is created.
     ///
     /// # Example
     ///
     /// ```rust
     /// use fmtor::FmtOr;
     ///
-    /// let foo = Some(Box::new(42));
-    /// let bar: Option<Box<u32>> = None;
+    /// let foo: Option<u32> = None;
+    /// let verbose = true;
     ///
-    /// assert_eq!(
-    ///     "foo: 42",
-    ///     format!("foo: {}", foo.fmt_or("Null"))
-    /// );
-    /// assert_eq!(
-    ///     "bar: Null",
-    ///     format!("bar: {}", bar.fmt_or("Null"))
-    /// );
+    /// let cell = foo.fmt_or_empty();
+    /// let cell = if verbose { cell.or("missing") } else { cell.or("") };
+    /// assert_eq!("missing", format!("{}", cell));
     /// ```
-    fn fmt_or<'t, U>(&'t self, u: U) -> MaybeFormatOr<'t, T, U>
-    where
-        U: Display;
-    /// Format the value, if there is one, or run the closure to get a value to display instead.
+    #[inline]
+    pub fn or<U: Display>(self, u: U) -> MaybeFormatOr<'t, T, U> {
+        MaybeFormatOr(self.0, u)
+    }
+    /// Run the closure and display its result instead of nothing if there is no value.
     ///
-    /// The returned value must implement [`Display`]
-    /// regardless of which formatting is used on the original value.
+    /// `opt.fmt_or_empty().or_else(f)` is the same as `opt.fmt_or_else(f)`.
+    #[inline]
+    pub fn or_else<U: Display, F: Fn() -> U>(self, f: F) -> MaybeFormatOrElse<'t, T, F> {
+        MaybeFormatOrElse(self.0, f)
+    }
+    /// Display the given marker instead of nothing if there is no value.
     ///
-    /// The value returned from the closure is not stored after use. Therefore, using a single
-    /// [`MaybeFormatOrElse`] object for multiple formatting operations will run the closure
-    /// multiple times.
+    /// The marker is a `&'static str`, so a team can keep its missing marker in one constant and
+    /// use it everywhere, and the returned wrapper is a single type whatever the marker is.
     ///
     /// # Example
     ///
     /// ```rust
-    /// use fmtor::FmtOr;
+    /// use fmtor::{FmtOr, MaybeFormatEmpty};
     ///
-    /// let foo = Some(42);
-    /// let bar: Option<u32> = None;
+    /// const MISSING: &str = "-";
+    ///
+    /// fn cell<T>(opt: &Option<T>) -> MaybeFormatEmpty<'_, T> {
+    ///     opt.fmt_or_empty().with_default(MISSING)
+    /// }
+    ///
+    /// assert_eq!("2a", format!("{:x}", cell(&Some(42))));
+    /// assert_eq!("-", format!("{:x}", cell(&None::<u32>)));
+    /// ```
+    #[inline]
+    pub const fn with_default(self, default: &'static str) -> MaybeFormatEmpty<'t, T> {
+        MaybeFormatEmpty(self.0, default)
+    }
+}
+impl<'t, T> MaybeFormatEmpty<'t, T> {
+    /// Returns the marker displayed if the option is [`None`].
+    #[inline]
+    pub fn default_marker(&self) -> &'static str {
+        self.1
+    }
+}
+impl<'t, T, U> MaybeFormatOr<'t, T, U> {
+    /// Creates the wrapper returned from [`FmtOr::fmt_or`], in a `const` context if need be.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::MaybeFormatOr;
+    ///
+    /// const PORT: MaybeFormatOr<'static, u16, &str> = MaybeFormatOr::new(&None, "default");
+    ///
+    /// assert_eq!("default", format!("{}", PORT));
+    /// ```
+    #[inline]
+    pub const fn new(opt: &'t Option<T>, u: U) -> Self {
+        Self(opt, u)
+    }
+    /// Returns the wrapped option.
+    #[inline]
+    pub fn inner(&self) -> &'t Option<T> {
+        self.0
+    }
+    /// Returns `true` if the wrapped option holds a value.
+    #[inline]
+    pub fn is_present(&self) -> bool {
+        self.0.is_some()
+    }
+    /// Returns `true` if the wrapped option is [`None`], so the fallback would be shown.
+    #[inline]
+    pub fn is_absent(&self) -> bool {
+        self.0.is_none()
+    }
+    /// Returns which branch would be formatted, borrowing the fallback.
+    #[inline]
+    pub fn resolve(&self) -> Resolved<'t, T, &U> {
+        match self.0 {
+            Some(t) => Resolved::Present(t),
+            None => Resolved::Absent(&self.1),
+        }
+    }
+    /// Returns the value displayed if the option is [`None`].
+    #[inline]
+    pub fn fallback(&self) -> &U {
+        &self.1
+    }
+    /// Returns a view of this wrapper which shows the wrapper itself when debug formatted.
+    pub fn inspect(&self) -> Inspect<'_, Self> {
+        Inspect(self)
+    }
+    /// Returns the wrapper as an opaque [`Display`] value, such as for a `Box<dyn Display>`.
+    ///
+    /// This hides the concrete wrapper type, and with it every formatting trait but [`Display`].
+    ///
+    /// See [`MaybeFormat::display`].
+    #[inline]
+    pub fn display(self) -> impl Display + 't
+    where
+        T: Display,
+        U: Display + 't,
+    {
+        self
+    }
+}
+impl<'t, T, F> MaybeFormatOrElse<'t, T, F> {
+    /// Creates the wrapper returned from [`FmtOr::fmt_or_else`], in a `const` context if need be.
+    #[inline]
+    pub const fn new(opt: &'t Option<T>, f: F) -> Self {
+        Self(opt, f)
+    }
+    /// Returns the wrapped option.
+    #[inline]
+    pub fn inner(&self) -> &'t Option<T> {
+        self.0
+    }
+    /// Returns `true` if the wrapped option holds a value.
+    #[inline]
+    pub fn is_present(&self) -> bool {
+        self.0.is_some()
+    }
+    /// Returns `true` if the wrapped option is [`None`], so the fallback would be shown.
+    #[inline]
+    pub fn is_absent(&self) -> bool {
+        self.0.is_none()
+    }
+    /// Returns which branch would be formatted, running the closure if the value is missing.
+    #[inline]
+    pub fn resolve<U>(&self) -> Resolved<'t, T, U>
+    where
+        F: Fn() -> U,
+    {
+        match self.0 {
+            Some(t) => Resolved::Present(t),
+            None => Resolved::Absent((self.1)()),
+        }
+    }
+    /// Returns a view of this wrapper which shows the wrapper itself when debug formatted.
+    ///
+    /// The closure is shown as `"<closure>"`.
+    pub fn inspect(&self) -> Inspect<'_, Self> {
+        Inspect(self)
+    }
+    /// Returns the wrapper as an opaque [`Display`] value, such as for a `Box<dyn Display>`.
+    ///
+    /// This hides the concrete wrapper type, and with it every formatting trait but [`Display`].
+    ///
+    /// See [`MaybeFormat::display`].
+    #[inline]
+    pub fn display<U>(self) -> impl Display + 't
+    where
+        T: Display,
+        F: Fn() -> U + 't,
+        U: Display,
+    {
+        self
+    }
+}
+
+impl<'t, T: Debug> Debug for Inspect<'_, MaybeFormat<'t, T>> {
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        out.debug_tuple("MaybeFormat").field(self.0 .0).finish()
+    }
+}
+impl<'t, T: Debug, U: Debug> Debug for Inspect<'_, MaybeFormatOr<'t, T, U>> {
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        out.debug_tuple("MaybeFormatOr")
+            .field(self.0 .0)
+            .field(&self.0 .1)
+            .finish()
+    }
+}
+impl<'t, T: Debug, F> Debug for Inspect<'_, MaybeFormatOrElse<'t, T, F>> {
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        out.debug_tuple("MaybeFormatOrElse")
+            .field(self.0 .0)
+            .field(&"<closure>")
+            .finish()
+    }
+}
+
+/// An extension trait for [`Option<T>`]. The methods on this trait are the inteded way to use this crate.
+///
+/// # TLDR
+///
+/// The methods on this trait allow a missing [`Option<T>`] value to be formatted as if it
+/// were a `T`, however [`None`] is replaced by some other value.
+///
+/// # Details
+///
+/// The methods on this trait return types which implement all the same format traits as `T`.
+/// They borrow the [`Option<T>`] for the duration of the formatting operation.
+///
+/// When the value is [`None`], the formatting is replaced with another, whose type and value are determined
+/// by the user. The replacement type, `U`, need only implement [`Display`]. Regardless of the desired format
+/// of T, the format of U will *always* be [`Display`]. This makes it simple to replace non-[`Display`] formats with
+/// the anticipated values.
+///
+/// This trait is sealed and implemented for [`Option<T>`] only, so that new methods can be added
+/// to it without breaking other implementations.
+///
+/// ```rust
+/// fn fallable_box_maker() -> Option<Box<()>> { None }
+///
+/// use fmtor::FmtOr;
+///
+/// let maybe_box = fallable_box_maker();
+/// println!("Got a fallable box at {:p}", maybe_box.fmt_or("Null"));
+/// ```
+///
+/// # Options of references
+///
+/// An `Option<&T>` needs no special handling: the formatting traits are implemented for `&T` by
+/// formatting the `T` it points to, so `{:x}` shows the hex of the pointee. The exception is
+/// [`Pointer`], which `&T` implements itself, so `{:p}` shows the address of the pointee.
+///
+/// ```rust
+/// use fmtor::FmtOr;
+///
+/// let value = 255u32;
+/// let some = Some(&value);
+///
+/// assert_eq!("ff", format!("{:x}", some.fmt_or("-")));
+/// assert_eq!(format!("{:p}", &value), format!("{:p}", some.fmt_or("-")));
+/// assert_eq!("null", format!("{:p}", None::<&u32>.fmt_or("null")));
+/// ```
+///
+/// # Smart pointers and guards
+///
+/// Method calls auto-deref, so the methods can be called directly on anything which derefs to an
+/// [`Option<T>`], such as `Box<Option<T>>`, `Rc<Option<T>>`, `Cow<'_, Option<T>>` or a lock
+/// guard. The returned wrapper borrows through the pointer or guard, which must outlive it, and
+/// nothing is cloned, even for a borrowed `Cow`.
+///
+/// ```rust
+/// use fmtor::FmtOr;
+/// use std::sync::RwLock;
+///
+/// let boxed = Box::new(Some(42));
+/// let lock = RwLock::new(None::<u32>);
+///
+/// assert_eq!("2a", format!("{:x}", boxed.fmt_or("-")));
+/// assert_eq!("-", format!("{:x}", lock.read().unwrap().fmt_or("-")));
+/// ```
+///
+/// # Wrappers behind references
+///
+/// The standard library forwards every formatting trait through `&W`, so a borrowed wrapper can
+/// be passed wherever an `impl Display` or `&dyn LowerHex` is expected. The one exception is
+/// [`Pointer`], which for `&W` prints the address of the wrapper itself rather than the value.
+///
+/// ```rust
+/// use fmtor::FmtOr;
+/// use std::fmt::Display;
+///
+/// fn cell(d: impl Display) -> String {
+///     format!("[{:>3}]", d)
+/// }
+///
+/// let wrapper = None::<u32>.fmt_or_empty();
+/// assert_eq!("[   ]", cell(&wrapper));
+/// ```
+///
+/// # Trait objects
+///
+/// Options of `&dyn Display` or `Box<dyn Display>` work like any other, with the wrapper
+/// implementing only the traits the object does. So `{}` works for them, while `{:x}` and the
+/// other formatting traits need a trait object of that trait instead.
+///
+/// ```rust
+/// use fmtor::FmtOr;
+/// use std::fmt::Display;
+///
+/// let cells: Vec<Option<Box<dyn Display>>> = vec![Some(Box::new(1)), None, Some(Box::new("two"))];
+/// let row: Vec<String> = cells.iter().map(|c| c.fmt_or("-").to_string()).collect();
+///
+/// assert_eq!(["1", "-", "two"], row[..]);
+/// ```
+///
+/// # Errors
+///
+/// The wrappers add no errors of their own, except [`fmt_or_error`](FmtOr::fmt_or_error). An
+/// error returned by the value's formatting, or by the fallback's, is passed straight back to the
+/// caller, and nothing else is written after it.
+///
+/// # Pointers
+///
+/// [`NonNull`](core::ptr::NonNull) and raw pointers implement [`Pointer`], so options of them
+/// print their address under `{:p}` with no unwrapping. A present pointer is always printed as an
+/// address, even a null raw pointer, and only [`None`] shows the fallback.
+///
+/// ```rust
+/// use fmtor::FmtOr;
+/// use std::ptr::NonNull;
+///
+/// let value = 7u32;
+/// let buf: Option<NonNull<u32>> = Some(NonNull::from(&value));
+/// let raw: Option<*const u32> = None;
+///
+/// assert_eq!(format!("{:p}", &value), format!("{:p}", buf.fmt_or("(null)")));
+/// assert_eq!("(null)", format!("{:p}", raw.fmt_or("(null)")));
+/// ```
+///
+/// # More formatting logic
+///
+/// Here's a real-ish example with surrounding formatting logic. Formatting is applied to log messages
+/// so that they include the optional file name and line number when either the file or both are present.
+/// Having just a line number by itself wouldn't be all that helpful.
+///
+/// ```rust no_run
+/// // Be warned: this is a hypothetical code snippet that may not compile on current versions of amethyst.
+/// // The code is not compiled by doctest, and therefore should not be relied upon as working.
+///
+/// use fmtor::FmtOr;
+///
+/// // Generates log messeges like
+/// // [INFO] src\game\states\init.rs:56 - Init starting
+/// # #[cfg(never)]
+/// amethyst::Logger::from_config_formatter(config, |out, msg, record| {
+///     out.finish(format_args!(
+///         "[{level}] {file}{line}{spacer}{message}",
+///         level = record.level(),
+///         file = record.file().unwrap_or(""), // <-- This could be .fmt_or(""), but is not necessary
+///         line = record.file().and(record.line()).fmt_wrap(":", ""), // <-- The colon only shows with the line
+///         spacer = if record.file().is_some() { " - " } else { "" },
+///         message = msg
+///     ))
+/// })
+/// .start();
+/// ```
+///
+#[allow(clippy::needless_lifetimes)] // They're nice to see in docs
+pub trait FmtOr<T>: sealed::Sealed {
+    /// Format the value, if there is one, or display an empty string instead.
+    ///
+    /// The empty string is still padded to the requested width, with the requested fill and
+    /// alignment, so a missing value takes up as much room as a present one would in a table.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOr;
+    ///
+    /// let foo = Some(0x42);
+    /// let bar: Option<u32> = None;
+    ///
+    /// assert_eq!(
+    ///     "0x42",
+    ///     format!("{:#x}", foo.fmt_or_empty())
+    /// );
+    /// assert_eq!(
+    ///     "",
+    ///     format!("{:#x}", bar.fmt_or_empty())
+    /// );
+    /// assert_eq!(
+    ///     "-----",
+    ///     format!("{:->5}", bar.fmt_or_empty())
+    /// );
+    /// ```
+    fn fmt_or_empty<'t>(&'t self) -> MaybeFormat<'t, T>;
+    /// Format the value, if there is one, or display the given value instead.
+    ///
+    /// The given value must implement [`Display`]
+    /// regardless of which formatting is used on the original value.
+    ///
+    /// The fallback is always formatted with [`Display`], even for `{:x}` or `{:e}`, so a number
+    /// used as the fallback will not match how the value would be formatted. Flags such as `+` and
+    /// `0` only apply if the fallback's [`Display`] impl supports them, which a string's does not.
+    /// Use [`fmt_or_num`](FmtOr::fmt_or_num) for numeric columns.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOr;
+    ///
+    /// let foo = Some(Box::new(42));
+    /// let bar: Option<Box<u32>> = None;
+    ///
+    /// assert_eq!(
+    ///     "foo: 42",
+    ///     format!("foo: {}", foo.fmt_or("Null"))
+    /// );
+    /// assert_eq!(
+    ///     "bar: Null",
+    ///     format!("bar: {}", bar.fmt_or("Null"))
+    /// );
+    /// ```
+    fn fmt_or<'t, U>(&'t self, u: U) -> MaybeFormatOr<'t, T, U>
+    where
+        U: Display;
+    /// Format the value, if there is one, or run the closure to get a value to display instead.
+    ///
+    /// The returned value must implement [`Display`]
+    /// regardless of which formatting is used on the original value.
+    ///
+    /// The value returned from the closure is not stored after use. Therefore, using a single
+    /// [`MaybeFormatOrElse`] object for multiple formatting operations will run the closure
+    /// multiple times.
+    ///
+    /// The closure may return a reference to data it borrows, such as `|| name.as_str()`, as the
+    /// returned type is not tied to the lifetime of the option. It can't return a reference to
+    /// data it owns, as with `move || name.as_str()`, since each call must return a fresh value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOr;
+    ///
+    /// let foo = Some(42);
+    /// let bar: Option<u32> = None;
     /// let missing_msg = "Missing";
     /// let missing_item = "bar";
     ///
@@ -206,66 +1017,1469 @@ pub trait FmtOr<T> {
     where
         U: Display,
         F: Fn() -> U;
+    /// Format the value, if there is one, or display the default value of `U` instead.
+    ///
+    /// This is useful when the fallback is a type whose [`Default`] is the desired placeholder,
+    /// so it doesn't need to be named at the call site. The default is constructed during each
+    /// formatting operation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOr;
+    ///
+    /// #[derive(Default)]
+    /// struct Unknown;
+    /// impl std::fmt::Display for Unknown {
+    ///     fn fmt(&self, out: &mut std::fmt::Formatter) -> std::fmt::Result {
+    ///         out.write_str("unknown")
+    ///     }
+    /// }
+    ///
+    /// let foo = Some(0x42);
+    /// let bar: Option<u32> = None;
+    ///
+    /// assert_eq!(
+    ///     "0x42",
+    ///     format!("{:#x}", foo.fmt_or_default_display::<Unknown>())
+    /// );
+    /// assert_eq!(
+    ///     "unknown",
+    ///     format!("{:#x}", bar.fmt_or_default_display::<Unknown>())
+    /// );
+    /// ```
+    fn fmt_or_default_display<'t, U>(&'t self) -> MaybeFormatOrDefaultDisplay<'t, T, U>
+    where
+        U: Default + Display;
+    /// Format the value, if there is one, or display `<missing {type}>` instead, where `{type}`
+    /// is the name of `T` as given by [`core::any::type_name`].
+    ///
+    /// This is meant for debugging output. The exact type name is not stable: it may differ
+    /// between compiler versions and may or may not include the module path.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOr;
+    ///
+    /// let foo = Some(0x42);
+    /// let bar: Option<u32> = None;
+    ///
+    /// assert_eq!(
+    ///     "0x42",
+    ///     format!("{:#x}", foo.fmt_or_typed_none())
+    /// );
+    /// assert_eq!(
+    ///     "<missing u32>",
+    ///     format!("{:#x}", bar.fmt_or_typed_none())
+    /// );
+    /// ```
+    fn fmt_or_typed_none<'t>(&'t self) -> MaybeFormatTypedNone<'t, T>;
+    /// Format the value, if there is one, or format the default value of `T` instead.
+    ///
+    /// Unlike the other methods, the replacement is formatted with the same trait as the value would
+    /// be, so flags such as `{:#x}` or `{:08b}` apply to it too. The default is constructed during
+    /// each formatting operation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOr;
+    ///
+    /// let foo = Some(0x42);
+    /// let bar: Option<u32> = None;
+    ///
+    /// assert_eq!(
+    ///     "0x42",
+    ///     format!("{:#x}", foo.fmt_or_default())
+    /// );
+    /// assert_eq!(
+    ///     "0x0",
+    ///     format!("{:#x}", bar.fmt_or_default())
+    /// );
+    /// ```
+    fn fmt_or_default<'t>(&'t self) -> MaybeFormatDefault<'t, T>
+    where
+        T: Default;
+    /// Format the value, if there is one, or format the given value of the same type instead.
+    ///
+    /// Like [`fmt_or_default`](FmtOr::fmt_or_default), the replacement is formatted with the same
+    /// trait and flags as the value would be, which suits a sentinel of the same type as the data.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOr;
+    ///
+    /// let foo = Some(0x42);
+    /// let bar: Option<u32> = None;
+    ///
+    /// assert_eq!(
+    ///     "42",
+    ///     format!("{:X}", foo.fmt_or_same(0xFF))
+    /// );
+    /// assert_eq!(
+    ///     "FF",
+    ///     format!("{:X}", bar.fmt_or_same(0xFF))
+    /// );
+    /// ```
+    fn fmt_or_same<'t>(&'t self, u: T) -> MaybeFormatSame<'t, T>;
+    /// Format the value, if there is one, or format the given number instead, with the same
+    /// formatting trait and flags.
+    ///
+    /// Unlike [`fmt_or`](FmtOr::fmt_or), which always uses [`Display`] for the fallback, the
+    /// fallback here is formatted with whichever trait is used for the value, so `{:x}`, `{:+}`,
+    /// `{:08}` and `{:#b}` apply to missing rows exactly as to present ones. The fallback may be a
+    /// different type than the value, as long as it supports the same formatting.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOr;
+    ///
+    /// let foo = Some(5);
+    /// let bar: Option<i32> = None;
+    ///
+    /// assert_eq!("+0000005", format!("{:+08}", foo.fmt_or_num(0)));
+    /// assert_eq!("+0000000", format!("{:+08}", bar.fmt_or_num(0)));
+    /// assert_eq!("0x0000ff", format!("{:#08x}", bar.fmt_or_num(255u8)));
+    /// ```
+    fn fmt_or_num<'t, N>(&'t self, n: N) -> MaybeFormatOrNum<'t, T, N>;
+    /// Format the value, if there is one, or display the borrowed value instead.
+    ///
+    /// This is [`fmt_or`](FmtOr::fmt_or) without moving the fallback into the wrapper, so a
+    /// large or non-[`Copy`] fallback can be shared by many wrappers. The returned wrapper is
+    /// always [`Copy`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOr;
+    ///
+    /// let placeholder = String::from("<unknown>");
+    /// let name: Option<&str> = None;
+    /// let host: Option<&str> = None;
+    ///
+    /// assert_eq!("<unknown>", format!("{}", name.fmt_or_ref(&placeholder)));
+    /// assert_eq!("<unknown>", format!("{}", host.fmt_or_ref(&placeholder)));
+    /// ```
+    fn fmt_or_ref<'t, U>(&'t self, u: &'t U) -> MaybeFormatOrRef<'t, T, U>
+    where
+        U: Display + ?Sized;
+    /// Format the value, if there is one, or the other value, if there is one, or display an
+    /// empty string instead.
+    ///
+    /// Unlike [`fmt_or`](FmtOr::fmt_or), both values are formatted with whichever trait and flags
+    /// are used, so `{:x}` applies to the second value as well. This is the same as
+    /// `self.fmt_or_empty().or_opt(other)`, and further options or a fallback may be chained
+    /// onto it; see [`MaybeFormatOrOpt`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOr;
+    ///
+    /// let primary: Option<u32> = None;
+    /// let secondary = Some(0xB);
+    ///
+    /// assert_eq!("b", format!("{:x}", primary.fmt_or_opt(&secondary)));
+    /// assert_eq!("", format!("{:x}", primary.fmt_or_opt(&None)));
+    /// assert_eq!("n/a", format!("{:x}", primary.fmt_or_opt(&None).or("n/a")));
+    /// ```
+    fn fmt_or_opt<'t>(&'t self, other: &'t Option<T>) -> MaybeFormatOrOpt<'t, MaybeFormat<'t, T>>;
+    /// Format the value, if there is one, or display an empty string instead, except under
+    /// [`Debug`], where a missing value shows as `None`.
+    ///
+    /// This suits wrappers used both in user facing output and as fields of a hand-written
+    /// [`Debug`] impl, where an empty field would look broken. A present value is debug
+    /// formatted as itself, not as `Some(..)`, and `{:#?}` is passed on to it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOr;
+    ///
+    /// let foo = Some(3);
+    /// let bar: Option<u32> = None;
+    ///
+    /// assert_eq!("", format!("{}", bar.fmt_or_debug_none()));
+    /// assert_eq!("None", format!("{:?}", bar.fmt_or_debug_none()));
+    /// assert_eq!("3", format!("{:?}", foo.fmt_or_debug_none()));
+    /// ```
+    fn fmt_or_debug_none<'t>(&'t self) -> MaybeFormatDebugNone<'t, T>;
+    /// Format the value, if there is one, or fail with [`fmt::Error`] instead.
+    ///
+    /// This suits strict rendering, where a missing value is a hard error rather than something
+    /// to paper over. The error propagates out of [`write!`] into the caller of a fallible sink.
+    /// Note that [`format!`] and `to_string` panic when a formatting trait returns an error, so
+    /// only use this wrapper with sinks whose errors are handled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOr;
+    /// use std::fmt::Write;
+    ///
+    /// let foo = Some(42);
+    /// let bar: Option<u32> = None;
+    /// let mut out = String::new();
+    ///
+    /// assert!(write!(out, "{:x}", foo.fmt_or_error()).is_ok());
+    /// assert!(write!(out, "{:x}", bar.fmt_or_error()).is_err());
+    /// assert_eq!("2a", out);
+    /// ```
+    ///
+    /// [`fmt::Error`]: core::fmt::Error
+    fn fmt_or_error<'t>(&'t self) -> MaybeFormatOrError<'t, T>;
+    /// Display `set` if there is a value, or `unset` if there is not, without formatting the value.
+    ///
+    /// The value is never touched, so `T` needs no formatting traits, and secrets such as
+    /// passwords can be reported without being printed. Either text is formatted like a string,
+    /// whichever formatting trait is used.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOr;
+    ///
+    /// let password = Some(String::from("hunter2"));
+    /// let token: Option<Vec<u8>> = None;
+    ///
+    /// assert_eq!("set", format!("{:x}", password.fmt_set_or_unset("set", "unset")));
+    /// assert_eq!("unset", format!("{}", token.fmt_set_or_unset("set", "unset")));
+    /// ```
+    fn fmt_set_or_unset<'t>(&'t self, set: &'t str, unset: &'t str)
+        -> MaybeFormatSetOrUnset<'t, T>;
+    /// Hex format the value, if there is one, or hex format the given number instead.
+    ///
+    /// The returned wrapper implements only [`LowerHex`] and [`UpperHex`]. The fallback is hex
+    /// formatted too, so `{:#x}` gives it the same `0x` prefix, and `{:#010x}` the same zero
+    /// padding, as a present value. [`fmt_or_num`](FmtOr::fmt_or_num) does the same for every
+    /// formatting trait.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOr;
+    ///
+    /// let foo = Some(0xff);
+    /// let bar: Option<u32> = None;
+    ///
+    /// assert_eq!("0xff", format!("{:#x}", foo.fmt_or_hex(0)));
+    /// assert_eq!("0x0", format!("{:#x}", bar.fmt_or_hex(0)));
+    /// assert_eq!("0x00000000", format!("{:#010X}", bar.fmt_or_hex(0)));
+    /// ```
+    fn fmt_or_hex<'t, N>(&'t self, n: N) -> MaybeFormatOrHex<'t, T, N>
+    where
+        N: LowerHex + UpperHex;
+    /// Display the value, if there is one, or the given value instead, and record how many bytes
+    /// were written in `written`.
+    ///
+    /// `written` is set every time the wrapper is formatted, to the length of the value or
+    /// fallback alone, not counting any padding. The returned wrapper implements [`Display`]
+    /// only. The value and fallback are written without a format spec, and the result is padded
+    /// to the requested width as a single piece of text.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOr;
+    /// use std::cell::Cell;
+    ///
+    /// let written = Cell::new(0);
+    /// let name: Option<&str> = None;
+    ///
+    /// assert_eq!("   x", format!("{:>4}", name.fmt_or_counting("x", &written)));
+    /// assert_eq!(1, written.get());
+    /// ```
+    fn fmt_or_counting<'t, 'c, U>(
+        &'t self,
+        u: U,
+        written: &'c Cell<usize>,
+    ) -> MaybeFormatCounting<'t, 'c, T, U>
+    where
+        U: Display;
+    /// Format the value, if there is one, or write the given character instead.
+    ///
+    /// This is [`fmt_or`](FmtOr::fmt_or) specialized for single character placeholders such as
+    /// `'-'` or `'∅'`. Without a width the character is written directly, and with one it is
+    /// padded just like `fmt_or(c)` would be.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOr;
+    ///
+    /// let foo = Some(0x42);
+    /// let bar: Option<u32> = None;
+    ///
+    /// assert_eq!("42", format!("{:x}", foo.fmt_or_char('-')));
+    /// assert_eq!("-", format!("{:x}", bar.fmt_or_char('-')));
+    /// assert_eq!("   ∅", format!("{:>4}", bar.fmt_or_char('∅')));
+    /// ```
+    fn fmt_or_char<'t>(&'t self, c: char) -> MaybeFormatOrChar<'t, T>;
+    /// Format the pointer, if there is one, or a null pointer in the given style instead.
+    ///
+    /// The returned wrapper implements [`Pointer`] only. Unlike `fmt_or("Null")`, a missing
+    /// pointer can be rendered like a real null address, so a column of addresses stays
+    /// recognizable. With [`NullPtr::Zero`] and the alternate flag, as in `{:#p}`, present and
+    /// missing pointers are all zero padded to the pointer width of the target, and line up.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::{FmtOr, NullPtr};
+    ///
+    /// let missing: Option<&u32> = None;
+    ///
+    /// assert_eq!("0x0", format!("{:p}", missing.fmt_ptr_or_null(NullPtr::Zero)));
+    /// assert_eq!("(nil)", format!("{:p}", missing.fmt_ptr_or_null(NullPtr::Nil)));
+    /// ```
+    fn fmt_ptr_or_null<'t>(&'t self, style: NullPtr) -> MaybeFormatPtrOrNull<'t, T>;
+    /// Format the value, if there is one, or debug format the given value instead.
+    ///
+    /// This is [`fmt_or`](FmtOr::fmt_or) with the fallback always formatted with [`Debug`]
+    /// instead of [`Display`], which suits fallbacks such as enum variants in debug logs. The
+    /// format spec, including the alternate flag of `{:#?}`, is passed to the fallback. Inside
+    /// pretty printed structures, the fallback is indented like any other field.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOr;
+    ///
+    /// #[derive(Debug)]
+    /// enum Reason {
+    ///     NotLoaded,
+    /// }
+    ///
+    /// let foo = Some("config.toml");
+    /// let bar: Option<&str> = None;
+    ///
+    /// assert_eq!("\"config.toml\"", format!("{:?}", foo.fmt_or_debug(Reason::NotLoaded)));
+    /// assert_eq!("NotLoaded", format!("{:?}", bar.fmt_or_debug(Reason::NotLoaded)));
+    /// ```
+    fn fmt_or_debug<'t, U>(&'t self, u: U) -> MaybeFormatOrDebug<'t, T, U>
+    where
+        U: Debug;
+    /// Format the value, if there is one, or display the sentinel for the formatting trait in use.
+    ///
+    /// See [`Sentinels`] for choosing a sentinel per trait.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::{FmtOr, Sentinels};
+    ///
+    /// let ptr: Option<&u32> = None;
+    /// let sentinels = Sentinels::new("0").pointer("nullptr");
+    ///
+    /// assert_eq!("nullptr", format!("{:p}", ptr.fmt_or_sentinel(sentinels)));
+    /// assert_eq!("0", format!("{:x}", None::<u32>.fmt_or_sentinel(sentinels)));
+    /// ```
+    fn fmt_or_sentinel<'t>(&'t self, sentinels: Sentinels<'t>) -> MaybeFormatSentinel<'t, T>;
+    /// Format the value, if there is one, or display the given formatted arguments instead.
+    ///
+    /// This allows a fallback built with [`format_args!`] at the call site, without allocating.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOr;
+    ///
+    /// let line = 12;
+    /// let foo: Option<u32> = None;
+    ///
+    /// assert_eq!(
+    ///     "missing at line 12",
+    ///     format!("{:x}", foo.fmt_or_args(format_args!("missing at line {}", line)))
+    /// );
+    /// ```
+    fn fmt_or_args<'t>(&'t self, args: Arguments<'t>) -> MaybeFormatOrArgs<'t, T>;
+    /// Format the result of the closure on the value, if there is one, or format nothing.
+    ///
+    /// The closure only runs when the wrapper is formatted, and its result is formatted with the
+    /// same trait and flags as the value would be. A fallback can be added with
+    /// [`or`](MaybeFormatMap::or) or [`or_else`](MaybeFormatMap::or_else).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOr;
+    ///
+    /// struct User {
+    ///     id: u32,
+    /// }
+    ///
+    /// let foo = Some(User { id: 42 });
+    /// let bar: Option<User> = None;
+    ///
+    /// assert_eq!("0x2a", format!("{:#x}", foo.fmt_map(|u| u.id)));
+    /// assert_eq!("", format!("{:#x}", bar.fmt_map(|u| u.id)));
+    /// assert_eq!("anonymous", format!("{:#x}", bar.fmt_map(|u| u.id).or("anonymous")));
+    /// ```
+    fn fmt_map<'t, G, V>(&'t self, g: G) -> MaybeFormatMap<'t, T, G>
+    where
+        G: Fn(&T) -> V;
+    /// Format the value between `prefix` and `suffix`, if there is one, or format nothing at all.
+    ///
+    /// The format spec applies to the value only, so `{:>4}` pads the value and not the prefix or
+    /// suffix.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOr;
+    ///
+    /// let foo = Some(56);
+    /// let bar: Option<u32> = None;
+    ///
+    /// assert_eq!("main.rs:56", format!("main.rs{}", foo.fmt_wrap(":", "")));
+    /// assert_eq!("main.rs", format!("main.rs{}", bar.fmt_wrap(":", "")));
+    /// assert_eq!("(0x38)", format!("{:#x}", foo.fmt_wrap("(", ")")));
+    /// ```
+    fn fmt_wrap<'t>(&'t self, prefix: &'t str, suffix: &'t str) -> MaybeFormatWrap<'t, T>;
+    /// Format the value after `prefix`, if there is one, or format nothing at all.
+    ///
+    /// This is [`fmt_wrap`](FmtOr::fmt_wrap) without a suffix, for the common case of a separator
+    /// before an optional trailing value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOr;
+    ///
+    /// let detail = Some("disk full");
+    /// let none: Option<&str> = None;
+    ///
+    /// assert_eq!("write failed - disk full", format!("write failed{}", detail.fmt_present(" - ")));
+    /// assert_eq!("write failed", format!("write failed{}", none.fmt_present(" - ")));
+    /// ```
+    fn fmt_present<'t>(&'t self, prefix: &'t str) -> MaybeFormatPresent<'t, T>;
+    /// Write the value, if there is one, or the given string, into `out` using [`Display`].
+    ///
+    /// This needs no allocation, so it suits `no_std` code writing into a fixed-size buffer or a
+    /// serial port. See [`write_or_fmt`](FmtOr::write_or_fmt) for a formatted fallback.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOr;
+    ///
+    /// let mut buf = String::new();
+    /// Some(42).write_or(&mut buf, "n/a").unwrap();
+    /// buf.push(' ');
+    /// None::<u32>.write_or(&mut buf, "n/a").unwrap();
+    ///
+    /// assert_eq!("42 n/a", buf);
+    /// ```
+    fn write_or<W: Write + ?Sized>(&self, out: &mut W, fallback: &str) -> Result
+    where
+        T: Display;
+    /// Write the value, if there is one, or the given formatted arguments, into `out` using
+    /// [`Display`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOr;
+    ///
+    /// let mut buf = String::new();
+    /// None::<u32>.write_or_fmt(&mut buf, format_args!("<sensor {} offline>", 3)).unwrap();
+    ///
+    /// assert_eq!("<sensor 3 offline>", buf);
+    /// ```
+    fn write_or_fmt<W: Write + ?Sized>(&self, out: &mut W, fallback: Arguments<'_>) -> Result
+    where
+        T: Display;
+    /// Write the value, if there is one, with the formatting trait picked by `fmt`, or the given
+    /// string instead, into `out`.
+    ///
+    /// Unlike the wrappers, where the trait is fixed by the format string, this picks the trait
+    /// at runtime, e.g. hex or decimal per field from a log configuration. See [`Fmt`] for the
+    /// traits which can be picked.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::{Fmt, FmtOr};
+    ///
+    /// let mut buf = String::new();
+    /// for &fmt in &[Fmt::Display, Fmt::LowerHex, Fmt::Binary] {
+    ///     Some(10).write_fmt_or(&mut buf, fmt, "n/a").unwrap();
+    ///     buf.push(' ');
+    /// }
+    /// None::<u32>.write_fmt_or(&mut buf, Fmt::LowerHex, "n/a").unwrap();
+    ///
+    /// assert_eq!("10 a 1010 n/a", buf);
+    /// ```
+    fn write_fmt_or<W: Write + ?Sized>(&self, out: &mut W, fmt: Fmt, fallback: &str) -> Result
+    where
+        T: Display + Debug + LowerHex + UpperHex + Octal + Binary + LowerExp + UpperExp;
+    /// Format the value, if there is one, in the radix or notation picked by `kind`, or display
+    /// the given value instead.
+    ///
+    /// The returned wrapper implements [`Display`] only, and formats the value with the trait
+    /// matching `kind`, passing on the format spec. This picks e.g. hex or decimal at runtime,
+    /// where `{:x}` would fix it in the format string. See [`FmtKind`] for the choices.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::{FmtKind, FmtOr};
+    ///
+    /// let radix = FmtKind::LowerHex; // e.g. read from a settings file
+    ///
+    /// assert_eq!("ff", format!("{}", Some(255).fmt_or_dyn(radix, "n/a")));
+    /// assert_eq!("0xff", format!("{:#}", Some(255).fmt_or_dyn(radix, "n/a")));
+    /// assert_eq!("255", format!("{}", Some(255).fmt_or_dyn(FmtKind::Decimal, "n/a")));
+    /// assert_eq!("n/a", format!("{}", None::<u32>.fmt_or_dyn(radix, "n/a")));
+    /// ```
+    fn fmt_or_dyn<'t, U>(&'t self, kind: FmtKind, fallback: U) -> MaybeFormatDyn<'t, T, U>
+    where
+        U: Display;
+    /// Like [`fmt_or_empty`](FmtOr::fmt_or_empty), but takes the option by value.
+    ///
+    /// The returned wrapper owns the option, so it can be returned from a function or stored in a
+    /// struct.
+    fn into_fmt_or_empty(self) -> OwnedMaybeFormat<T>;
+    /// Like [`fmt_or`](FmtOr::fmt_or), but takes the option by value.
+    ///
+    /// The returned wrapper owns the option, so it can be returned from a function or stored in a
+    /// struct.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOr;
+    /// use std::fmt::Display;
+    ///
+    /// fn label(id: Option<u32>) -> impl Display {
+    ///     id.into_fmt_or("unassigned")
+    /// }
+    ///
+    /// assert_eq!("7", label(Some(7)).to_string());
+    /// assert_eq!("unassigned", label(None).to_string());
+    /// ```
+    ///
+    /// This also suits iterator adapters, where each option is owned by the closure:
+    ///
+    /// ```rust
+    /// use fmtor::FmtOr;
+    ///
+    /// let cells = vec![Some(1), None, Some(3)];
+    /// let rendered: Vec<String> = cells.into_iter().map(|o| o.into_fmt_or("n/a").to_string()).collect();
+    ///
+    /// assert_eq!(["1", "n/a", "3"], rendered[..]);
+    /// ```
+    fn into_fmt_or<U>(self, u: U) -> OwnedMaybeFormatOr<T, U>
+    where
+        U: Display;
+    /// Like [`fmt_or_else`](FmtOr::fmt_or_else), but takes the option by value.
+    ///
+    /// The returned wrapper owns the option, so it can be returned from a function or stored in a
+    /// struct.
+    fn into_fmt_or_else<U, F>(self, f: F) -> OwnedMaybeFormatOrElse<T, F>
+    where
+        U: Display,
+        F: Fn() -> U;
+    /// Format the value, if there is one, or run the closure to get a value to display instead,
+    /// running it at most once.
+    ///
+    /// Unlike [`fmt_or_else`](FmtOr::fmt_or_else), the value returned from the closure is stored
+    /// in the returned [`MaybeFormatOrElseCached`] the first time it is needed, and reused by every
+    /// formatting operation after that. So the closure runs at most once per wrapper, never runs
+    /// if the value is present, and its output is the same every time. This also allows an
+    /// [`FnOnce`] closure.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOr;
+    ///
+    /// let bar: Option<u32> = None;
+    /// let name = String::from("bar");
+    ///
+    /// let wrapper = bar.fmt_or_else_cached(move || name + " is missing");
+    /// assert_eq!("bar is missing", format!("{}", wrapper));
+    /// assert_eq!("bar is missing", format!("{:x}", wrapper));
+    /// ```
+    fn fmt_or_else_cached<'t, U, F>(&'t self, f: F) -> MaybeFormatOrElseCached<'t, T, F, U>
+    where
+        U: Display,
+        F: FnOnce() -> U;
+    /// Format the value, if there is one, or run the closure once and display its result instead.
+    ///
+    /// Unlike [`fmt_or_else`](FmtOr::fmt_or_else), the closure only needs to be [`FnOnce`], so it
+    /// may move captured values out, as in `|| buf.take()`. The closure is consumed by the first
+    /// formatting operation while the value is missing, and never runs if the value is present.
+    ///
+    /// Formatting a missing value a second time is a logic error, since the closure is gone by
+    /// then; nothing is written in that case, as with [`fmt_or_empty`](FmtOr::fmt_or_empty). Use
+    /// [`fmt_or_else_cached`](FmtOr::fmt_or_else_cached) to keep the result for later formatting.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOr;
+    ///
+    /// let foo: Option<u32> = None;
+    /// let reason = String::from("not configured");
+    ///
+    /// let wrapper = foo.fmt_or_else_once(move || reason);
+    /// assert_eq!("not configured", format!("{}", wrapper));
+    /// assert_eq!("", format!("{}", wrapper));
+    /// ```
+    fn fmt_or_else_once<'t, U, F>(&'t self, f: F) -> MaybeFormatOrElseOnce<'t, T, F>
+    where
+        U: Display,
+        F: FnOnce() -> U;
+    /// Format the value, if there is one, or display a fallback made up front instead.
+    ///
+    /// `make` runs right away if the value is missing, and never if it is present. The resulting
+    /// fallback is stored in the wrapper, so formatting it again, even with padding, doesn't run
+    /// anything. Compared to [`fmt_or_else`](FmtOr::fmt_or_else), this trades a lazy closure run
+    /// on every format for a single eager one, which is run even if the wrapper is never
+    /// formatted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOr;
+    ///
+    /// let foo: Option<u32> = None;
+    /// let wrapper = foo.fmt_or_eager(|| format!("<{} missing>", "id"));
+    ///
+    /// assert_eq!("<id missing>", format!("{}", wrapper));
+    /// assert_eq!("<id missing>  ", format!("{:14}", wrapper));
+    /// ```
+    fn fmt_or_eager<'t, U, F>(&'t self, make: F) -> MaybeFormatOrEager<'t, T, U>
+    where
+        U: Display,
+        F: FnOnce() -> U;
+    /// Format the value, if there is one, or format the given [`AllFmt`] trait object with the same
+    /// formatting trait.
+    ///
+    /// Unlike [`fmt_or`](FmtOr::fmt_or), where the fallback is always formatted with [`Display`],
+    /// the fallback here is formatted with whichever trait is used for the value, so it can render
+    /// differently for `{}`, `{:x}` and so on.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOr;
+    /// use std::fmt;
+    ///
+    /// struct Missing;
+    /// macro_rules! missing {
+    ///     ($($Trait:ident => $text:expr),*) => {$(
+    ///         impl fmt::$Trait for Missing {
+    ///             fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+    ///                 out.pad($text)
+    ///             }
+    ///         }
+    ///     )*};
+    /// }
+    /// missing!(
+    ///     Binary => "0b?", Debug => "Missing", Display => "?", LowerExp => "?e?",
+    ///     LowerHex => "0x?", Octal => "0o?", Pointer => "null", UpperExp => "?E?", UpperHex => "0X?"
+    /// );
+    ///
+    /// let foo: Option<u32> = None;
+    ///
+    /// assert_eq!("?", format!("{}", foo.fmt_or_dyn_all(&Missing)));
+    /// assert_eq!("0x?", format!("{:x}", foo.fmt_or_dyn_all(&Missing)));
+    /// assert_eq!("2a", format!("{:x}", Some(42).fmt_or_dyn_all(&Missing)));
+    /// ```
+    fn fmt_or_dyn_all<'t, 'u>(&'t self, fallback: &'u dyn AllFmt)
+        -> MaybeFormatOrDynAll<'t, 'u, T>;
+    /// Format the value, if there is one, or let the closure write to the formatter instead.
+    ///
+    /// This is more flexible than [`fmt_or_else`](FmtOr::fmt_or_else): the closure gets the
+    /// [`Formatter`] itself, so it may inspect the format spec, such as [`Formatter::width`] or
+    /// [`Formatter::alternate`], and write several pieces directly.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOr;
+    ///
+    /// let foo: Option<u32> = None;
+    /// let wrapper = foo.fmt_or_with(|out| match out.width() {
+    ///     Some(width) => out.write_str(&"?".repeat(width)),
+    ///     None => out.write_str("?"),
+    /// });
+    ///
+    /// assert_eq!("???", format!("{:3x}", wrapper));
+    /// assert_eq!("?", format!("{}", wrapper));
+    /// ```
+    fn fmt_or_with<'t, G>(&'t self, g: G) -> MaybeFormatOrWith<'t, T, G>
+    where
+        G: Fn(&mut Formatter<'_>) -> Result;
+    /// Format the value, if there is one, or else map the value of another option of a different
+    /// type and display that, or else display nothing.
+    ///
+    /// This coalesces options holding different types, which
+    /// [`fmt_or_opt`](FmtOr::fmt_or_opt) can't, as it needs both options to hold the same type.
+    /// The value is formatted with the requested trait, while the mapped value of `other` is
+    /// always displayed with [`Display`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOr;
+    ///
+    /// let port: Option<u16> = None;
+    /// let service = Some("https");
+    /// let to_display = |name: &&str| name.to_uppercase();
+    ///
+    /// assert_eq!("HTTPS", format!("{}", port.fmt_or_map_opt(&service, to_display)));
+    /// assert_eq!("1bb", format!("{:x}", Some(443u16).fmt_or_map_opt(&service, to_display)));
+    /// assert_eq!("", format!("{}", port.fmt_or_map_opt(&None, to_display)));
+    /// ```
+    fn fmt_or_map_opt<'t, B, G, D>(
+        &'t self,
+        other: &'t Option<B>,
+        to_display: G,
+    ) -> MaybeFormatOrMapOpt<'t, T, B, G>
+    where
+        G: Fn(&B) -> D,
+        D: Display;
+    /// Render the value with the closure, if there is one, or display the given string instead.
+    ///
+    /// `on_some` writes the value to the formatter itself, so it can render it in any way, such
+    /// as a timestamp in a custom format. The returned wrapper implements [`Display`] only, and
+    /// the fallback is formatted with the full format spec, like [`fmt_or`](FmtOr::fmt_or).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOr;
+    ///
+    /// let version = Some(0x0001_0203u64);
+    /// let dotted = |v: &u64, f: &mut std::fmt::Formatter| write!(f, "{}.{}.{}", v >> 16, (v >> 8) & 0xff, v & 0xff);
+    ///
+    /// assert_eq!("1.2.3", format!("{}", version.fmt_display_or(dotted, "unknown")));
+    /// assert_eq!("unknown", format!("{}", None.fmt_display_or(dotted, "unknown")));
+    /// ```
+    fn fmt_display_or<'t, G>(
+        &'t self,
+        on_some: G,
+        fallback: &'t str,
+    ) -> MaybeFormatDisplayWith<'t, T, G>
+    where
+        G: Fn(&T, &mut Formatter<'_>) -> Result;
+    /// Render the value with `present`, if there is one, or render with `absent` instead.
+    ///
+    /// This is the low-level escape hatch: both closures write to the formatter themselves, so
+    /// they can render in any way, and every other method could be written with it. The
+    /// returned wrapper implements [`Display`] only. Prefer the more specific methods where they
+    /// fit, as they pass on the format spec and support the other formatting traits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOr;
+    /// use std::fmt::Write;
+    ///
+    /// let shout = |name: &&str, f: &mut std::fmt::Formatter| {
+    ///     name.chars().try_for_each(|c| f.write_char(c.to_ascii_uppercase()))
+    /// };
+    /// let whisper = |f: &mut std::fmt::Formatter| f.write_str("nobody");
+    ///
+    /// assert_eq!("ALICE", format!("{}", Some("alice").fmt_either(shout, whisper)));
+    /// assert_eq!("nobody", format!("{}", None.fmt_either(shout, whisper)));
+    /// ```
+    fn fmt_either<'t, P, A>(&'t self, present: P, absent: A) -> MaybeFormatEither<'t, T, P, A>
+    where
+        P: Fn(&T, &mut Formatter<'_>) -> Result,
+        A: Fn(&mut Formatter<'_>) -> Result;
+    /// Format the value, if there is one, or run the closure with the formatter and display its
+    /// result instead.
+    ///
+    /// The closure can read the requested flags, such as [`Formatter::alternate`] or
+    /// [`Formatter::width`], to pick a fitting fallback. Unlike
+    /// [`fmt_or_with`](FmtOr::fmt_or_with), it returns a value which is then displayed with the
+    /// full format spec, rather than writing to the formatter itself.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOr;
+    ///
+    /// let foo: Option<u32> = None;
+    /// let wrapper = foo.fmt_or_else_ctx(|f| if f.alternate() { "0x?" } else { "?" });
+    ///
+    /// assert_eq!("?", format!("{:x}", wrapper));
+    /// assert_eq!("0x?", format!("{:#x}", wrapper));
+    /// ```
+    fn fmt_or_else_ctx<'t, U, F>(&'t self, f: F) -> MaybeFormatOrElseCtx<'t, T, F>
+    where
+        U: Display,
+        F: Fn(&Formatter<'_>) -> U;
+}
+
+impl<T> sealed::Sealed for Option<T> {}
+
+impl<T> FmtOr<T> for Option<T> {
+    #[inline]
+    fn fmt_or_empty(&self) -> MaybeFormat<'_, T> {
+        MaybeFormat(self)
+    }
+    #[inline]
+    fn fmt_or<U>(&self, u: U) -> MaybeFormatOr<'_, T, U>
+    where
+        U: Display,
+    {
+        MaybeFormatOr(self, u)
+    }
+    #[inline]
+    fn fmt_or_else<U, F>(&self, f: F) -> MaybeFormatOrElse<'_, T, F>
+    where
+        U: Display,
+        F: Fn() -> U,
+    {
+        MaybeFormatOrElse(self, f)
+    }
+    #[inline]
+    fn fmt_or_default_display<U>(&self) -> MaybeFormatOrDefaultDisplay<'_, T, U>
+    where
+        U: Default + Display,
+    {
+        MaybeFormatOrDefaultDisplay(self, PhantomData)
+    }
+    #[inline]
+    fn fmt_or_typed_none(&self) -> MaybeFormatTypedNone<'_, T> {
+        MaybeFormatTypedNone(self)
+    }
+    #[inline]
+    fn fmt_or_default(&self) -> MaybeFormatDefault<'_, T>
+    where
+        T: Default,
+    {
+        MaybeFormatDefault(self)
+    }
+    #[inline]
+    fn fmt_or_same(&self, u: T) -> MaybeFormatSame<'_, T> {
+        MaybeFormatSame(self, u)
+    }
+    #[inline]
+    fn fmt_or_num<N>(&self, n: N) -> MaybeFormatOrNum<'_, T, N> {
+        MaybeFormatOrNum(self, n)
+    }
+    #[inline]
+    fn fmt_or_opt<'t>(&'t self, other: &'t Option<T>) -> MaybeFormatOrOpt<'t, MaybeFormat<'t, T>> {
+        MaybeFormat(self).or_opt(other)
+    }
+    #[inline]
+    fn fmt_or_debug_none(&self) -> MaybeFormatDebugNone<'_, T> {
+        MaybeFormatDebugNone(self)
+    }
+    #[inline]
+    fn fmt_or_error(&self) -> MaybeFormatOrError<'_, T> {
+        MaybeFormatOrError(self)
+    }
+    #[inline]
+    fn fmt_set_or_unset<'t>(
+        &'t self,
+        set: &'t str,
+        unset: &'t str,
+    ) -> MaybeFormatSetOrUnset<'t, T> {
+        MaybeFormatSetOrUnset(self, set, unset)
+    }
+    #[inline]
+    fn fmt_or_ref<'t, U>(&'t self, u: &'t U) -> MaybeFormatOrRef<'t, T, U>
+    where
+        U: Display + ?Sized,
+    {
+        MaybeFormatOrRef(self, u)
+    }
+    #[inline]
+    fn fmt_or_hex<N>(&self, n: N) -> MaybeFormatOrHex<'_, T, N>
+    where
+        N: LowerHex + UpperHex,
+    {
+        MaybeFormatOrHex(self, n)
+    }
+    #[inline]
+    fn fmt_or_counting<'c, U>(
+        &self,
+        u: U,
+        written: &'c Cell<usize>,
+    ) -> MaybeFormatCounting<'_, 'c, T, U>
+    where
+        U: Display,
+    {
+        MaybeFormatCounting(self, u, written)
+    }
+    #[inline]
+    fn fmt_or_char(&self, c: char) -> MaybeFormatOrChar<'_, T> {
+        MaybeFormatOrChar(self, c)
+    }
+    #[inline]
+    fn fmt_ptr_or_null(&self, style: NullPtr) -> MaybeFormatPtrOrNull<'_, T> {
+        MaybeFormatPtrOrNull(self, style)
+    }
+    #[inline]
+    fn fmt_or_debug<U>(&self, u: U) -> MaybeFormatOrDebug<'_, T, U>
+    where
+        U: Debug,
+    {
+        MaybeFormatOrDebug(self, u)
+    }
+    #[inline]
+    fn fmt_or_sentinel<'t>(&'t self, sentinels: Sentinels<'t>) -> MaybeFormatSentinel<'t, T> {
+        MaybeFormatSentinel(self, sentinels)
+    }
+    #[inline]
+    fn fmt_or_args<'t>(&'t self, args: Arguments<'t>) -> MaybeFormatOrArgs<'t, T> {
+        MaybeFormatOrArgs(self, args)
+    }
+    #[inline]
+    fn fmt_map<G, V>(&self, g: G) -> MaybeFormatMap<'_, T, G>
+    where
+        G: Fn(&T) -> V,
+    {
+        MaybeFormatMap(self, g)
+    }
+    #[inline]
+    fn fmt_wrap<'t>(&'t self, prefix: &'t str, suffix: &'t str) -> MaybeFormatWrap<'t, T> {
+        MaybeFormatWrap(self, prefix, suffix)
+    }
+    #[inline]
+    fn fmt_present<'t>(&'t self, prefix: &'t str) -> MaybeFormatPresent<'t, T> {
+        MaybeFormatPresent(self, prefix)
+    }
+    #[inline]
+    fn write_or<W: Write + ?Sized>(&self, out: &mut W, fallback: &str) -> Result
+    where
+        T: Display,
+    {
+        match self {
+            Some(t) => write!(out, "{}", t),
+            None => out.write_str(fallback),
+        }
+    }
+    #[inline]
+    fn write_or_fmt<W: Write + ?Sized>(&self, out: &mut W, fallback: Arguments<'_>) -> Result
+    where
+        T: Display,
+    {
+        match self {
+            Some(t) => write!(out, "{}", t),
+            None => out.write_fmt(fallback),
+        }
+    }
+    #[inline]
+    fn write_fmt_or<W: Write + ?Sized>(&self, out: &mut W, fmt: Fmt, fallback: &str) -> Result
+    where
+        T: Display + Debug + LowerHex + UpperHex + Octal + Binary + LowerExp + UpperExp,
+    {
+        match self {
+            Some(t) => fmt.write(out, t),
+            None => out.write_str(fallback),
+        }
+    }
+    #[inline]
+    fn fmt_or_dyn<U>(&self, kind: FmtKind, fallback: U) -> MaybeFormatDyn<'_, T, U>
+    where
+        U: Display,
+    {
+        MaybeFormatDyn(self, kind, fallback)
+    }
+    #[inline]
+    fn into_fmt_or_empty(self) -> OwnedMaybeFormat<T> {
+        OwnedMaybeFormat(self)
+    }
+    #[inline]
+    fn into_fmt_or<U>(self, u: U) -> OwnedMaybeFormatOr<T, U>
+    where
+        U: Display,
+    {
+        OwnedMaybeFormatOr(self, u)
+    }
+    #[inline]
+    fn into_fmt_or_else<U, F>(self, f: F) -> OwnedMaybeFormatOrElse<T, F>
+    where
+        U: Display,
+        F: Fn() -> U,
+    {
+        OwnedMaybeFormatOrElse(self, f)
+    }
+    #[inline]
+    fn fmt_or_else_cached<U, F>(&self, f: F) -> MaybeFormatOrElseCached<'_, T, F, U>
+    where
+        U: Display,
+        F: FnOnce() -> U,
+    {
+        MaybeFormatOrElseCached(self, Cell::new(Some(f)), OnceCell::new())
+    }
+    #[inline]
+    fn fmt_or_else_once<U, F>(&self, f: F) -> MaybeFormatOrElseOnce<'_, T, F>
+    where
+        U: Display,
+        F: FnOnce() -> U,
+    {
+        MaybeFormatOrElseOnce(self, Cell::new(Some(f)))
+    }
+    #[inline]
+    fn fmt_or_eager<U, F>(&self, make: F) -> MaybeFormatOrEager<'_, T, U>
+    where
+        U: Display,
+        F: FnOnce() -> U,
+    {
+        MaybeFormatOrEager(self, if self.is_none() { Some(make()) } else { None })
+    }
+    #[inline]
+    fn fmt_or_dyn_all<'u>(&self, fallback: &'u dyn AllFmt) -> MaybeFormatOrDynAll<'_, 'u, T> {
+        MaybeFormatOrDynAll(self, fallback)
+    }
+    #[inline]
+    fn fmt_or_with<G>(&self, g: G) -> MaybeFormatOrWith<'_, T, G>
+    where
+        G: Fn(&mut Formatter<'_>) -> Result,
+    {
+        MaybeFormatOrWith(self, g)
+    }
+    #[inline]
+    fn fmt_or_map_opt<'t, B, G, D>(
+        &'t self,
+        other: &'t Option<B>,
+        to_display: G,
+    ) -> MaybeFormatOrMapOpt<'t, T, B, G>
+    where
+        G: Fn(&B) -> D,
+        D: Display,
+    {
+        MaybeFormatOrMapOpt(self, other, to_display)
+    }
+    #[inline]
+    fn fmt_display_or<'t, G>(
+        &'t self,
+        on_some: G,
+        fallback: &'t str,
+    ) -> MaybeFormatDisplayWith<'t, T, G>
+    where
+        G: Fn(&T, &mut Formatter<'_>) -> Result,
+    {
+        MaybeFormatDisplayWith(self, on_some, fallback)
+    }
+    #[inline]
+    fn fmt_either<P, A>(&self, present: P, absent: A) -> MaybeFormatEither<'_, T, P, A>
+    where
+        P: Fn(&T, &mut Formatter<'_>) -> Result,
+        A: Fn(&mut Formatter<'_>) -> Result,
+    {
+        MaybeFormatEither(self, present, absent)
+    }
+    #[inline]
+    fn fmt_or_else_ctx<U, F>(&self, f: F) -> MaybeFormatOrElseCtx<'_, T, F>
+    where
+        U: Display,
+        F: Fn(&Formatter<'_>) -> U,
+    {
+        MaybeFormatOrElseCtx(self, f)
+    }
+}
+
+macro_rules! impl_fmt_traits {
+    ($($Trait:ident),*$(,)?) => {$(
+
+impl<'t, T> $Trait for MaybeFormatEmpty<'t, T>
+where
+    T: $Trait,
+{
+    #[inline]
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) => <T as $Trait>::fmt(t, out),
+            None => Display::fmt(self.1, out),
+        }
+    }
+}
+
+impl<'t, T> $Trait for MaybeFormat<'t, T>
+where
+    T: $Trait,
+{
+    #[inline]
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) => <T as $Trait>::fmt(t, out),
+            // Without a width there is nothing to pad, so nothing at all is written.
+            None if out.width().is_none() && out.precision().is_none() => Ok(()),
+            None => Display::fmt("", out),
+        }
+    }
+}
+
+impl<'t, T, U> $Trait for MaybeFormatOr<'t, T, U>
+where
+    T: $Trait,
+    U: Display,
+{
+    #[inline]
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) => <T as $Trait>::fmt(t, out),
+            None => Display::fmt(&self.1, out),
+        }
+    }
 }
 
-impl<T> FmtOr<T> for Option<T> {
+impl<'t, T, F, U> $Trait for MaybeFormatOrElse<'t, T, F>
+where
+    T: $Trait,
+    F: Fn() -> U,
+    U: Display,
+{
     #[inline]
-    fn fmt_or_empty(&self) -> MaybeFormat<T> {
-        MaybeFormat(self)
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) => <T as $Trait>::fmt(t, out),
+            None => Display::fmt(&self.1(), out),
+        }
     }
+}
+
+impl<'t, T, U> $Trait for MaybeFormatOrDefaultDisplay<'t, T, U>
+where
+    T: $Trait,
+    U: Default + Display,
+{
     #[inline]
-    fn fmt_or<U>(&self, u: U) -> MaybeFormatOr<T, U>
-    where
-        U: Display,
-    {
-        MaybeFormatOr(self, u)
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) => <T as $Trait>::fmt(t, out),
+            None => Display::fmt(&U::default(), out),
+        }
     }
+}
+
+impl<'t, T> $Trait for MaybeFormatTypedNone<'t, T>
+where
+    T: $Trait,
+{
     #[inline]
-    fn fmt_or_else<U, F>(&self, f: F) -> MaybeFormatOrElse<T, F>
-    where
-        U: Display,
-        F: Fn() -> U,
-    {
-        MaybeFormatOrElse(self, f)
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) => <T as $Trait>::fmt(t, out),
+            None => pad(out, Alignment::Left, |w| {
+                write!(w, "<missing {}>", type_name::<T>())
+            }),
+        }
     }
 }
 
-macro_rules! impl_fmt_traits {
-    ($($Trait:ident),*$(,)?) => {$(
+impl<'t, T> $Trait for MaybeFormatDefault<'t, T>
+where
+    T: $Trait + Default,
+{
+    #[inline]
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) => <T as $Trait>::fmt(t, out),
+            None => <T as $Trait>::fmt(&T::default(), out),
+        }
+    }
+}
 
-impl<'t, T> $Trait for MaybeFormat<'t, T>
+impl<'t, T> $Trait for MaybeFormatSame<'t, T>
 where
     T: $Trait,
 {
     #[inline]
     fn fmt(&self, out: &mut Formatter<'_>) -> Result {
-        $Trait::fmt(&self.0.fmt_or(""), out)
+        match self.0 {
+            Some(t) => <T as $Trait>::fmt(t, out),
+            None => <T as $Trait>::fmt(&self.1, out),
+        }
     }
 }
 
-impl<'t, T, U> $Trait for MaybeFormatOr<'t, T, U>
+impl<'t, T, F, U> $Trait for MaybeFormatOrElseOnce<'t, T, F>
 where
     T: $Trait,
+    F: FnOnce() -> U,
     U: Display,
 {
     #[inline]
     fn fmt(&self, out: &mut Formatter<'_>) -> Result {
-        $Trait::fmt(&self.0.fmt_or_else(||&self.1), out)
+        match (self.0, self.1.take()) {
+            (Some(t), f) => {
+                self.1.set(f);
+                <T as $Trait>::fmt(t, out)
+            }
+            (None, Some(f)) => Display::fmt(&f(), out),
+            (None, None) => Display::fmt("", out),
+        }
     }
 }
 
-impl<'t, T, F, U> $Trait for MaybeFormatOrElse<'t, T, F>
+impl<'t, T, F, U> $Trait for MaybeFormatOrElseCached<'t, T, F, U>
 where
     T: $Trait,
-    F: Fn() -> U,
+    F: FnOnce() -> U,
     U: Display,
 {
     #[inline]
     fn fmt(&self, out: &mut Formatter<'_>) -> Result {
         if let Some(t) = self.0 {
-            <T as $Trait>::fmt(t, out)
-        } else {
-            Display::fmt(&self.1(), out)
+            return <T as $Trait>::fmt(t, out);
+        }
+        if self.2.get().is_none() {
+            // The closure is only missing if it panicked on an earlier call.
+            if let Some(f) = self.1.take() {
+                let _ = self.2.set(f());
+            }
+        }
+        match self.2.get() {
+            Some(u) => Display::fmt(u, out),
+            None => Display::fmt("", out),
+        }
+    }
+}
+
+impl<'t, T> $Trait for MaybeFormatOrArgs<'t, T>
+where
+    T: $Trait,
+{
+    #[inline]
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) => <T as $Trait>::fmt(t, out),
+            None => Display::fmt(&self.1, out),
+        }
+    }
+}
+
+impl<'t, T, U> $Trait for MaybeFormatOrDebug<'t, T, U>
+where
+    T: $Trait,
+    U: Debug,
+{
+    #[inline]
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) => <T as $Trait>::fmt(t, out),
+            None => Debug::fmt(&self.1, out),
+        }
+    }
+}
+
+impl<'t, T> $Trait for MaybeFormatWrap<'t, T>
+where
+    T: $Trait,
+{
+    #[inline]
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) => {
+                out.write_str(self.1)?;
+                <T as $Trait>::fmt(t, out)?;
+                out.write_str(self.2)
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+impl<'t, T> $Trait for MaybeFormatPresent<'t, T>
+where
+    T: $Trait,
+{
+    #[inline]
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) => {
+                out.write_str(self.1)?;
+                <T as $Trait>::fmt(t, out)
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+impl<'t, T, G, V> $Trait for MaybeFormatMap<'t, T, G>
+where
+    G: Fn(&T) -> V,
+    V: $Trait,
+{
+    #[inline]
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) => <V as $Trait>::fmt(&(self.1)(t), out),
+            None => Display::fmt("", out),
+        }
+    }
+}
+
+impl<'t, T, G, V, U> $Trait for MaybeFormatMapOr<'t, T, G, U>
+where
+    G: Fn(&T) -> V,
+    V: $Trait,
+    U: Display,
+{
+    #[inline]
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) => <V as $Trait>::fmt(&(self.1)(t), out),
+            None => Display::fmt(&self.2, out),
+        }
+    }
+}
+
+impl<'t, T, G, V, F, U> $Trait for MaybeFormatMapOrElse<'t, T, G, F>
+where
+    G: Fn(&T) -> V,
+    V: $Trait,
+    F: Fn() -> U,
+    U: Display,
+{
+    #[inline]
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) => <V as $Trait>::fmt(&(self.1)(t), out),
+            None => Display::fmt(&(self.2)(), out),
+        }
+    }
+}
+
+impl<'t, T, N> $Trait for MaybeFormatOrNum<'t, T, N>
+where
+    T: $Trait,
+    N: $Trait,
+{
+    #[inline]
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) => <T as $Trait>::fmt(t, out),
+            None => <N as $Trait>::fmt(&self.1, out),
+        }
+    }
+}
+
+impl<'t, T, U> $Trait for MaybeFormatOrEager<'t, T, U>
+where
+    T: $Trait,
+    U: Display,
+{
+    #[inline]
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match (self.0, &self.1) {
+            (Some(t), _) => <T as $Trait>::fmt(t, out),
+            (None, Some(u)) => Display::fmt(u, out),
+            // The fallback is always made for a missing value.
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+impl<'t, T> $Trait for MaybeFormatOrError<'t, T>
+where
+    T: $Trait,
+{
+    #[inline]
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) => <T as $Trait>::fmt(t, out),
+            None => Err(core::fmt::Error),
+        }
+    }
+}
+
+impl<'t, T> $Trait for MaybeFormatSetOrUnset<'t, T> {
+    #[inline]
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(_) => Display::fmt(self.1, out),
+            None => Display::fmt(self.2, out),
+        }
+    }
+}
+
+impl<'t, T, U> $Trait for MaybeFormatOrRef<'t, T, U>
+where
+    T: $Trait,
+    U: Display + ?Sized,
+{
+    #[inline]
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) => <T as $Trait>::fmt(t, out),
+            None => Display::fmt(self.1, out),
+        }
+    }
+}
+
+impl<'t, T> $Trait for MaybeFormatOrChar<'t, T>
+where
+    T: $Trait,
+{
+    #[inline]
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) => <T as $Trait>::fmt(t, out),
+            None if out.width().is_none() => out.write_char(self.1),
+            None => Display::fmt(&self.1, out),
+        }
+    }
+}
+
+impl<'t, T, F, U> $Trait for MaybeFormatOrElseCtx<'t, T, F>
+where
+    T: $Trait,
+    F: Fn(&Formatter<'_>) -> U,
+    U: Display,
+{
+    #[inline]
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) => <T as $Trait>::fmt(t, out),
+            None => Display::fmt(&(self.1)(out), out),
+        }
+    }
+}
+
+impl<'t, T, G> $Trait for MaybeFormatOrWith<'t, T, G>
+where
+    T: $Trait,
+    G: Fn(&mut Formatter<'_>) -> Result,
+{
+    #[inline]
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) => <T as $Trait>::fmt(t, out),
+            None => (self.1)(out),
+        }
+    }
+}
+
+impl<'t, T, B, G, D> $Trait for MaybeFormatOrMapOpt<'t, T, B, G>
+where
+    T: $Trait,
+    G: Fn(&B) -> D,
+    D: Display,
+{
+    #[inline]
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match (self.0, self.1) {
+            (Some(t), _) => <T as $Trait>::fmt(t, out),
+            (None, Some(b)) => Display::fmt(&(self.2)(b), out),
+            (None, None) => Display::fmt("", out),
+        }
+    }
+}
+
+impl<'t, 'u, T> $Trait for MaybeFormatOrDynAll<'t, 'u, T>
+where
+    T: $Trait,
+{
+    #[inline]
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) => <T as $Trait>::fmt(t, out),
+            None => <dyn AllFmt as $Trait>::fmt(self.1, out),
         }
     }
 }
@@ -274,3 +2488,86 @@ where
 } // macro_rules! impl_fmt_traits
 
 impl_fmt_traits!(Binary, Debug, Display, LowerExp, LowerHex, Octal, Pointer, UpperExp, UpperHex);
+
+macro_rules! impl_hex_traits {
+    ($($Trait:ident),*$(,)?) => {$(
+
+impl<'t, T, N> $Trait for MaybeFormatOrHex<'t, T, N>
+where
+    T: $Trait,
+    N: $Trait,
+{
+    #[inline]
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) => <T as $Trait>::fmt(t, out),
+            None => <N as $Trait>::fmt(&self.1, out),
+        }
+    }
+}
+
+    )*}
+} // macro_rules! impl_hex_traits
+
+impl_hex_traits!(LowerHex, UpperHex);
+
+macro_rules! impl_debug_none_traits {
+    ($($Trait:ident),*$(,)?) => {$(
+
+impl<'t, T> $Trait for MaybeFormatDebugNone<'t, T>
+where
+    T: $Trait,
+{
+    #[inline]
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) => <T as $Trait>::fmt(t, out),
+            None => Display::fmt("", out),
+        }
+    }
+}
+
+    )*}
+} // macro_rules! impl_debug_none_traits
+
+impl_debug_none_traits!(Binary, Display, LowerExp, LowerHex, Octal, Pointer, UpperExp, UpperHex);
+
+impl<'t, T, G> Display for MaybeFormatDisplayWith<'t, T, G>
+where
+    G: Fn(&T, &mut Formatter<'_>) -> Result,
+{
+    #[inline]
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) => (self.1)(t, out),
+            None => Display::fmt(self.2, out),
+        }
+    }
+}
+
+impl<'t, T, P, A> Display for MaybeFormatEither<'t, T, P, A>
+where
+    P: Fn(&T, &mut Formatter<'_>) -> Result,
+    A: Fn(&mut Formatter<'_>) -> Result,
+{
+    #[inline]
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) => (self.1)(t, out),
+            None => (self.2)(out),
+        }
+    }
+}
+
+impl<'t, T> Debug for MaybeFormatDebugNone<'t, T>
+where
+    T: Debug,
+{
+    #[inline]
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) => Debug::fmt(t, out),
+            None => Display::fmt("None", out),
+        }
+    }
+}
@@ -0,0 +1,44 @@
+//! Macros shortening the common calls.
+
+/// Shorthand for the common [`FmtOr`](crate::FmtOr) calls, without importing the trait.
+///
+/// - `fmtor!(opt)` is `opt.fmt_or_empty()`
+/// - `fmtor!(opt, fallback)` is `opt.fmt_or(fallback)`
+/// - `fmtor!(opt => prefix, suffix)` is `opt.fmt_wrap(prefix, suffix)`
+///
+/// These are method calls, so `opt` may also be a reference to an [`Option`] or anything else
+/// which derefs to one, such as a `Box`. The option is borrowed, so `opt` may be a temporary as
+/// long as the result is used in the same statement, as in a `format!` argument.
+///
+/// # Example
+///
+/// ```rust
+/// use fmtor::fmtor;
+///
+/// let file = Some("main.rs");
+/// let line: Option<u32> = Some(56);
+/// let msg = None::<&str>;
+///
+/// assert_eq!(
+///     "main.rs:56 - n/a",
+///     format!("{}{} - {}", fmtor!(file), fmtor!(line => ":", ""), fmtor!(msg, "n/a"))
+/// );
+/// ```
+#[macro_export]
+macro_rules! fmtor {
+    ($opt:expr => $prefix:expr, $suffix:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::FmtOr as _;
+        ($opt).fmt_wrap($prefix, $suffix)
+    }};
+    ($opt:expr, $fallback:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::FmtOr as _;
+        ($opt).fmt_or($fallback)
+    }};
+    ($opt:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::FmtOr as _;
+        ($opt).fmt_or_empty()
+    }};
+}
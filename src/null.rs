@@ -0,0 +1,34 @@
+//! Ready-made fallback values.
+
+use core::fmt::{Display, Formatter, Result};
+
+/// A fallback which displays as `null`.
+///
+/// # Example
+///
+/// ```rust
+/// use fmtor::{FmtOr, Null, NullUpper};
+///
+/// let maybe_ref: Option<&u32> = None;
+///
+/// assert_eq!("null", format!("{:p}", maybe_ref.fmt_or(Null)));
+/// assert_eq!("NULL", format!("{:p}", maybe_ref.fmt_or(NullUpper)));
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Null;
+
+/// A fallback which displays as `NULL`, as in SQL. See [`Null`] for the lowercase form.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct NullUpper;
+
+impl Display for Null {
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        Display::fmt("null", out)
+    }
+}
+
+impl Display for NullUpper {
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        Display::fmt("NULL", out)
+    }
+}
@@ -0,0 +1,1907 @@
+//! Adapters for optional numbers.
+
+#[cfg(test)]
+mod tests;
+
+use core::fmt::{Alignment, Display, Formatter, Result, Write};
+
+use crate::ansi;
+use crate::pad::pad;
+use crate::FmtOr;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A primitive numeric type.
+///
+/// This trait is sealed and implemented for all of the built-in integer and floating point types.
+pub trait Number: sealed::Sealed + Copy + PartialOrd + Display {
+    /// Returns `true` if the value is less than zero.
+    fn is_negative(self) -> bool;
+    /// Returns `true` unless the value is infinite or NaN.
+    fn is_finite(self) -> bool;
+    /// Writes the magnitude of the value with exactly `decimals` digits after the decimal point.
+    #[doc(hidden)]
+    fn write_fixed_abs(self, out: &mut dyn Write, decimals: usize) -> Result;
+    /// Converts the value to the nearest [`f64`].
+    #[doc(hidden)]
+    fn to_f64(self) -> f64;
+    /// Writes the absolute difference between the two values, without overflowing.
+    #[doc(hidden)]
+    fn write_abs_diff(self, other: Self, out: &mut dyn Write) -> Result;
+}
+
+/// A primitive integer type.
+///
+/// This trait is sealed and implemented for all of the built-in integer types.
+pub trait Integer: Number {
+    /// The size of this integer type in bits.
+    const BITS: u32;
+    /// Returns the magnitude of the value.
+    fn unsigned_abs(self) -> u128;
+    /// Returns the bits of the value in two's complement, zero-extended to 128 bits.
+    fn to_bits(self) -> u128;
+    /// Returns the distance of the value from the type's minimum, which orders the same as the value.
+    #[doc(hidden)]
+    fn to_sort_key(self) -> u128;
+}
+
+/// Writes a `.` followed by `decimals` zeros, or nothing when `decimals` is zero.
+fn write_zero_decimals(out: &mut dyn Write, decimals: usize) -> Result {
+    if decimals > 0 {
+        out.write_char('.')?;
+        for _ in 0..decimals {
+            out.write_char('0')?;
+        }
+    }
+    Ok(())
+}
+
+macro_rules! impl_integer {
+    (signed: $($S:ident),*; unsigned: $($U:ident),*) => {
+        $(
+            impl sealed::Sealed for $S {}
+            impl Number for $S {
+                #[inline]
+                fn is_negative(self) -> bool {
+                    self < 0
+                }
+                #[inline]
+                fn is_finite(self) -> bool {
+                    true
+                }
+                fn write_fixed_abs(self, out: &mut dyn Write, decimals: usize) -> Result {
+                    write!(out, "{}", <$S>::unsigned_abs(self))?;
+                    write_zero_decimals(out, decimals)
+                }
+                #[inline]
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+                fn write_abs_diff(self, other: Self, out: &mut dyn Write) -> Result {
+                    write!(out, "{}", self.abs_diff(other))
+                }
+            }
+            impl Integer for $S {
+                const BITS: u32 = <$S>::BITS;
+                #[inline]
+                fn unsigned_abs(self) -> u128 {
+                    <$S>::unsigned_abs(self) as u128
+                }
+                #[inline]
+                fn to_bits(self) -> u128 {
+                    self as u128 & (u128::MAX >> (128 - <$S>::BITS))
+                }
+                #[inline]
+                fn to_sort_key(self) -> u128 {
+                    (self as i128).wrapping_sub(<$S>::MIN as i128) as u128
+                }
+            }
+        )*
+        $(
+            impl sealed::Sealed for $U {}
+            impl Number for $U {
+                #[inline]
+                fn is_negative(self) -> bool {
+                    false
+                }
+                #[inline]
+                fn is_finite(self) -> bool {
+                    true
+                }
+                fn write_fixed_abs(self, out: &mut dyn Write, decimals: usize) -> Result {
+                    write!(out, "{}", self)?;
+                    write_zero_decimals(out, decimals)
+                }
+                #[inline]
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+                fn write_abs_diff(self, other: Self, out: &mut dyn Write) -> Result {
+                    write!(out, "{}", self.abs_diff(other))
+                }
+            }
+            impl Integer for $U {
+                const BITS: u32 = <$U>::BITS;
+                #[inline]
+                fn unsigned_abs(self) -> u128 {
+                    self as u128
+                }
+                #[inline]
+                fn to_bits(self) -> u128 {
+                    self as u128
+                }
+                #[inline]
+                fn to_sort_key(self) -> u128 {
+                    self as u128
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(signed: i8, i16, i32, i64, i128, isize; unsigned: u8, u16, u32, u64, u128, usize);
+
+/// A primitive floating point type.
+///
+/// This trait is sealed and implemented for [`f32`] and [`f64`].
+pub trait Float: Number {
+    /// Zero, positive.
+    const ZERO: Self;
+    /// Returns the absolute value.
+    fn abs(self) -> Self;
+}
+
+macro_rules! impl_float {
+    ($($F:ident),*) => {$(
+        impl sealed::Sealed for $F {}
+        impl Number for $F {
+            #[inline]
+            fn is_negative(self) -> bool {
+                self < 0.0
+            }
+            #[inline]
+            fn is_finite(self) -> bool {
+                <$F>::is_finite(self)
+            }
+            fn write_fixed_abs(self, out: &mut dyn Write, decimals: usize) -> Result {
+                write!(out, "{:.*}", decimals, self.abs())
+            }
+            #[inline]
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+            fn write_abs_diff(self, other: Self, out: &mut dyn Write) -> Result {
+                write!(out, "{}", (self - other).abs())
+            }
+        }
+        impl Float for $F {
+            const ZERO: Self = 0.0;
+            #[inline]
+            fn abs(self) -> Self {
+                // `abs` is only in core since Rust 1.85.
+                if self.is_sign_negative() {
+                    -self
+                } else {
+                    self
+                }
+            }
+        }
+    )*};
+}
+
+impl_float!(f32, f64);
+
+/// Counts the characters written before the decimal point.
+struct IntDigits(usize, bool);
+
+impl Write for IntDigits {
+    fn write_str(&mut self, s: &str) -> Result {
+        for c in s.chars() {
+            if c == '.' {
+                self.1 = true;
+            } else if !self.1 {
+                self.0 += 1;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Passes writes through, inserting a separator between groups of integer digits.
+struct Grouped<'w> {
+    out: &'w mut dyn Write,
+    remaining: usize,
+    written: usize,
+    group: usize,
+    sep: char,
+}
+
+impl Write for Grouped<'_> {
+    fn write_str(&mut self, s: &str) -> Result {
+        for c in s.chars() {
+            if self.remaining > 0 {
+                if self.written > 0 && self.remaining % self.group == 0 {
+                    self.out.write_char(self.sep)?;
+                }
+                self.remaining -= 1;
+                self.written += 1;
+            }
+            self.out.write_char(c)?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes the magnitude of `n` with `decimals` decimal places and its integer
+/// digits grouped in threes by `sep`, if any.
+fn write_grouped_abs<N: Number>(
+    out: &mut dyn Write,
+    n: N,
+    decimals: usize,
+    sep: Option<char>,
+) -> Result {
+    let sep = match sep {
+        Some(sep) => sep,
+        None => return n.write_fixed_abs(out, decimals),
+    };
+    let mut digits = IntDigits(0, false);
+    n.write_fixed_abs(&mut digits, decimals)?;
+    let mut grouped = Grouped {
+        out,
+        remaining: digits.0,
+        written: 0,
+        group: 3,
+        sep,
+    };
+    n.write_fixed_abs(&mut grouped, decimals)
+}
+
+/// Passes writes through, inserting a separator after each group of characters counting from
+/// the left. Group sizes are taken from `groups` in order, with the last size repeating.
+pub(crate) struct GroupLeft<'w, 'g> {
+    out: &'w mut dyn Write,
+    groups: &'g [usize],
+    written: usize,
+    sep: &'g str,
+}
+
+impl<'w, 'g> GroupLeft<'w, 'g> {
+    pub(crate) fn new(out: &'w mut dyn Write, groups: &'g [usize], sep: &'g str) -> Self {
+        GroupLeft {
+            out,
+            groups,
+            written: 0,
+            sep,
+        }
+    }
+}
+
+impl Write for GroupLeft<'_, '_> {
+    fn write_str(&mut self, s: &str) -> Result {
+        for c in s.chars() {
+            if let Some((&size, rest)) = self.groups.split_first() {
+                if size > 0 && self.written == size {
+                    self.out.write_str(self.sep)?;
+                    self.written = 0;
+                    if !rest.is_empty() {
+                        self.groups = rest;
+                    }
+                }
+            }
+            self.out.write_char(c)?;
+            self.written += 1;
+        }
+        Ok(())
+    }
+}
+
+/// A display style for [`FmtOrInt::fmt_styled_or`], so the style can be picked at runtime, such
+/// as from configuration.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Style {
+    /// The value as it is, e.g. `1234567`, with the format spec applied.
+    Plain,
+    /// Digits grouped in threes by `,`, e.g. `1,234,567`.
+    Grouped,
+    /// Hex with a `0x` prefix, e.g. `0x12d687`.
+    Hex,
+    /// Abbreviated by magnitude to one decimal place, e.g. `1.2M`.
+    Compact,
+    /// The value followed by `%`, e.g. `42%`.
+    Percent,
+}
+
+/// Settings for [`FmtOrNum::fmt_money_spec_or`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct MoneySpec {
+    /// The separator inserted between each group of three integer digits, or `None` for no grouping.
+    pub group_sep: Option<char>,
+    /// The number of digits to show after the decimal point.
+    pub decimals: usize,
+    /// Whether to reserve a column for the sign, so that non-negative values begin with a space
+    /// where negative values begin with `-`.
+    pub sign_column: bool,
+}
+
+impl Default for MoneySpec {
+    /// Grouping by `,` with two decimal places and a sign column, e.g. ` 1,234.50` or `-1,234.50`.
+    fn default() -> Self {
+        MoneySpec {
+            group_sep: Some(','),
+            decimals: 2,
+            sign_column: true,
+        }
+    }
+}
+
+/// The type returned from [`FmtOrInt::fmt_unit_plural_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatUnitPlural<'t, T, U>(&'t Option<T>, &'t str, &'t str, U);
+/// The type returned from [`FmtOrNum::fmt_money_spec_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatMoney<'t, T, U>(&'t Option<T>, MoneySpec, U);
+/// The type returned from [`FmtOrNum::fmt_ltr_number_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatLtrNumber<'t, T, U>(&'t Option<T>, U);
+/// The type returned from [`FmtOrInt::fmt_group_left_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatGroupLeft<'t, T, U>(&'t Option<T>, &'t [usize], &'t str, U);
+/// The type returned from [`FmtOrNum::fmt_threshold_color_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatThresholdColor<'t, T, U>(&'t Option<T>, T, T, bool, U);
+/// The type returned from [`FmtOrInt::fmt_signed_clock_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatSignedClock<'t, T, U>(&'t Option<T>, U);
+/// The type returned from [`FmtOrFloat::fmt_epsilon_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatEpsilon<'t, T, U>(&'t Option<T>, T, U);
+/// The type returned from [`FmtOrInt::fmt_prefixed_radix_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatPrefixedRadix<'t, T, U>(&'t Option<T>, &'t str, u32, U);
+/// The type returned from [`FmtOrInt::fmt_flags_pipe_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatFlagsPipe<'t, T, U>(&'t Option<T>, &'t [(T, &'t str)], U);
+/// The type returned from [`FmtOrNum::fmt_json_value_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatJsonNumber<'t, T>(&'t Option<T>);
+/// The type returned from [`FmtOrInt::fmt_dec_hex_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatDecHex<'t, T, U>(&'t Option<T>, U);
+/// The type returned from [`FmtOrInterval::fmt_ci_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatCi<'t, T, U>(&'t Option<(T, T)>, usize, U);
+/// The type returned from [`FmtOrNum::fmt_pm_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatPm<'t, T, U>(&'t Option<T>, T, bool, U);
+/// The type returned from [`FmtOrNum::fmt_compact_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatCompact<'t, T, U>(&'t Option<T>, usize, U);
+/// The type returned from [`FmtOrInt::fmt_superscript_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatSuperscript<'t, T, U>(&'t Option<T>, U);
+/// The type returned from [`FmtOrFloat::fmt_bearing_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatBearing<'t, T, U>(&'t Option<T>, usize, U);
+/// The type returned from [`FmtOrNum::fmt_clamp_indicator_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatClampIndicator<'t, T, U>(&'t Option<T>, T, T, U);
+/// The type returned from [`FmtOrInt::fmt_hex_native_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatHexNative<'t, T, U>(&'t Option<T>, U);
+/// The type returned from [`FmtOrNum::fmt_trend_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatTrend<'t, T, U>(&'t Option<T>, T, U);
+/// The type returned from [`FmtOrNum::fmt_alert_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatAlert<'t, T, U>(&'t Option<T>, T, bool, U);
+/// The type returned from [`FmtOrFloat::fmt_fraction_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatFraction<'t, T, U>(&'t Option<T>, u32, U);
+/// The type returned from [`FmtOrNum::fmt_gauge_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatGauge<'t, T, U>(&'t Option<T>, T, T, usize, U);
+/// The type returned from [`FmtOrInt::fmt_styled_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatStyled<'t, T, U>(&'t Option<T>, Style, U);
+/// The type returned from [`FmtOrNum::fmt_pct_change_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatPctChange<'t, T, U>(&'t Option<T>, T, usize, U);
+/// The type returned from [`FmtOrInt::fmt_sortkey_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatSortKey<'t, T, U>(&'t Option<T>, U);
+
+/// An extension trait for [`Option<T>`] where `T` is any primitive [`Number`].
+///
+/// The wrappers returned from these methods implement [`Display`] only. Like [`FmtOr::fmt_or`],
+/// the fallback is formatted with the full format spec. Unless noted otherwise, a present value
+/// is rendered as a single piece of text which is then padded to the requested width. Methods
+/// which write marks around the value say whether the marks are inside the padding.
+///
+/// [`FmtOr::fmt_or`]: crate::FmtOr::fmt_or
+#[allow(clippy::needless_lifetimes)] // They're nice to see in docs
+pub trait FmtOrNum<T: Number>: sealed::Sealed {
+    /// Format the value as an amount of money according to `spec`, or display the given value instead.
+    ///
+    /// Present values are right-aligned by default when a width is given, so that a column of
+    /// amounts lines up on the decimal point.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::{FmtOrNum, MoneySpec};
+    ///
+    /// let spec = MoneySpec::default();
+    ///
+    /// assert_eq!(" 1,234.50", format!("{}", Some(1234.5).fmt_money_spec_or(spec, "-")));
+    /// assert_eq!("-1,234.50", format!("{}", Some(-1234.5).fmt_money_spec_or(spec, "-")));
+    /// assert_eq!("   12.00", format!("{:>8}", Some(12).fmt_money_spec_or(spec, "-")));
+    /// ```
+    fn fmt_money_spec_or<'t, U>(
+        &'t self,
+        spec: MoneySpec,
+        fallback: U,
+    ) -> MaybeFormatMoney<'t, T, U>
+    where
+        U: Display;
+    /// Format the value inside a left-to-right isolate, or display the given value instead.
+    ///
+    /// The value is surrounded by U+2066 LEFT-TO-RIGHT ISOLATE and U+2069 POP DIRECTIONAL
+    /// ISOLATE, so a number such as `-1.5` keeps its order when embedded in right-to-left text.
+    /// The format spec applies to the number itself, inside the isolate. The fallback is not
+    /// isolated.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrNum;
+    ///
+    /// assert_eq!("\u{2066}-1.50\u{2069}", format!("{:.2}", Some(-1.5).fmt_ltr_number_or("?")));
+    /// ```
+    fn fmt_ltr_number_or<'t, U>(&'t self, fallback: U) -> MaybeFormatLtrNumber<'t, T, U>
+    where
+        U: Display;
+    /// Format the value colored by how it compares to two thresholds, or display the given value instead.
+    ///
+    /// Values below `warn` are green, values at or above `warn` are yellow, and values at or above
+    /// `crit` are red. The colors are ANSI escape sequences and are only written when `enabled`
+    /// is `true`, so color can be switched off for non-terminal output. The format spec applies
+    /// to the value inside the escape sequences. The fallback is never colored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrNum;
+    ///
+    /// let load = Some(0.95);
+    ///
+    /// assert_eq!("\x1b[31m0.95\x1b[0m", format!("{}", load.fmt_threshold_color_or(0.7, 0.9, true, "-")));
+    /// assert_eq!("0.95", format!("{}", load.fmt_threshold_color_or(0.7, 0.9, false, "-")));
+    /// ```
+    fn fmt_threshold_color_or<'t, U>(
+        &'t self,
+        warn: T,
+        crit: T,
+        enabled: bool,
+        fallback: U,
+    ) -> MaybeFormatThresholdColor<'t, T, U>
+    where
+        U: Display;
+    /// Format the value as a JSON number, or `null` if there is no value.
+    ///
+    /// JSON has no representation for infinity or NaN, so those are also written as `null`.
+    /// See [`FmtOrStr::fmt_json_value_or`] for strings.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrNum;
+    ///
+    /// assert_eq!(r#"{"x": 1.5}"#, format!(r#"{{"x": {}}}"#, Some(1.5).fmt_json_value_or()));
+    /// assert_eq!(r#"{"x": null}"#, format!(r#"{{"x": {}}}"#, None::<f64>.fmt_json_value_or()));
+    /// ```
+    ///
+    /// [`FmtOrStr::fmt_json_value_or`]: crate::FmtOrStr::fmt_json_value_or
+    fn fmt_json_value_or<'t>(&'t self) -> MaybeFormatJsonNumber<'t, T>;
+    /// Format the value followed by `±` and `value2`, or display the given value instead.
+    ///
+    /// When `flip` is `true`, `∓` is used instead, for the opposite sign convention. The precision
+    /// of the format spec applies to both numbers, and the whole text is padded to the requested
+    /// width. The fallback is shown alone.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrNum;
+    ///
+    /// let energy = Some(4.2);
+    ///
+    /// assert_eq!("4.20 ± 0.10", format!("{:.2}", energy.fmt_pm_or(0.1, false, "-")));
+    /// assert_eq!("4.20 ∓ 0.10", format!("{:.2}", energy.fmt_pm_or(0.1, true, "-")));
+    /// ```
+    fn fmt_pm_or<'t, U>(&'t self, value2: T, flip: bool, fallback: U) -> MaybeFormatPm<'t, T, U>
+    where
+        U: Display;
+    /// Format the value abbreviated by its magnitude, such as `1.5K` or `2.3M`, or display the
+    /// given value instead.
+    ///
+    /// Values of a thousand or more are divided down and shown with `decimals` digits after the
+    /// decimal point followed by `K`, `M`, `B` or `T`. Smaller values, infinity and NaN are shown
+    /// as they are. Negative values keep their sign.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrNum;
+    ///
+    /// assert_eq!("999", format!("{}", Some(999).fmt_compact_or(1, "-")));
+    /// assert_eq!("1.5K", format!("{}", Some(1500).fmt_compact_or(1, "-")));
+    /// assert_eq!("-2.3M", format!("{}", Some(-2_300_000.0).fmt_compact_or(1, "-")));
+    /// ```
+    fn fmt_compact_or<'t, U>(
+        &'t self,
+        decimals: usize,
+        fallback: U,
+    ) -> MaybeFormatCompact<'t, T, U>
+    where
+        U: Display;
+    /// Format the value clamped into `min..=max`, marked when it was clamped, or display the given
+    /// value instead.
+    ///
+    /// A value above `max` is shown as `>max` and a value below `min` as `<min`. Values in range
+    /// are shown plainly. The precision of the format spec applies to the number shown, and the
+    /// whole text is padded to the requested width.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrNum;
+    ///
+    /// assert_eq!(">100", format!("{}", Some(250).fmt_clamp_indicator_or(0, 100, "-")));
+    /// assert_eq!("<0", format!("{}", Some(-3).fmt_clamp_indicator_or(0, 100, "-")));
+    /// assert_eq!("42", format!("{}", Some(42).fmt_clamp_indicator_or(0, 100, "-")));
+    /// ```
+    fn fmt_clamp_indicator_or<'t, U>(
+        &'t self,
+        min: T,
+        max: T,
+        fallback: U,
+    ) -> MaybeFormatClampIndicator<'t, T, U>
+    where
+        U: Display;
+    /// Format the value followed by its change from `prev`, or display the given value instead.
+    ///
+    /// The change is shown as `(↑n)` for an increase, `(↓n)` for a decrease and `(=)` for no
+    /// change, where `n` is the size of the change. Values which can't be compared, such as NaN,
+    /// are followed by `(?)`. The whole text is padded to the requested width.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrNum;
+    ///
+    /// assert_eq!("12 (↑2)", format!("{}", Some(12).fmt_trend_or(10, "-")));
+    /// assert_eq!("9 (↓1)", format!("{}", Some(9).fmt_trend_or(10, "-")));
+    /// assert_eq!("10 (=)", format!("{}", Some(10).fmt_trend_or(10, "-")));
+    /// ```
+    fn fmt_trend_or<'t, U>(&'t self, prev: T, fallback: U) -> MaybeFormatTrend<'t, T, U>
+    where
+        U: Display;
+    /// Format the value, preceded by a terminal bell when it is at or above `crit`, or display the
+    /// given value instead.
+    ///
+    /// The bell is the BEL control character, `\x07`. It can be switched off for non-terminal
+    /// output with [`MaybeFormatAlert::bell`]. The format spec applies to the value itself, so the
+    /// bell comes before any padding. The fallback never rings the bell.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrNum;
+    ///
+    /// let temp = Some(97);
+    ///
+    /// assert_eq!("\x0797", format!("{}", temp.fmt_alert_or(90, "-")));
+    /// assert_eq!("97", format!("{}", temp.fmt_alert_or(90, "-").bell(false)));
+    /// assert_eq!("97", format!("{}", temp.fmt_alert_or(100, "-")));
+    /// ```
+    fn fmt_alert_or<'t, U>(&'t self, crit: T, fallback: U) -> MaybeFormatAlert<'t, T, U>
+    where
+        U: Display;
+    /// Format the value as a gauge such as `[----|----]`, with the needle placed by where the
+    /// value falls between `min` and `max`, or display the given value instead.
+    ///
+    /// The gauge is `width` chars between the brackets, one of which is the needle. The fallback
+    /// is also used for values outside `min..=max`, for NaN, and when `width` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrNum;
+    ///
+    /// assert_eq!("[----|----]", format!("{}", Some(50).fmt_gauge_or(0, 100, 9, "-")));
+    /// assert_eq!("[|--------]", format!("{}", Some(0).fmt_gauge_or(0, 100, 9, "-")));
+    /// assert_eq!("-", format!("{}", Some(101).fmt_gauge_or(0, 100, 9, "-")));
+    /// ```
+    fn fmt_gauge_or<'t, U>(
+        &'t self,
+        min: T,
+        max: T,
+        width: usize,
+        fallback: U,
+    ) -> MaybeFormatGauge<'t, T, U>
+    where
+        U: Display;
+    /// Format the change from `prev` to the value as a signed percentage of `prev`, such as
+    /// `+12.3%` or `-4.5%`, or display the given value instead.
+    ///
+    /// The percentage has `decimals` digits after the decimal point, and no change is shown as
+    /// `0.0%` without a sign. When `prev` is zero, any change is infinite and is shown as `+∞` or
+    /// `-∞`. The whole text is padded to the requested width.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrNum;
+    ///
+    /// assert_eq!("+25.0%", format!("{}", Some(125).fmt_pct_change_or(100, 1, "-")));
+    /// assert_eq!("-4.5%", format!("{}", Some(95.5).fmt_pct_change_or(100.0, 1, "-")));
+    /// assert_eq!("+∞", format!("{}", Some(3).fmt_pct_change_or(0, 1, "-")));
+    /// ```
+    fn fmt_pct_change_or<'t, U>(
+        &'t self,
+        prev: T,
+        decimals: usize,
+        fallback: U,
+    ) -> MaybeFormatPctChange<'t, T, U>
+    where
+        U: Display;
+}
+
+impl<T> sealed::Sealed for Option<T> {}
+
+impl<T: Number> FmtOrNum<T> for Option<T> {
+    #[inline]
+    fn fmt_money_spec_or<U>(&self, spec: MoneySpec, fallback: U) -> MaybeFormatMoney<'_, T, U>
+    where
+        U: Display,
+    {
+        MaybeFormatMoney(self, spec, fallback)
+    }
+    #[inline]
+    fn fmt_ltr_number_or<U>(&self, fallback: U) -> MaybeFormatLtrNumber<'_, T, U>
+    where
+        U: Display,
+    {
+        MaybeFormatLtrNumber(self, fallback)
+    }
+    #[inline]
+    fn fmt_threshold_color_or<U>(
+        &self,
+        warn: T,
+        crit: T,
+        enabled: bool,
+        fallback: U,
+    ) -> MaybeFormatThresholdColor<'_, T, U>
+    where
+        U: Display,
+    {
+        MaybeFormatThresholdColor(self, warn, crit, enabled, fallback)
+    }
+    #[inline]
+    fn fmt_json_value_or(&self) -> MaybeFormatJsonNumber<'_, T> {
+        MaybeFormatJsonNumber(self)
+    }
+    #[inline]
+    fn fmt_pm_or<U>(&self, value2: T, flip: bool, fallback: U) -> MaybeFormatPm<'_, T, U>
+    where
+        U: Display,
+    {
+        MaybeFormatPm(self, value2, flip, fallback)
+    }
+    #[inline]
+    fn fmt_compact_or<U>(&self, decimals: usize, fallback: U) -> MaybeFormatCompact<'_, T, U>
+    where
+        U: Display,
+    {
+        MaybeFormatCompact(self, decimals, fallback)
+    }
+    #[inline]
+    fn fmt_clamp_indicator_or<U>(
+        &self,
+        min: T,
+        max: T,
+        fallback: U,
+    ) -> MaybeFormatClampIndicator<'_, T, U>
+    where
+        U: Display,
+    {
+        MaybeFormatClampIndicator(self, min, max, fallback)
+    }
+    #[inline]
+    fn fmt_trend_or<U>(&self, prev: T, fallback: U) -> MaybeFormatTrend<'_, T, U>
+    where
+        U: Display,
+    {
+        MaybeFormatTrend(self, prev, fallback)
+    }
+    #[inline]
+    fn fmt_alert_or<U>(&self, crit: T, fallback: U) -> MaybeFormatAlert<'_, T, U>
+    where
+        U: Display,
+    {
+        MaybeFormatAlert(self, crit, true, fallback)
+    }
+    #[inline]
+    fn fmt_gauge_or<U>(
+        &self,
+        min: T,
+        max: T,
+        width: usize,
+        fallback: U,
+    ) -> MaybeFormatGauge<'_, T, U>
+    where
+        U: Display,
+    {
+        MaybeFormatGauge(self, min, max, width, fallback)
+    }
+    #[inline]
+    fn fmt_pct_change_or<U>(
+        &self,
+        prev: T,
+        decimals: usize,
+        fallback: U,
+    ) -> MaybeFormatPctChange<'_, T, U>
+    where
+        U: Display,
+    {
+        MaybeFormatPctChange(self, prev, decimals, fallback)
+    }
+}
+
+/// An extension trait for [`Option<T>`] where `T` is a primitive [`Float`].
+///
+/// Unless noted otherwise, the wrappers returned from these methods implement [`Display`] only.
+/// Like [`FmtOr::fmt_or`], the fallback is formatted with the full format spec, and so is a present
+/// value.
+///
+/// [`FmtOr::fmt_or`]: crate::FmtOr::fmt_or
+#[allow(clippy::needless_lifetimes)] // They're nice to see in docs
+pub trait FmtOrFloat<T: Float>: sealed::Sealed {
+    /// Format the value, shown as zero when it is closer to zero than `epsilon`, or display the
+    /// given value instead.
+    ///
+    /// This hides floating point noise such as `1e-17` left over from a calculation. The format
+    /// spec applies to the zero as well, so `{:.2}` shows `0.00`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrFloat;
+    ///
+    /// let noise = Some(0.1 + 0.2 - 0.3);
+    ///
+    /// assert_eq!("0", format!("{}", noise.fmt_epsilon_or(1e-9, "-")));
+    /// assert_eq!("0.00", format!("{:.2}", noise.fmt_epsilon_or(1e-9, "-")));
+    /// ```
+    fn fmt_epsilon_or<'t, U>(&'t self, epsilon: T, fallback: U) -> MaybeFormatEpsilon<'t, T, U>
+    where
+        U: Display;
+    /// Format the value as a bearing in degrees, normalized into `[0, 360)` and followed by `°`,
+    /// or display the given value instead.
+    ///
+    /// The bearing is shown with `decimals` digits after the decimal point. A bearing which would
+    /// round up to `360` is shown as `0`. Infinity and NaN have no bearing and are shown as `NaN°`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrFloat;
+    ///
+    /// assert_eq!("10.0°", format!("{}", Some(370.0).fmt_bearing_or(1, "-")));
+    /// assert_eq!("270°", format!("{}", Some(-90.0).fmt_bearing_or(0, "-")));
+    /// ```
+    fn fmt_bearing_or<'t, U>(
+        &'t self,
+        decimals: usize,
+        fallback: U,
+    ) -> MaybeFormatBearing<'t, T, U>
+    where
+        U: Display;
+    /// Format the value as the nearest fraction with a denominator of at most `max_denom`, such as
+    /// `3/4` or `1 1/2`, or display the given value instead.
+    ///
+    /// Whole numbers are shown without a fraction, and the whole part is left out when it is zero.
+    /// Fractions are in lowest terms. A `max_denom` of zero is treated as one. Infinity, NaN and
+    /// values of at least 2<sup>53</sup>, which are always whole, are shown as they are.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrFloat;
+    ///
+    /// assert_eq!("3/4", format!("{}", Some(0.75).fmt_fraction_or(16, "-")));
+    /// assert_eq!("1 1/2", format!("{}", Some(1.5).fmt_fraction_or(16, "-")));
+    /// assert_eq!("1/3", format!("{}", Some(0.33).fmt_fraction_or(8, "-")));
+    /// ```
+    fn fmt_fraction_or<'t, U>(
+        &'t self,
+        max_denom: u32,
+        fallback: U,
+    ) -> MaybeFormatFraction<'t, T, U>
+    where
+        U: Display;
+}
+
+impl<T: Float> FmtOrFloat<T> for Option<T> {
+    #[inline]
+    fn fmt_epsilon_or<U>(&self, epsilon: T, fallback: U) -> MaybeFormatEpsilon<'_, T, U>
+    where
+        U: Display,
+    {
+        MaybeFormatEpsilon(self, epsilon, fallback)
+    }
+    #[inline]
+    fn fmt_bearing_or<U>(&self, decimals: usize, fallback: U) -> MaybeFormatBearing<'_, T, U>
+    where
+        U: Display,
+    {
+        MaybeFormatBearing(self, decimals, fallback)
+    }
+    #[inline]
+    fn fmt_fraction_or<U>(&self, max_denom: u32, fallback: U) -> MaybeFormatFraction<'_, T, U>
+    where
+        U: Display,
+    {
+        MaybeFormatFraction(self, max_denom, fallback)
+    }
+}
+
+/// An extension trait for [`Option<T>`] where `T` is a primitive [`Integer`].
+///
+/// Unless noted otherwise, the wrappers returned from these methods implement [`Display`] only,
+/// the fallback is formatted with the full format spec like [`FmtOr::fmt_or`], and a present
+/// value is padded to the requested width as a single piece of text.
+///
+/// [`FmtOr::fmt_or`]: crate::FmtOr::fmt_or
+#[allow(clippy::needless_lifetimes)] // They're nice to see in docs
+pub trait FmtOrInt<T: Integer>: sealed::Sealed {
+    /// Format the value followed by a unit, or display the given value instead.
+    ///
+    /// The `singular` unit is used when the magnitude of the value is exactly one, and `plural`
+    /// is used otherwise, including for zero. This matches English, where `-1 degree` and
+    /// `0 degrees` are both correct.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrInt;
+    ///
+    /// assert_eq!("1 byte", format!("{}", Some(1).fmt_unit_plural_or("byte", "bytes", "?")));
+    /// assert_eq!("2 bytes", format!("{}", Some(2).fmt_unit_plural_or("byte", "bytes", "?")));
+    /// assert_eq!("?", format!("{}", None::<u8>.fmt_unit_plural_or("byte", "bytes", "?")));
+    /// ```
+    fn fmt_unit_plural_or<'t, U>(
+        &'t self,
+        singular: &'t str,
+        plural: &'t str,
+        fallback: U,
+    ) -> MaybeFormatUnitPlural<'t, T, U>
+    where
+        U: Display;
+    /// Format the digits of the value in groups counted from the left, or display the given value instead.
+    ///
+    /// `groups` gives the size of each group from left to right, and the last size repeats for
+    /// any remaining digits, so `&[3]` groups every three digits while `&[3, 3, 4]` gives the
+    /// common `555-123-4567` phone number layout. A sign is written before the first group.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrInt;
+    ///
+    /// let phone = Some(5551234567u64);
+    ///
+    /// assert_eq!("555-123-4567", format!("{}", phone.fmt_group_left_or(&[3, 3, 4], "-", "")));
+    /// assert_eq!("555 123 456 7", format!("{}", phone.fmt_group_left_or(&[3], " ", "")));
+    /// ```
+    fn fmt_group_left_or<'t, U>(
+        &'t self,
+        groups: &'t [usize],
+        sep: &'t str,
+        fallback: U,
+    ) -> MaybeFormatGroupLeft<'t, T, U>
+    where
+        U: Display;
+    /// Format the value as a count of seconds on a `M:SS` clock, or display the given value instead.
+    ///
+    /// Negative values are shown with a leading `-`, which is useful for countdowns that can run
+    /// overdue. Minutes are not wrapped into hours.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrInt;
+    ///
+    /// assert_eq!("1:23", format!("{}", Some(83).fmt_signed_clock_or("--:--")));
+    /// assert_eq!("-1:23", format!("{}", Some(-83).fmt_signed_clock_or("--:--")));
+    /// assert_eq!("--:--", format!("{}", None::<i64>.fmt_signed_clock_or("--:--")));
+    /// ```
+    fn fmt_signed_clock_or<'t, U>(&'t self, fallback: U) -> MaybeFormatSignedClock<'t, T, U>
+    where
+        U: Display;
+    /// Format the value in the given radix after a literal prefix, or display the given value instead.
+    ///
+    /// Digits above 9 are lowercase letters. Negative values are written as `-` followed by the
+    /// prefix and the magnitude, so `-255` in radix 16 with prefix `hex:` is `-hex:ff`. The prefix
+    /// is written as given; the `#` flag does not add a `0x`-style prefix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range `2..=36`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrInt;
+    ///
+    /// assert_eq!("bin:1010", format!("{}", Some(10).fmt_prefixed_radix_or("bin:", 2, "-")));
+    /// assert_eq!("hex:ff", format!("{}", Some(255u8).fmt_prefixed_radix_or("hex:", 16, "-")));
+    /// ```
+    fn fmt_prefixed_radix_or<'t, U>(
+        &'t self,
+        prefix: &'t str,
+        radix: u32,
+        fallback: U,
+    ) -> MaybeFormatPrefixedRadix<'t, T, U>
+    where
+        U: Display;
+    /// Format the value as the names of its set flags joined by `|`, or display the given value instead.
+    ///
+    /// `names` pairs each flag's mask with its name. A name is written when all bits of its mask
+    /// are set, in the order given. Zero is shown as `NONE`, and any set bits not covered by a
+    /// named mask are shown last in hex, like `READ|0x10`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrInt;
+    ///
+    /// const NAMES: &[(u8, &str)] = &[(1, "READ"), (2, "WRITE"), (4, "EXEC")];
+    ///
+    /// assert_eq!("READ|WRITE", format!("{}", Some(3).fmt_flags_pipe_or(NAMES, "?")));
+    /// assert_eq!("NONE", format!("{}", Some(0).fmt_flags_pipe_or(NAMES, "?")));
+    /// ```
+    fn fmt_flags_pipe_or<'t, U>(
+        &'t self,
+        names: &'t [(T, &'t str)],
+        fallback: U,
+    ) -> MaybeFormatFlagsPipe<'t, T, U>
+    where
+        U: Display;
+    /// Format the value in decimal followed by hex in parentheses, or display the given value instead.
+    ///
+    /// The hex form of a negative value shows its two's complement bits, the same as `{:x}` does
+    /// for signed integers, so `-1i8` is shown as `-1 (0xff)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrInt;
+    ///
+    /// assert_eq!("42 (0x2a)", format!("{}", Some(42).fmt_dec_hex_or("-")));
+    /// assert_eq!("-1 (0xff)", format!("{}", Some(-1i8).fmt_dec_hex_or("-")));
+    /// ```
+    fn fmt_dec_hex_or<'t, U>(&'t self, fallback: U) -> MaybeFormatDecHex<'t, T, U>
+    where
+        U: Display;
+    /// Format the value using Unicode superscript digits, such as `¹²³`, or display the given
+    /// value instead.
+    ///
+    /// Negative values are prefixed with a superscript minus, `⁻`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrInt;
+    ///
+    /// let exponent = Some(23u32);
+    ///
+    /// assert_eq!("10²³", format!("10{}", exponent.fmt_superscript_or("")));
+    /// assert_eq!("x⁻¹", format!("x{}", Some(-1).fmt_superscript_or("")));
+    /// ```
+    fn fmt_superscript_or<'t, U>(&'t self, fallback: U) -> MaybeFormatSuperscript<'t, T, U>
+    where
+        U: Display;
+    /// Format the value as hex zero-padded to the full width of its type, or display the given
+    /// value instead.
+    ///
+    /// A `u8` always shows 2 digits, a `u16` 4, a `u32` 8 and so on. Negative values are shown in
+    /// two's complement. The alternate flag, as in `{:#}`, adds a `0x` prefix.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrInt;
+    ///
+    /// assert_eq!("0005", format!("{}", Some(0x5u16).fmt_hex_native_or("-")));
+    /// assert_eq!("0x00000005", format!("{:#}", Some(0x5u32).fmt_hex_native_or("-")));
+    /// ```
+    fn fmt_hex_native_or<'t, U>(&'t self, fallback: U) -> MaybeFormatHexNative<'t, T, U>
+    where
+        U: Display;
+    /// Format the value in the given [`Style`], or display the given value instead.
+    ///
+    /// Each style uses the matching adapter: [`Style::Grouped`] is
+    /// [`fmt_money_spec_or`](FmtOrNum::fmt_money_spec_or) without decimals or a sign column,
+    /// [`Style::Hex`] is [`fmt_prefixed_radix_or`](FmtOrInt::fmt_prefixed_radix_or) with `0x`
+    /// and [`Style::Compact`] is [`fmt_compact_or`](FmtOrNum::fmt_compact_or) with one decimal.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::{FmtOrInt, Style};
+    ///
+    /// let bytes = Some(1_234_567);
+    ///
+    /// assert_eq!("1,234,567", format!("{}", bytes.fmt_styled_or(Style::Grouped, "-")));
+    /// assert_eq!("1.2M", format!("{}", bytes.fmt_styled_or(Style::Compact, "-")));
+    /// ```
+    fn fmt_styled_or<'t, U>(&'t self, style: Style, fallback: U) -> MaybeFormatStyled<'t, T, U>
+    where
+        U: Display;
+    /// Format the value as a fixed width key which sorts as text in the same order as the values,
+    /// with missing values sorting first.
+    ///
+    /// A present value is written as `1` followed by its distance from the type's minimum, zero
+    /// padded to the number of digits of the largest distance, so that negative values sort before
+    /// positive ones. A missing value is written as `0` followed by the fallback, zero padded on the
+    /// left to the same number of digits, so it sorts before every present value. An empty fallback
+    /// gives an all-zeros key. Keys for one integer type all have the same length, as long as the
+    /// fallback is no longer than the digits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrInt;
+    ///
+    /// assert_eq!("1042", format!("{}", Some(42u8).fmt_sortkey_or("")));
+    /// assert_eq!("1000", format!("{}", Some(-128i8).fmt_sortkey_or("")));
+    /// assert_eq!("1255", format!("{}", Some(127i8).fmt_sortkey_or("")));
+    /// assert_eq!("0000", format!("{}", None::<i8>.fmt_sortkey_or("")));
+    /// ```
+    fn fmt_sortkey_or<'t, U>(&'t self, fallback: U) -> MaybeFormatSortKey<'t, T, U>
+    where
+        U: Display;
+}
+
+impl<T: Integer> FmtOrInt<T> for Option<T> {
+    #[inline]
+    fn fmt_unit_plural_or<'t, U>(
+        &'t self,
+        singular: &'t str,
+        plural: &'t str,
+        fallback: U,
+    ) -> MaybeFormatUnitPlural<'t, T, U>
+    where
+        U: Display,
+    {
+        MaybeFormatUnitPlural(self, singular, plural, fallback)
+    }
+    #[inline]
+    fn fmt_group_left_or<'t, U>(
+        &'t self,
+        groups: &'t [usize],
+        sep: &'t str,
+        fallback: U,
+    ) -> MaybeFormatGroupLeft<'t, T, U>
+    where
+        U: Display,
+    {
+        MaybeFormatGroupLeft(self, groups, sep, fallback)
+    }
+    #[inline]
+    fn fmt_signed_clock_or<U>(&self, fallback: U) -> MaybeFormatSignedClock<'_, T, U>
+    where
+        U: Display,
+    {
+        MaybeFormatSignedClock(self, fallback)
+    }
+    #[inline]
+    fn fmt_prefixed_radix_or<'t, U>(
+        &'t self,
+        prefix: &'t str,
+        radix: u32,
+        fallback: U,
+    ) -> MaybeFormatPrefixedRadix<'t, T, U>
+    where
+        U: Display,
+    {
+        assert!(
+            (2..=36).contains(&radix),
+            "radix must be in the range 2..=36, got {}",
+            radix
+        );
+        MaybeFormatPrefixedRadix(self, prefix, radix, fallback)
+    }
+    #[inline]
+    fn fmt_flags_pipe_or<'t, U>(
+        &'t self,
+        names: &'t [(T, &'t str)],
+        fallback: U,
+    ) -> MaybeFormatFlagsPipe<'t, T, U>
+    where
+        U: Display,
+    {
+        MaybeFormatFlagsPipe(self, names, fallback)
+    }
+    #[inline]
+    fn fmt_dec_hex_or<U>(&self, fallback: U) -> MaybeFormatDecHex<'_, T, U>
+    where
+        U: Display,
+    {
+        MaybeFormatDecHex(self, fallback)
+    }
+    #[inline]
+    fn fmt_superscript_or<U>(&self, fallback: U) -> MaybeFormatSuperscript<'_, T, U>
+    where
+        U: Display,
+    {
+        MaybeFormatSuperscript(self, fallback)
+    }
+    #[inline]
+    fn fmt_hex_native_or<U>(&self, fallback: U) -> MaybeFormatHexNative<'_, T, U>
+    where
+        U: Display,
+    {
+        MaybeFormatHexNative(self, fallback)
+    }
+    #[inline]
+    fn fmt_styled_or<U>(&self, style: Style, fallback: U) -> MaybeFormatStyled<'_, T, U>
+    where
+        U: Display,
+    {
+        MaybeFormatStyled(self, style, fallback)
+    }
+    #[inline]
+    fn fmt_sortkey_or<U>(&self, fallback: U) -> MaybeFormatSortKey<'_, T, U>
+    where
+        U: Display,
+    {
+        MaybeFormatSortKey(self, fallback)
+    }
+}
+
+impl<'t, T, U> Display for MaybeFormatUnitPlural<'t, T, U>
+where
+    T: Integer,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) => {
+                let unit = if t.unsigned_abs() == 1 {
+                    self.1
+                } else {
+                    self.2
+                };
+                pad(out, Alignment::Left, |w: &mut dyn Write| {
+                    write!(w, "{} {}", t, unit)
+                })
+            }
+            None => Display::fmt(&self.3, out),
+        }
+    }
+}
+
+impl<'t, T, U> Display for MaybeFormatMoney<'t, T, U>
+where
+    T: Number,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        let spec = self.1;
+        match self.0 {
+            Some(t) => pad(out, Alignment::Right, |w: &mut dyn Write| {
+                if t.is_negative() {
+                    w.write_char('-')?;
+                } else if spec.sign_column {
+                    w.write_char(' ')?;
+                }
+                write_grouped_abs(w, *t, spec.decimals, spec.group_sep)
+            }),
+            None => Display::fmt(&self.2, out),
+        }
+    }
+}
+
+/// Starts a left-to-right isolate.
+const LRI: char = '\u{2066}';
+/// Ends the most recent directional isolate.
+const PDI: char = '\u{2069}';
+
+impl<'t, T, U> Display for MaybeFormatLtrNumber<'t, T, U>
+where
+    T: Number,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) => {
+                out.write_char(LRI)?;
+                Display::fmt(t, out)?;
+                out.write_char(PDI)
+            }
+            None => Display::fmt(&self.1, out),
+        }
+    }
+}
+
+impl<'t, T, U> Display for MaybeFormatGroupLeft<'t, T, U>
+where
+    T: Integer,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) => pad(out, Alignment::Right, |w: &mut dyn Write| {
+                if t.is_negative() {
+                    w.write_char('-')?;
+                }
+                write!(GroupLeft::new(w, self.1, self.2), "{}", t.unsigned_abs())
+            }),
+            None => Display::fmt(&self.3, out),
+        }
+    }
+}
+
+impl<'t, T, U> Display for MaybeFormatThresholdColor<'t, T, U>
+where
+    T: Number,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) if self.3 => {
+                let color = if *t >= self.2 {
+                    ansi::RED
+                } else if *t >= self.1 {
+                    ansi::YELLOW
+                } else {
+                    ansi::GREEN
+                };
+                out.write_str(color)?;
+                Display::fmt(t, out)?;
+                out.write_str(ansi::RESET)
+            }
+            Some(t) => Display::fmt(t, out),
+            None => Display::fmt(&self.4, out),
+        }
+    }
+}
+
+impl<'t, T, U> Display for MaybeFormatSignedClock<'t, T, U>
+where
+    T: Integer,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) => pad(out, Alignment::Right, |w: &mut dyn Write| {
+                let secs = t.unsigned_abs();
+                let sign = if t.is_negative() { "-" } else { "" };
+                write!(w, "{}{}:{:02}", sign, secs / 60, secs % 60)
+            }),
+            None => Display::fmt(&self.1, out),
+        }
+    }
+}
+
+impl<'t, T, U> Display for MaybeFormatEpsilon<'t, T, U>
+where
+    T: Float,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) if t.abs() < self.1 => Display::fmt(&T::ZERO, out),
+            Some(t) => Display::fmt(t, out),
+            None => Display::fmt(&self.2, out),
+        }
+    }
+}
+
+/// Writes `n` in the given radix, which must be in the range `2..=36`.
+fn write_radix(out: &mut dyn Write, mut n: u128, radix: u32) -> Result {
+    // Enough digits for u128::MAX in radix 2.
+    let mut buf = [0u8; 128];
+    let mut start = buf.len();
+    loop {
+        start -= 1;
+        let digit = (n % radix as u128) as u32;
+        buf[start] = core::char::from_digit(digit, radix).map_or(b'?', |c| c as u8);
+        n /= radix as u128;
+        if n == 0 {
+            break;
+        }
+    }
+    for &b in &buf[start..] {
+        out.write_char(b as char)?;
+    }
+    Ok(())
+}
+
+impl<'t, T, U> Display for MaybeFormatPrefixedRadix<'t, T, U>
+where
+    T: Integer,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) => pad(out, Alignment::Right, |w: &mut dyn Write| {
+                if t.is_negative() {
+                    w.write_char('-')?;
+                }
+                w.write_str(self.1)?;
+                write_radix(w, t.unsigned_abs(), self.2)
+            }),
+            None => Display::fmt(&self.3, out),
+        }
+    }
+}
+
+impl<'t, T, U> Display for MaybeFormatFlagsPipe<'t, T, U>
+where
+    T: Integer,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        let bits = match self.0 {
+            Some(t) => t.to_bits(),
+            None => return Display::fmt(&self.2, out),
+        };
+        pad(out, Alignment::Left, |w: &mut dyn Write| {
+            if bits == 0 {
+                return w.write_str("NONE");
+            }
+            let mut unnamed = bits;
+            let mut first = true;
+            for (mask, name) in self.1 {
+                let mask = mask.to_bits();
+                if mask != 0 && bits & mask == mask {
+                    if !first {
+                        w.write_char('|')?;
+                    }
+                    first = false;
+                    w.write_str(name)?;
+                    unnamed &= !mask;
+                }
+            }
+            if unnamed != 0 {
+                if !first {
+                    w.write_char('|')?;
+                }
+                write!(w, "{:#x}", unnamed)?;
+            }
+            Ok(())
+        })
+    }
+}
+
+impl<'t, T> Display for MaybeFormatJsonNumber<'t, T>
+where
+    T: Number,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) if t.is_finite() => write!(out, "{}", t),
+            _ => out.write_str("null"),
+        }
+    }
+}
+
+impl<'t, T, U> Display for MaybeFormatDecHex<'t, T, U>
+where
+    T: Integer,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) => pad(out, Alignment::Right, |w: &mut dyn Write| {
+                write!(w, "{} ({:#x})", t, t.to_bits())
+            }),
+            None => Display::fmt(&self.1, out),
+        }
+    }
+}
+
+/// An extension trait for [`Option<(T, T)>`] holding a value and its margin of error, where `T`
+/// is a primitive [`Float`].
+///
+/// Unless noted otherwise, the wrappers returned from these methods implement [`Display`] only.
+/// Like [`FmtOr::fmt_or`], the fallback is formatted with the full format spec, while both ends of
+/// a present interval are rendered as a single piece of text which is then padded.
+///
+/// [`FmtOr::fmt_or`]: crate::FmtOr::fmt_or
+#[allow(clippy::needless_lifetimes)] // They're nice to see in docs
+pub trait FmtOrInterval<T: Float>: sealed::Sealed {
+    /// Format the value and margin as `value ± margin`, or display the given value instead.
+    ///
+    /// Both numbers are shown with `decimals` digits after the decimal point. The margin is
+    /// always shown as positive.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrInterval;
+    ///
+    /// let estimate = Some((12.345, 0.27));
+    ///
+    /// assert_eq!("12.3 ± 0.3", format!("{}", estimate.fmt_ci_or(1, "n/a")));
+    /// assert_eq!("n/a", format!("{}", None::<(f64, f64)>.fmt_ci_or(1, "n/a")));
+    /// ```
+    fn fmt_ci_or<'t, U>(&'t self, decimals: usize, fallback: U) -> MaybeFormatCi<'t, T, U>
+    where
+        U: Display;
+}
+
+impl<T: Float> FmtOrInterval<T> for Option<(T, T)> {
+    #[inline]
+    fn fmt_ci_or<U>(&self, decimals: usize, fallback: U) -> MaybeFormatCi<'_, T, U>
+    where
+        U: Display,
+    {
+        MaybeFormatCi(self, decimals, fallback)
+    }
+}
+
+impl<'t, T, U> Display for MaybeFormatCi<'t, T, U>
+where
+    T: Float,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some((value, margin)) => pad(out, Alignment::Left, |w: &mut dyn Write| {
+                write!(
+                    w,
+                    "{:.prec$} ± {:.prec$}",
+                    value,
+                    margin.abs(),
+                    prec = self.1
+                )
+            }),
+            None => Display::fmt(&self.2, out),
+        }
+    }
+}
+
+impl<'t, T, U> Display for MaybeFormatPm<'t, T, U>
+where
+    T: Number,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        let value = match self.0 {
+            Some(value) => value,
+            None => return Display::fmt(&self.3, out),
+        };
+        let sign = if self.2 { '∓' } else { '±' };
+        let precision = out.precision();
+        pad(out, Alignment::Left, |w: &mut dyn Write| match precision {
+            Some(prec) => write!(
+                w,
+                "{:.prec$} {} {:.prec$}",
+                value,
+                sign,
+                self.1,
+                prec = prec
+            ),
+            None => write!(w, "{} {} {}", value, sign, self.1),
+        })
+    }
+}
+
+impl<'t, T, U> Display for MaybeFormatCompact<'t, T, U>
+where
+    T: Number,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        const SUFFIXES: [char; 4] = ['K', 'M', 'B', 'T'];
+        let value = match self.0 {
+            Some(value) => *value,
+            None => return Display::fmt(&self.2, out),
+        };
+        let decimals = self.1;
+        let magnitude = value.to_f64();
+        let magnitude = if magnitude < 0.0 {
+            -magnitude
+        } else {
+            magnitude
+        };
+        if !value.is_finite() || magnitude < 1000.0 {
+            return pad(out, Alignment::Left, |w: &mut dyn Write| {
+                write!(w, "{}", value)
+            });
+        }
+        let mut scaled = magnitude;
+        let mut suffix = 0;
+        while suffix < SUFFIXES.len() {
+            scaled /= 1000.0;
+            // Step up again if rounding would show four integer digits, as in `1000.0K`.
+            let mut digits = IntDigits(0, false);
+            write!(digits, "{:.*}", decimals, scaled)?;
+            if digits.0 <= 3 || suffix + 1 == SUFFIXES.len() {
+                break;
+            }
+            suffix += 1;
+        }
+        pad(out, Alignment::Left, |w: &mut dyn Write| {
+            if value.is_negative() {
+                w.write_char('-')?;
+            }
+            write!(w, "{:.*}{}", decimals, scaled, SUFFIXES[suffix])
+        })
+    }
+}
+
+/// Passes writes through, replacing ASCII digits and `-` with their superscript forms.
+struct Superscript<'w>(&'w mut dyn Write);
+
+impl Write for Superscript<'_> {
+    fn write_str(&mut self, s: &str) -> Result {
+        const DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+        for c in s.chars() {
+            let c = match c {
+                '0'..='9' => DIGITS[c as usize - '0' as usize],
+                '-' => '⁻',
+                c => c,
+            };
+            self.0.write_char(c)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'t, T, U> Display for MaybeFormatSuperscript<'t, T, U>
+where
+    T: Integer,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) => pad(out, Alignment::Left, |w: &mut dyn Write| {
+                write!(Superscript(w), "{}", t)
+            }),
+            None => Display::fmt(&self.1, out),
+        }
+    }
+}
+
+impl<'t, T, U> Display for MaybeFormatBearing<'t, T, U>
+where
+    T: Float,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        let value = match self.0 {
+            Some(value) => value.to_f64(),
+            None => return Display::fmt(&self.2, out),
+        };
+        let decimals = self.1;
+        let mut bearing = value % 360.0;
+        if bearing < 0.0 {
+            bearing += 360.0;
+        }
+        // Anything which rounds to 360 at this precision is due north. Adding zero also turns
+        // a negative zero positive.
+        let mut half_step = 0.5;
+        for _ in 0..decimals {
+            half_step /= 10.0;
+        }
+        if bearing >= 360.0 - half_step {
+            bearing = 0.0;
+        }
+        bearing += 0.0;
+        pad(out, Alignment::Left, |w: &mut dyn Write| {
+            write!(w, "{:.*}°", decimals, bearing)
+        })
+    }
+}
+
+impl<'t, T, U> Display for MaybeFormatClampIndicator<'t, T, U>
+where
+    T: Number,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        let value = match self.0 {
+            Some(value) => *value,
+            None => return Display::fmt(&self.3, out),
+        };
+        let (indicator, shown) = if value > self.2 {
+            (">", self.2)
+        } else if value < self.1 {
+            ("<", self.1)
+        } else {
+            ("", value)
+        };
+        let precision = out.precision();
+        pad(out, Alignment::Left, |w: &mut dyn Write| match precision {
+            Some(prec) => write!(w, "{}{:.prec$}", indicator, shown, prec = prec),
+            None => write!(w, "{}{}", indicator, shown),
+        })
+    }
+}
+
+impl<'t, T, U> Display for MaybeFormatHexNative<'t, T, U>
+where
+    T: Integer,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        let value = match self.0 {
+            Some(value) => value,
+            None => return Display::fmt(&self.1, out),
+        };
+        let prefix = if out.alternate() { "0x" } else { "" };
+        let digits = T::BITS as usize / 4;
+        pad(out, Alignment::Left, |w: &mut dyn Write| {
+            write!(
+                w,
+                "{}{:0digits$x}",
+                prefix,
+                value.to_bits(),
+                digits = digits
+            )
+        })
+    }
+}
+
+impl<'t, T, U> Display for MaybeFormatTrend<'t, T, U>
+where
+    T: Number,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        use core::cmp::Ordering;
+
+        let value = match self.0 {
+            Some(value) => *value,
+            None => return Display::fmt(&self.2, out),
+        };
+        let prev = self.1;
+        pad(out, Alignment::Left, |w: &mut dyn Write| {
+            write!(w, "{} (", value)?;
+            match value.partial_cmp(&prev) {
+                Some(Ordering::Greater) => {
+                    w.write_char('↑')?;
+                    value.write_abs_diff(prev, w)?;
+                }
+                Some(Ordering::Less) => {
+                    w.write_char('↓')?;
+                    value.write_abs_diff(prev, w)?;
+                }
+                Some(Ordering::Equal) => w.write_char('=')?,
+                None => w.write_char('?')?,
+            }
+            w.write_char(')')
+        })
+    }
+}
+
+impl<'t, T, U> MaybeFormatAlert<'t, T, U> {
+    /// Sets whether the bell is written for critical values. It is on by default.
+    #[inline]
+    pub fn bell(self, enabled: bool) -> Self {
+        Self(self.0, self.1, enabled, self.3)
+    }
+}
+
+impl<'t, T, U> Display for MaybeFormatAlert<'t, T, U>
+where
+    T: Number,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(value) => {
+                if self.2 && *value >= self.1 {
+                    out.write_char(ansi::BEL)?;
+                }
+                Display::fmt(value, out)
+            }
+            None => Display::fmt(&self.3, out),
+        }
+    }
+}
+
+/// Every float at least this large is a whole number, and some of them don't fit in a `u64`.
+const WHOLE_FLOATS: f64 = 9_007_199_254_740_992.0;
+
+/// Finds the fraction `num/den` nearest to `frac`, which is in `[0, 1)`, with `den <= max_denom`.
+///
+/// This walks the continued fraction of `frac` down the Stern–Brocot tree, so it takes a few
+/// dozen steps at most whatever `max_denom` is. Convergents are always in lowest terms.
+fn nearest_fraction(frac: f64, max_denom: u32) -> (u64, u64) {
+    let max_denom = u64::from(max_denom.max(1));
+    let (mut p0, mut q0, mut p1, mut q1) = (0u64, 1u64, 1u64, 0u64);
+    let mut rest = frac;
+    loop {
+        // `rest` is never negative, so truncating is flooring. Huge terms saturate, which still
+        // ends the walk below.
+        let term = rest as u64;
+        let q2 = q0.saturating_add(term.saturating_mul(q1));
+        if q2 > max_denom {
+            break;
+        }
+        let p2 = p0.saturating_add(term.saturating_mul(p1));
+        p0 = p1;
+        q0 = q1;
+        p1 = p2;
+        q1 = q2;
+        rest -= term as f64;
+        if rest <= 0.0 {
+            return (p1, q1);
+        }
+        rest = 1.0 / rest;
+    }
+    // The best approximation is either the last convergent or the semiconvergent with the
+    // largest denominator that still fits.
+    let steps = (max_denom - q0) / q1;
+    let semi = (p0 + steps * p1, q0 + steps * q1);
+    let error = |(num, den): (u64, u64)| {
+        let err = frac - num as f64 / den as f64;
+        if err < 0.0 {
+            -err
+        } else {
+            err
+        }
+    };
+    if error((p1, q1)) <= error(semi) {
+        (p1, q1)
+    } else {
+        semi
+    }
+}
+
+impl<'t, T, U> Display for MaybeFormatFraction<'t, T, U>
+where
+    T: Float,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        let value = match self.0 {
+            Some(value) => *value,
+            None => return Display::fmt(&self.2, out),
+        };
+        let magnitude = value.abs().to_f64();
+        if !value.is_finite() || magnitude >= WHOLE_FLOATS {
+            return pad(out, Alignment::Left, |w: &mut dyn Write| {
+                write!(w, "{}", value)
+            });
+        }
+        let mut whole = magnitude as u64;
+        let (mut num, den) = nearest_fraction(magnitude - whole as f64, self.1);
+        if num == den {
+            whole += 1;
+            num = 0;
+        }
+        let negative = value.is_negative() && (whole > 0 || num > 0);
+        pad(out, Alignment::Left, |w: &mut dyn Write| {
+            if negative {
+                w.write_char('-')?;
+            }
+            match (whole, num) {
+                (whole, 0) => write!(w, "{}", whole),
+                (0, num) => write!(w, "{}/{}", num, den),
+                (whole, num) => write!(w, "{} {}/{}", whole, num, den),
+            }
+        })
+    }
+}
+
+impl<'t, T, U> Display for MaybeFormatGauge<'t, T, U>
+where
+    T: Number,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        let (min, max, width) = (self.1, self.2, self.3);
+        let value = match self.0 {
+            Some(value) if width > 0 && min <= *value && *value <= max => value.to_f64(),
+            _ => return Display::fmt(&self.4, out),
+        };
+        let (min, max) = (min.to_f64(), max.to_f64());
+        let fraction = if max > min {
+            (value - min) / (max - min)
+        } else {
+            0.0
+        };
+        let needle = (fraction * (width - 1) as f64 + 0.5) as usize;
+        pad(out, Alignment::Left, |w: &mut dyn Write| {
+            w.write_char('[')?;
+            for i in 0..width {
+                w.write_char(if i == needle { '|' } else { '-' })?;
+            }
+            w.write_char(']')
+        })
+    }
+}
+
+impl<'t, T, U> Display for MaybeFormatStyled<'t, T, U>
+where
+    T: Integer,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        let (opt, fallback) = (self.0, &self.2);
+        match self.1 {
+            Style::Plain => Display::fmt(&opt.fmt_or(fallback), out),
+            Style::Grouped => {
+                let spec = MoneySpec {
+                    group_sep: Some(','),
+                    decimals: 0,
+                    sign_column: false,
+                };
+                Display::fmt(&opt.fmt_money_spec_or(spec, fallback), out)
+            }
+            Style::Hex => Display::fmt(&opt.fmt_prefixed_radix_or("0x", 16, fallback), out),
+            Style::Compact => Display::fmt(&opt.fmt_compact_or(1, fallback), out),
+            Style::Percent => match opt {
+                Some(t) => pad(out, Alignment::Left, |w: &mut dyn Write| {
+                    write!(w, "{}%", t)
+                }),
+                None => Display::fmt(fallback, out),
+            },
+        }
+    }
+}
+
+impl<'t, T, U> Display for MaybeFormatPctChange<'t, T, U>
+where
+    T: Number,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        let value = match self.0 {
+            Some(value) => value.to_f64(),
+            None => return Display::fmt(&self.3, out),
+        };
+        let prev = self.1.to_f64();
+        let decimals = self.2;
+        let delta = value - prev;
+        pad(out, Alignment::Left, |w: &mut dyn Write| {
+            if prev == 0.0 && delta != 0.0 {
+                return w.write_str(if delta > 0.0 { "+∞" } else { "-∞" });
+            }
+            let change = if delta == 0.0 {
+                0.0
+            } else {
+                delta / prev.abs() * 100.0
+            };
+            if change > 0.0 {
+                w.write_char('+')?;
+            }
+            write!(w, "{:.*}%", decimals, change)
+        })
+    }
+}
+
+impl<'t, T, U> Display for MaybeFormatSortKey<'t, T, U>
+where
+    T: Integer,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        let mut digits = IntDigits(0, false);
+        write!(digits, "{}", u128::MAX >> (128 - T::BITS))?;
+        let digits = digits.0;
+        pad(out, Alignment::Left, |w: &mut dyn Write| match self.0 {
+            Some(value) => write!(w, "1{:0digits$}", value.to_sort_key(), digits = digits),
+            None => write!(w, "0{:0>digits$}", self.1, digits = digits),
+        })
+    }
+}
@@ -0,0 +1,546 @@
+use crate::{FmtOrFloat, FmtOrInt, FmtOrInterval, FmtOrNum, MoneySpec, Style};
+
+#[test]
+fn test_unit_plural() {
+    let fmt = |opt: Option<i32>| format!("{}", opt.fmt_unit_plural_or("byte", "bytes", "n/a"));
+    assert_eq!("0 bytes", fmt(Some(0)));
+    assert_eq!("1 byte", fmt(Some(1)));
+    assert_eq!("2 bytes", fmt(Some(2)));
+    assert_eq!("-1 byte", fmt(Some(-1)));
+    assert_eq!("-2 bytes", fmt(Some(-2)));
+    assert_eq!("n/a", fmt(None));
+}
+
+#[test]
+fn test_unit_plural_width() {
+    let some = Some(1u8);
+    let none = None::<u8>;
+    assert_eq!(
+        "1 byte    ",
+        format!("{:10}", some.fmt_unit_plural_or("byte", "bytes", "n/a"))
+    );
+    assert_eq!(
+        "    1 byte",
+        format!("{:>10}", some.fmt_unit_plural_or("byte", "bytes", "n/a"))
+    );
+    assert_eq!(
+        "       n/a",
+        format!("{:>10}", none.fmt_unit_plural_or("byte", "bytes", "n/a"))
+    );
+}
+
+#[test]
+fn test_money_spec() {
+    let spec = MoneySpec::default();
+    let fmt = |opt: Option<f64>| format!("{}", opt.fmt_money_spec_or(spec, "n/a"));
+    assert_eq!(" 1,234,567.89", fmt(Some(1234567.891)));
+    assert_eq!("-1,234,567.89", fmt(Some(-1234567.891)));
+    assert_eq!(" 0.50", fmt(Some(0.5)));
+    assert_eq!("-999.00", fmt(Some(-999.0)));
+    assert_eq!("n/a", fmt(None));
+
+    let spec = MoneySpec {
+        group_sep: Some('_'),
+        decimals: 0,
+        sign_column: false,
+    };
+    assert_eq!(
+        "1_000_000",
+        format!("{}", Some(1_000_000u32).fmt_money_spec_or(spec, "n/a"))
+    );
+    assert_eq!(
+        "-1_000",
+        format!("{}", Some(-1000i64).fmt_money_spec_or(spec, "n/a"))
+    );
+}
+
+#[test]
+fn test_money_spec_column() {
+    let spec = MoneySpec {
+        group_sep: None,
+        ..MoneySpec::default()
+    };
+    let a = Some(12.5);
+    let b = Some(-1200.0);
+    let c = None::<f64>;
+    assert_eq!("    12.50", format!("{:9}", a.fmt_money_spec_or(spec, "-")));
+    assert_eq!(" -1200.00", format!("{:9}", b.fmt_money_spec_or(spec, "-")));
+    assert_eq!(
+        "        -",
+        format!("{:>9}", c.fmt_money_spec_or(spec, "-"))
+    );
+}
+
+#[test]
+fn test_ltr_number() {
+    assert_eq!(
+        "\u{2066}42\u{2069}",
+        format!("{}", Some(42).fmt_ltr_number_or("-"))
+    );
+    assert_eq!(
+        "\u{2066}  -7\u{2069}",
+        format!("{:4}", Some(-7).fmt_ltr_number_or("-"))
+    );
+    assert_eq!("-", format!("{}", None::<f32>.fmt_ltr_number_or("-")));
+}
+
+#[test]
+fn test_group_left() {
+    let phone = Some(5551234567u64);
+    assert_eq!(
+        "555-123-4567",
+        format!("{}", phone.fmt_group_left_or(&[3, 3, 4], "-", "n/a"))
+    );
+    assert_eq!(
+        "555.123.456.7",
+        format!("{}", phone.fmt_group_left_or(&[3], ".", "n/a"))
+    );
+    assert_eq!(
+        "-12 34",
+        format!("{}", Some(-1234).fmt_group_left_or(&[2], " ", "n/a"))
+    );
+    assert_eq!(
+        "5551234567",
+        format!("{}", phone.fmt_group_left_or(&[], "-", "n/a"))
+    );
+    assert_eq!(
+        "n/a",
+        format!("{}", None::<u64>.fmt_group_left_or(&[3], "-", "n/a"))
+    );
+}
+
+#[test]
+fn test_threshold_color() {
+    let fmt = |opt: Option<u8>, enabled| {
+        format!("{}", opt.fmt_threshold_color_or(50, 80, enabled, "n/a"))
+    };
+    assert_eq!("\x1b[32m10\x1b[0m", fmt(Some(10), true));
+    assert_eq!("\x1b[33m50\x1b[0m", fmt(Some(50), true));
+    assert_eq!("\x1b[33m79\x1b[0m", fmt(Some(79), true));
+    assert_eq!("\x1b[31m80\x1b[0m", fmt(Some(80), true));
+    assert_eq!("\x1b[31m99\x1b[0m", fmt(Some(99), true));
+    assert_eq!("n/a", fmt(None, true));
+    assert_eq!("10", fmt(Some(10), false));
+    assert_eq!("99", fmt(Some(99), false));
+    assert_eq!("n/a", fmt(None, false));
+    assert_eq!(
+        "\x1b[32m   7\x1b[0m",
+        format!("{:4}", Some(7).fmt_threshold_color_or(50, 80, true, "n/a"))
+    );
+}
+
+#[test]
+fn test_signed_clock() {
+    let fmt = |opt: Option<i64>| format!("{}", opt.fmt_signed_clock_or("--:--"));
+    assert_eq!("1:23", fmt(Some(83)));
+    assert_eq!("-1:23", fmt(Some(-83)));
+    assert_eq!("0:00", fmt(Some(0)));
+    assert_eq!("-0:05", fmt(Some(-5)));
+    assert_eq!("125:00", fmt(Some(7500)));
+    assert_eq!("--:--", fmt(None));
+    assert_eq!(
+        " -1:23",
+        format!("{:6}", Some(-83).fmt_signed_clock_or("--:--"))
+    );
+}
+
+#[test]
+fn test_epsilon() {
+    let fmt = |opt: Option<f64>| format!("{}", opt.fmt_epsilon_or(1e-6, "n/a"));
+    assert_eq!("0", fmt(Some(1e-9)));
+    assert_eq!("0", fmt(Some(-1e-9)));
+    assert_eq!("0.5", fmt(Some(0.5)));
+    assert_eq!("-0.001", fmt(Some(-0.001)));
+    assert_eq!("n/a", fmt(None));
+    assert_eq!(
+        " 0.000",
+        format!("{:6.3}", Some(3e-7f32).fmt_epsilon_or(1e-6, "n/a"))
+    );
+}
+
+#[test]
+fn test_prefixed_radix() {
+    let fmt = |opt: Option<i32>, prefix, radix| {
+        format!("{}", opt.fmt_prefixed_radix_or(prefix, radix, "n/a"))
+    };
+    assert_eq!("bin:1010", fmt(Some(10), "bin:", 2));
+    assert_eq!("oct:17", fmt(Some(15), "oct:", 8));
+    assert_eq!("hex:ff", fmt(Some(255), "hex:", 16));
+    assert_eq!("-hex:ff", fmt(Some(-255), "hex:", 16));
+    assert_eq!("b36:z", fmt(Some(35), "b36:", 36));
+    assert_eq!("0", fmt(Some(0), "", 2));
+    assert_eq!("n/a", fmt(None, "hex:", 16));
+    assert_eq!(
+        format!("{:b}", u128::MAX),
+        format!("{}", Some(u128::MAX).fmt_prefixed_radix_or("", 2, ""))
+    );
+    assert_eq!(
+        "-b:10000000",
+        format!("{}", Some(i8::MIN).fmt_prefixed_radix_or("b:", 2, ""))
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_prefixed_radix_invalid() {
+    let _ = Some(1).fmt_prefixed_radix_or("", 37, "");
+}
+
+#[test]
+fn test_flags_pipe() {
+    const NAMES: &[(u32, &str)] = &[(0x1, "READ"), (0x2, "WRITE"), (0x4, "EXEC"), (0x3, "RW")];
+    let fmt = |opt: Option<u32>| format!("{}", opt.fmt_flags_pipe_or(NAMES, "n/a"));
+    assert_eq!("READ|EXEC", fmt(Some(0x5)));
+    assert_eq!("READ|WRITE|RW", fmt(Some(0x3)));
+    assert_eq!("NONE", fmt(Some(0)));
+    assert_eq!("WRITE|0x30", fmt(Some(0x32)));
+    assert_eq!("0x8", fmt(Some(0x8)));
+    assert_eq!("n/a", fmt(None));
+    assert_eq!(
+        "NEG",
+        format!(
+            "{}",
+            Some(-128i8).fmt_flags_pipe_or(&[(-128, "NEG")], "n/a")
+        )
+    );
+}
+
+#[test]
+fn test_json_value() {
+    assert_eq!("42", format!("{}", Some(42u8).fmt_json_value_or()));
+    assert_eq!("-7", format!("{}", Some(-7i64).fmt_json_value_or()));
+    assert_eq!("0.25", format!("{}", Some(0.25f32).fmt_json_value_or()));
+    assert_eq!("null", format!("{}", Some(f64::NAN).fmt_json_value_or()));
+    assert_eq!(
+        "null",
+        format!("{}", Some(f64::INFINITY).fmt_json_value_or())
+    );
+    assert_eq!("null", format!("{}", None::<u8>.fmt_json_value_or()));
+}
+
+#[test]
+fn test_dec_hex() {
+    assert_eq!(
+        "42 (0x2a)",
+        format!("{}", Some(42u32).fmt_dec_hex_or("n/a"))
+    );
+    assert_eq!("0 (0x0)", format!("{}", Some(0).fmt_dec_hex_or("n/a")));
+    assert_eq!(
+        "-2 (0xfffe)",
+        format!("{}", Some(-2i16).fmt_dec_hex_or("n/a"))
+    );
+    assert_eq!("n/a", format!("{}", None::<u8>.fmt_dec_hex_or("n/a")));
+    assert_eq!(
+        "  255 (0xff)",
+        format!("{:12}", Some(255u8).fmt_dec_hex_or("n/a"))
+    );
+}
+
+#[test]
+fn test_ci() {
+    let fmt = |opt: Option<(f64, f64)>| format!("{}", opt.fmt_ci_or(2, "n/a"));
+    assert_eq!("1.23 ± 0.05", fmt(Some((1.23456, 0.05))));
+    assert_eq!("-1.50 ± 0.25", fmt(Some((-1.5, -0.25))));
+    assert_eq!("n/a", fmt(None));
+    assert_eq!(
+        "  10 ± 1",
+        format!("{:>8}", Some((10.0f32, 1.0)).fmt_ci_or(0, "n/a"))
+    );
+}
+
+#[test]
+fn test_pm() {
+    let a = Some(1.5);
+    assert_eq!("1.5 ± 0.2", format!("{}", a.fmt_pm_or(0.2, false, "-")));
+    assert_eq!("1.5 ∓ 0.2", format!("{}", a.fmt_pm_or(0.2, true, "-")));
+    assert_eq!(
+        "1.500 ∓ 0.200",
+        format!("{:.3}", a.fmt_pm_or(0.2, true, "-"))
+    );
+    assert_eq!(
+        "  7 ± 1",
+        format!("{:>7}", Some(7).fmt_pm_or(1, false, "-"))
+    );
+    assert_eq!("-", format!("{}", None::<f64>.fmt_pm_or(0.2, true, "-")));
+}
+
+#[test]
+fn test_compact() {
+    let fmt = |opt: Option<f64>| format!("{}", opt.fmt_compact_or(1, "n/a"));
+    assert_eq!("999", fmt(Some(999.0)));
+    assert_eq!("1.5K", fmt(Some(1500.0)));
+    assert_eq!("2.3M", fmt(Some(2_300_000.0)));
+    assert_eq!("5.6B", fmt(Some(5_600_000_000.0)));
+    assert_eq!("-1.5K", fmt(Some(-1500.0)));
+    assert_eq!("1.0M", fmt(Some(999_999.0)));
+    assert_eq!("1200.0T", fmt(Some(1.2e15)));
+    assert_eq!("inf", fmt(Some(f64::INFINITY)));
+    assert_eq!("n/a", fmt(None));
+
+    assert_eq!("999", format!("{}", Some(999u16).fmt_compact_or(1, "n/a")));
+    assert_eq!(
+        "-12K",
+        format!("{}", Some(-12_345i32).fmt_compact_or(0, "n/a"))
+    );
+    assert_eq!(
+        "  2.3M",
+        format!("{:>6}", Some(2_300_000u64).fmt_compact_or(1, "n/a"))
+    );
+}
+
+#[test]
+fn test_superscript() {
+    let fmt = |opt: Option<u32>| format!("{}", opt.fmt_superscript_or("n/a"));
+    assert_eq!("¹²³⁴⁵⁶⁷⁸⁹⁰", fmt(Some(1234567890)));
+    assert_eq!("⁰", fmt(Some(0)));
+    assert_eq!("n/a", fmt(None));
+    assert_eq!("⁻⁴²", format!("{}", Some(-42i8).fmt_superscript_or("")));
+    assert_eq!(" ²", format!("{:>2}", Some(2u8).fmt_superscript_or("")));
+}
+
+#[test]
+fn test_bearing() {
+    let fmt = |opt: Option<f64>| format!("{}", opt.fmt_bearing_or(1, "n/a"));
+    assert_eq!("45.0°", fmt(Some(45.0)));
+    assert_eq!("10.5°", fmt(Some(370.5)));
+    assert_eq!("0.0°", fmt(Some(720.0)));
+    assert_eq!("270.0°", fmt(Some(-90.0)));
+    assert_eq!("350.0°", fmt(Some(-370.0)));
+    assert_eq!("0.0°", fmt(Some(-0.0)));
+    assert_eq!("0.0°", fmt(Some(359.97)));
+    assert_eq!("359.9°", fmt(Some(359.94)));
+    assert_eq!("NaN°", fmt(Some(f64::NAN)));
+    assert_eq!("n/a", fmt(None));
+    assert_eq!(
+        "  90°",
+        format!("{:>5}", Some(450f32).fmt_bearing_or(0, "n/a"))
+    );
+}
+
+#[test]
+fn test_clamp_indicator() {
+    let fmt = |opt: Option<i32>| format!("{}", opt.fmt_clamp_indicator_or(0, 100, "n/a"));
+    assert_eq!("<0", fmt(Some(-5)));
+    assert_eq!(">100", fmt(Some(101)));
+    assert_eq!("0", fmt(Some(0)));
+    assert_eq!("100", fmt(Some(100)));
+    assert_eq!("55", fmt(Some(55)));
+    assert_eq!("n/a", fmt(None));
+    assert_eq!(
+        " >1.00",
+        format!("{:>6.2}", Some(1.7).fmt_clamp_indicator_or(0.0, 1.0, "n/a"))
+    );
+}
+
+#[test]
+fn test_hex_native() {
+    assert_eq!("05", format!("{}", Some(0x5u8).fmt_hex_native_or("-")));
+    assert_eq!("0005", format!("{}", Some(0x5u16).fmt_hex_native_or("-")));
+    assert_eq!(
+        "00000005",
+        format!("{}", Some(0x5u32).fmt_hex_native_or("-"))
+    );
+    assert_eq!(
+        "0000000000000005",
+        format!("{}", Some(0x5u64).fmt_hex_native_or("-"))
+    );
+    assert_eq!(
+        "0000000000000000000000000000abcd",
+        format!("{}", Some(0xabcdu128).fmt_hex_native_or("-"))
+    );
+    assert_eq!("ffff", format!("{}", Some(-1i16).fmt_hex_native_or("-")));
+    assert_eq!(
+        "0x80",
+        format!("{:#}", Some(i8::MIN).fmt_hex_native_or("-"))
+    );
+    assert_eq!(
+        "    -",
+        format!("{:>5}", None::<u16>.fmt_hex_native_or("-"))
+    );
+}
+
+#[test]
+fn test_trend() {
+    let fmt = |opt: Option<i32>| format!("{}", opt.fmt_trend_or(10, "n/a"));
+    assert_eq!("12 (↑2)", fmt(Some(12)));
+    assert_eq!("9 (↓1)", fmt(Some(9)));
+    assert_eq!("10 (=)", fmt(Some(10)));
+    assert_eq!("n/a", fmt(None));
+    assert_eq!(
+        "127 (↑255)",
+        format!("{}", Some(127i8).fmt_trend_or(-128, "n/a"))
+    );
+    assert_eq!("0 (↓7)", format!("{}", Some(0u8).fmt_trend_or(7, "n/a")));
+    assert_eq!(
+        "1.5 (↓0.5)",
+        format!("{}", Some(1.5).fmt_trend_or(2.0, "n/a"))
+    );
+    assert_eq!(
+        "NaN (?)",
+        format!("{}", Some(f64::NAN).fmt_trend_or(2.0, "n/a"))
+    );
+    assert_eq!("3 (↑1)  ", format!("{:8}", Some(3).fmt_trend_or(2, "n/a")));
+}
+
+#[test]
+fn test_alert() {
+    let fmt = |opt: Option<f64>, bell| format!("{}", opt.fmt_alert_or(0.9, "n/a").bell(bell));
+    assert_eq!("\x070.95", fmt(Some(0.95), true));
+    assert_eq!("\x070.9", fmt(Some(0.9), true));
+    assert_eq!("0.5", fmt(Some(0.5), true));
+    assert_eq!("0.95", fmt(Some(0.95), false));
+    assert_eq!("n/a", fmt(None, true));
+    assert_eq!("\x07   7", format!("{:4}", Some(7).fmt_alert_or(5, "n/a")));
+}
+
+#[test]
+fn test_fraction() {
+    let fmt = |opt: Option<f64>| format!("{}", opt.fmt_fraction_or(8, "n/a"));
+    assert_eq!("3/4", fmt(Some(0.75)));
+    assert_eq!("1 1/2", fmt(Some(1.5)));
+    assert_eq!("1/2", fmt(Some(0.5)));
+    assert_eq!("2", fmt(Some(2.0)));
+    assert_eq!("0", fmt(Some(0.01)));
+    assert_eq!("1", fmt(Some(0.99)));
+    assert_eq!("-2 1/4", fmt(Some(-2.25)));
+    assert_eq!("0", fmt(Some(-0.01)));
+    assert_eq!("1/8", fmt(Some(0.12)));
+    assert_eq!("n/a", fmt(None));
+    assert_eq!("1/3", format!("{}", Some(0.3333f32).fmt_fraction_or(3, "")));
+    assert_eq!("1", format!("{}", Some(0.6).fmt_fraction_or(0, "")));
+    assert_eq!(
+        "  1 1/2",
+        format!("{:>7}", Some(1.5).fmt_fraction_or(2, ""))
+    );
+    assert_eq!(
+        "1000000000000000000000000000000",
+        format!("{}", Some(1e30).fmt_fraction_or(16, "-"))
+    );
+    assert_eq!(
+        "-300000000000000000000",
+        format!("{}", Some(-3e20).fmt_fraction_or(16, "-"))
+    );
+    assert_eq!(
+        "1/10",
+        format!("{}", Some(0.1).fmt_fraction_or(u32::MAX, ""))
+    );
+    assert_eq!(
+        "3 16/113",
+        format!("{}", Some(core::f64::consts::PI).fmt_fraction_or(1000, ""))
+    );
+}
+
+#[test]
+fn test_gauge() {
+    let fmt = |opt: Option<f64>| format!("{}", opt.fmt_gauge_or(0.0, 1.0, 9, "n/a"));
+    assert_eq!("[----|----]", fmt(Some(0.5)));
+    assert_eq!("[|--------]", fmt(Some(0.0)));
+    assert_eq!("[--------|]", fmt(Some(1.0)));
+    assert_eq!("[--|------]", fmt(Some(0.25)));
+    assert_eq!("n/a", fmt(Some(1.01)));
+    assert_eq!("n/a", fmt(Some(-0.01)));
+    assert_eq!("n/a", fmt(Some(f64::NAN)));
+    assert_eq!("n/a", fmt(None));
+    assert_eq!("[|]", format!("{}", Some(5).fmt_gauge_or(5, 5, 1, "n/a")));
+    assert_eq!("n/a", format!("{}", Some(5).fmt_gauge_or(0, 10, 0, "n/a")));
+    assert_eq!(
+        "[-|-]  ",
+        format!("{:7}", Some(-3i8).fmt_gauge_or(-10, 4, 3, "n/a"))
+    );
+}
+
+#[test]
+fn test_styled() {
+    let fmt = |opt: Option<i64>, style| format!("{}", opt.fmt_styled_or(style, "n/a"));
+    let value = Some(2_300_000);
+    assert_eq!("2300000", fmt(value, Style::Plain));
+    assert_eq!("2,300,000", fmt(value, Style::Grouped));
+    assert_eq!("0x231860", fmt(value, Style::Hex));
+    assert_eq!("2.3M", fmt(value, Style::Compact));
+    assert_eq!("2300000%", fmt(value, Style::Percent));
+    assert_eq!("-1,500", fmt(Some(-1500), Style::Grouped));
+    assert_eq!("-0xff", fmt(Some(-255), Style::Hex));
+    assert_eq!("42%", fmt(Some(42), Style::Percent));
+    for &style in &[
+        Style::Plain,
+        Style::Grouped,
+        Style::Hex,
+        Style::Compact,
+        Style::Percent,
+    ] {
+        assert_eq!("n/a", fmt(None, style));
+        assert_eq!(
+            "  n/a",
+            format!("{:>5}", None::<u8>.fmt_styled_or(style, "n/a"))
+        );
+    }
+    assert_eq!(
+        "00042",
+        format!("{:05}", Some(42).fmt_styled_or(Style::Plain, "n/a"))
+    );
+    assert_eq!(
+        "  42%",
+        format!("{:>5}", Some(42).fmt_styled_or(Style::Percent, "n/a"))
+    );
+}
+
+#[test]
+fn test_pct_change() {
+    let fmt = |opt: Option<f64>, prev| format!("{}", opt.fmt_pct_change_or(prev, 1, "n/a"));
+    assert_eq!("+12.3%", fmt(Some(112.3), 100.0));
+    assert_eq!("-4.5%", fmt(Some(95.5), 100.0));
+    assert_eq!("0.0%", fmt(Some(100.0), 100.0));
+    assert_eq!("+50.0%", fmt(Some(-5.0), -10.0));
+    assert_eq!("-100.0%", fmt(Some(-20.0), -10.0));
+    assert_eq!("+∞", fmt(Some(1.0), 0.0));
+    assert_eq!("-∞", fmt(Some(-1.0), 0.0));
+    assert_eq!("0.0%", fmt(Some(0.0), 0.0));
+    assert_eq!("n/a", fmt(None, 100.0));
+    assert_eq!(
+        "  -50%",
+        format!("{:>6}", Some(5u8).fmt_pct_change_or(10, 0, "n/a"))
+    );
+}
+
+#[test]
+fn test_sortkey() {
+    let values = [
+        Some(7i16),
+        None,
+        Some(-300),
+        Some(0),
+        Some(i16::MAX),
+        Some(-1),
+        Some(i16::MIN),
+    ];
+    let mut keys: Vec<_> = values
+        .iter()
+        .map(|v| (format!("{}", v.fmt_sortkey_or("")), *v))
+        .collect();
+    keys.sort();
+    let sorted: Vec<_> = keys.iter().map(|(_, v)| *v).collect();
+    assert_eq!(
+        vec![
+            None,
+            Some(i16::MIN),
+            Some(-300),
+            Some(-1),
+            Some(0),
+            Some(7),
+            Some(i16::MAX)
+        ],
+        sorted
+    );
+    assert!(keys.iter().all(|(k, _)| k.len() == 6));
+
+    assert_eq!("100007", format!("{}", Some(7u16).fmt_sortkey_or("")));
+    assert_eq!("0000-1", format!("{}", None::<u16>.fmt_sortkey_or("-1")));
+    assert_eq!(
+        "1340282366920938463463374607431768211455",
+        format!("{}", Some(u128::MAX).fmt_sortkey_or(""))
+    );
+    assert_eq!(
+        "1170141183460469231731687303715884105728",
+        format!("{}", Some(0i128).fmt_sortkey_or(""))
+    );
+}
@@ -0,0 +1,68 @@
+//! A newtype alternative to the [`FmtOr`] extension trait.
+
+#[cfg(test)]
+mod tests;
+
+use core::fmt::Display;
+
+use crate::{FmtOr, MaybeFormat, MaybeFormatOr, MaybeFormatOrElse};
+
+/// A borrowed option, offering the [`FmtOr`] fallbacks as inherent methods.
+///
+/// This is for those who would rather wrap explicitly than import an extension trait. Each
+/// method returns the same wrapper as the matching [`FmtOr`] method.
+///
+/// # Example
+///
+/// ```rust
+/// use fmtor::Opt;
+///
+/// let foo = Some(0x42);
+/// let bar: Option<u32> = None;
+///
+/// assert_eq!("0x42", format!("{:#x}", Opt(&foo).or("n/a")));
+/// assert_eq!("n/a", format!("{:#x}", Opt(&bar).or("n/a")));
+/// assert_eq!("", format!("{}", Opt(&bar).or_empty()));
+/// ```
+pub struct Opt<'t, T>(pub &'t Option<T>);
+
+impl<'t, T> Copy for Opt<'t, T> {}
+impl<'t, T> Clone for Opt<'t, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'t, T> Opt<'t, T> {
+    /// Format the value, if there is one, or display an empty string instead.
+    ///
+    /// See [`FmtOr::fmt_or_empty`].
+    #[inline]
+    pub fn or_empty(self) -> MaybeFormat<'t, T> {
+        self.0.fmt_or_empty()
+    }
+    /// Format the value, if there is one, or display the given value instead.
+    ///
+    /// See [`FmtOr::fmt_or`].
+    #[inline]
+    pub fn or<U: Display>(self, u: U) -> MaybeFormatOr<'t, T, U> {
+        self.0.fmt_or(u)
+    }
+    /// Format the value, if there is one, or run the closure and display its result instead.
+    ///
+    /// See [`FmtOr::fmt_or_else`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::Opt;
+    ///
+    /// let bar: Option<u32> = None;
+    ///
+    /// assert_eq!("  lazy", format!("{:>6x}", Opt(&bar).or_else(|| "lazy")));
+    /// ```
+    #[inline]
+    pub fn or_else<U: Display, F: Fn() -> U>(self, f: F) -> MaybeFormatOrElse<'t, T, F> {
+        self.0.fmt_or_else(f)
+    }
+}
@@ -0,0 +1,28 @@
+use crate::{FmtOr, Opt};
+
+#[test]
+fn test_opt() {
+    let foo = Some(0x42);
+    let bar: Option<u32> = None;
+    assert_eq!("0x42", format!("{:#x}", Opt(&foo).or_empty()));
+    assert_eq!("", format!("{:#x}", Opt(&bar).or_empty()));
+    assert_eq!("0x42", format!("{:#x}", Opt(&foo).or("None")));
+    assert_eq!("None", format!("{:#x}", Opt(&bar).or("None")));
+    assert_eq!("0x42", format!("{:#X}", Opt(&foo).or_else(|| "unused")));
+    assert_eq!("lazy", format!("{:#X}", Opt(&bar).or_else(|| "lazy")));
+}
+
+#[test]
+fn test_opt_matches_fmt_or() {
+    for opt in [Some(7u8), None] {
+        let wrapped = Opt(&opt);
+        assert_eq!(
+            format!("{:>5b}", opt.fmt_or("-")),
+            format!("{:>5b}", wrapped.or("-"))
+        );
+        assert_eq!(
+            format!("{:03}", opt.fmt_or_empty()),
+            format!("{:03}", wrapped.or_empty())
+        );
+    }
+}
@@ -0,0 +1,102 @@
+//! Wrappers which own the option instead of borrowing it.
+
+#[cfg(test)]
+mod tests;
+
+use core::fmt::{
+    Binary, Debug, Display, Formatter, LowerExp, LowerHex, Octal, Pointer, Result, UpperExp,
+    UpperHex,
+};
+
+/// The type returned from [`FmtOr::into_fmt_or_empty`]
+///
+/// [`FmtOr::into_fmt_or_empty`]: crate::FmtOr::into_fmt_or_empty
+#[derive(Copy, Clone)]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct OwnedMaybeFormat<T>(pub(crate) Option<T>);
+/// The type returned from [`FmtOr::into_fmt_or`]
+///
+/// [`FmtOr::into_fmt_or`]: crate::FmtOr::into_fmt_or
+#[derive(Copy, Clone)]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct OwnedMaybeFormatOr<T, U>(pub(crate) Option<T>, pub(crate) U);
+/// The type returned from [`FmtOr::into_fmt_or_else`]
+///
+/// [`FmtOr::into_fmt_or_else`]: crate::FmtOr::into_fmt_or_else
+#[derive(Copy, Clone)]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct OwnedMaybeFormatOrElse<T, F>(pub(crate) Option<T>, pub(crate) F);
+
+impl<T> OwnedMaybeFormat<T> {
+    /// Returns the option, giving up the wrapper.
+    #[inline]
+    pub fn into_inner(self) -> Option<T> {
+        self.0
+    }
+}
+
+impl<T, U> OwnedMaybeFormatOr<T, U> {
+    /// Returns the option, giving up the wrapper.
+    #[inline]
+    pub fn into_inner(self) -> Option<T> {
+        self.0
+    }
+}
+
+impl<T, F> OwnedMaybeFormatOrElse<T, F> {
+    /// Returns the option, giving up the wrapper.
+    #[inline]
+    pub fn into_inner(self) -> Option<T> {
+        self.0
+    }
+}
+
+macro_rules! impl_fmt_traits {
+    ($($Trait:ident),*$(,)?) => {$(
+
+impl<T> $Trait for OwnedMaybeFormat<T>
+where
+    T: $Trait,
+{
+    #[inline]
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match &self.0 {
+            Some(t) => <T as $Trait>::fmt(t, out),
+            None => Display::fmt("", out),
+        }
+    }
+}
+
+impl<T, U> $Trait for OwnedMaybeFormatOr<T, U>
+where
+    T: $Trait,
+    U: Display,
+{
+    #[inline]
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match &self.0 {
+            Some(t) => <T as $Trait>::fmt(t, out),
+            None => Display::fmt(&self.1, out),
+        }
+    }
+}
+
+impl<T, F, U> $Trait for OwnedMaybeFormatOrElse<T, F>
+where
+    T: $Trait,
+    F: Fn() -> U,
+    U: Display,
+{
+    #[inline]
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match &self.0 {
+            Some(t) => <T as $Trait>::fmt(t, out),
+            None => Display::fmt(&(self.1)(), out),
+        }
+    }
+}
+
+    )*}
+} // macro_rules! impl_fmt_traits
+
+impl_fmt_traits!(Binary, Debug, Display, LowerExp, LowerHex, Octal, Pointer, UpperExp, UpperHex);
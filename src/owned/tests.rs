@@ -0,0 +1,50 @@
+use std::fmt::Display;
+
+use crate::FmtOr;
+
+fn label(id: Option<u32>) -> impl Display {
+    id.into_fmt_or("unassigned")
+}
+
+struct Row<F> {
+    cell: F,
+}
+
+#[test]
+fn test_owned() {
+    assert_eq!("7", label(Some(7)).to_string());
+    assert_eq!("unassigned", label(None).to_string());
+
+    assert_eq!("0x2a", format!("{:#x}", Some(42).into_fmt_or("-")));
+    assert_eq!("  -", format!("{:>3x}", None::<u32>.into_fmt_or("-")));
+    assert_eq!("", format!("{:x}", None::<u32>.into_fmt_or_empty()));
+    assert_eq!("101", format!("{:b}", Some(5).into_fmt_or_empty()));
+    assert_eq!("?", format!("{:?}", None::<u32>.into_fmt_or_else(|| "?")));
+    assert_eq!(
+        "\"x\"",
+        format!("{:?}", Some(String::from("x")).into_fmt_or_else(|| "?"))
+    );
+
+    let row = Row {
+        cell: Some(1.5).into_fmt_or("n/a"),
+    };
+    assert_eq!("1.50", format!("{:.2}", row.cell));
+    assert_eq!(Some(1.5), row.cell.into_inner());
+}
+
+#[test]
+fn test_owned_iter() {
+    let cells = vec![Some(1), None, Some(3)];
+    let rendered: Vec<String> = cells
+        .into_iter()
+        .map(|o| o.into_fmt_or("n/a").to_string())
+        .collect();
+    assert_eq!(["1", "n/a", "3"], rendered[..]);
+
+    // The wrappers themselves are 'static, so they can be collected before formatting.
+    let wrappers: Vec<Box<dyn Display>> = vec![Some(0x10), None]
+        .into_iter()
+        .map(|o| Box::new(o.into_fmt_or("-")) as Box<dyn Display>)
+        .collect();
+    assert_eq!("16 -", format!("{} {}", wrappers[0], wrappers[1]));
+}
@@ -0,0 +1,56 @@
+//! Padding for adapters whose output is assembled from several pieces.
+
+use core::fmt::{Alignment, Formatter, Result, Write};
+
+/// Counts the `char`s written to it, the same way [`Formatter::pad`] measures width.
+pub(crate) struct CharCount(pub(crate) usize);
+
+impl Write for CharCount {
+    fn write_str(&mut self, s: &str) -> Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+/// Writes the output of `body`, padded to the width, fill and alignment of `out` as if the
+/// whole output were a single string. `default` is used when no alignment was requested.
+///
+/// When padding is needed, `body` runs twice: once to measure and once to write.
+pub(crate) fn pad<F>(out: &mut Formatter<'_>, default: Alignment, body: F) -> Result
+where
+    F: Fn(&mut dyn Write) -> Result,
+{
+    let align = out.align().unwrap_or(default);
+    pad_aligned(out, align, body)
+}
+
+/// Like [`pad`], but always uses `align`, ignoring any alignment requested by `out`.
+pub(crate) fn pad_aligned<F>(out: &mut Formatter<'_>, align: Alignment, body: F) -> Result
+where
+    F: Fn(&mut dyn Write) -> Result,
+{
+    let width = match out.width() {
+        Some(width) => width,
+        None => return body(out),
+    };
+    let mut count = CharCount(0);
+    body(&mut count)?;
+    if count.0 >= width {
+        return body(out);
+    }
+    let padding = width - count.0;
+    let (pre, post) = match align {
+        Alignment::Left => (0, padding),
+        Alignment::Right => (padding, 0),
+        Alignment::Center => (padding / 2, padding - padding / 2),
+    };
+    let fill = out.fill();
+    for _ in 0..pre {
+        out.write_char(fill)?;
+    }
+    body(out)?;
+    for _ in 0..post {
+        out.write_char(fill)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,43 @@
+//! Adapters for optional pointers.
+
+#[cfg(test)]
+mod tests;
+
+use core::fmt::{Display, Formatter, Pointer, Result};
+use core::ptr;
+
+/// How [`FmtOr::fmt_ptr_or_null`](crate::FmtOr::fmt_ptr_or_null) renders a missing pointer.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum NullPtr {
+    /// A real null pointer, formatted with the format spec like any other pointer. This is
+    /// `0x0`, or with the alternate flag as in `{:#p}`, zero padded to the pointer width of the
+    /// target, e.g. `0x0000000000000000` on 64-bit targets.
+    Zero,
+    /// `(nil)`, as printed by some C libraries for a null `%p`.
+    Nil,
+}
+
+/// The type returned from [`FmtOr::fmt_ptr_or_null`](crate::FmtOr::fmt_ptr_or_null)
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatPtrOrNull<'t, T>(pub(crate) &'t Option<T>, pub(crate) NullPtr);
+
+impl<'t, T> Copy for MaybeFormatPtrOrNull<'t, T> {}
+impl<'t, T> Clone for MaybeFormatPtrOrNull<'t, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'t, T> Pointer for MaybeFormatPtrOrNull<'t, T>
+where
+    T: Pointer,
+{
+    #[inline]
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match (self.0, self.1) {
+            (Some(t), _) => Pointer::fmt(t, out),
+            (None, NullPtr::Zero) => Pointer::fmt(&ptr::null::<u8>(), out),
+            (None, NullPtr::Nil) => Display::fmt("(nil)", out),
+        }
+    }
+}
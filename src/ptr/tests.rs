@@ -0,0 +1,48 @@
+use core::mem::size_of;
+
+use crate::{FmtOr, NullPtr};
+
+#[test]
+fn test_ptr_or_null_present() {
+    let x = 5u32;
+    let some = Some(&x);
+    let address = format!("{:p}", &x);
+    assert!(address.starts_with("0x"), "{}", address);
+    assert_eq!(
+        address,
+        format!("{:p}", some.fmt_ptr_or_null(NullPtr::Zero))
+    );
+    assert_eq!(address, format!("{:p}", some.fmt_ptr_or_null(NullPtr::Nil)));
+    assert_eq!(
+        format!("{:#p}", &x),
+        format!("{:#p}", some.fmt_ptr_or_null(NullPtr::Zero))
+    );
+}
+
+#[test]
+fn test_ptr_or_null_absent() {
+    let none: Option<&u32> = None;
+    assert_eq!("0x0", format!("{:p}", none.fmt_ptr_or_null(NullPtr::Zero)));
+    assert_eq!("(nil)", format!("{:p}", none.fmt_ptr_or_null(NullPtr::Nil)));
+    assert_eq!(
+        "  (nil)",
+        format!("{:>7p}", none.fmt_ptr_or_null(NullPtr::Nil))
+    );
+}
+
+#[test]
+fn test_ptr_or_null_width() {
+    let x = 5u32;
+    let zero = format!("{:#p}", None::<&u32>.fmt_ptr_or_null(NullPtr::Zero));
+    // Two hex digits per byte of a pointer, after the `0x`.
+    let expected = format!("0x{}", "0".repeat(2 * size_of::<usize>()));
+    assert_eq!(expected, zero);
+    if cfg!(target_pointer_width = "64") {
+        assert_eq!("0x0000000000000000", zero);
+    } else if cfg!(target_pointer_width = "32") {
+        assert_eq!("0x00000000", zero);
+    }
+    // A present pointer under the same spec lines up with the null one.
+    let present = format!("{:#p}", Some(&x).fmt_ptr_or_null(NullPtr::Zero));
+    assert_eq!(zero.len(), present.len());
+}
@@ -0,0 +1,219 @@
+//! Adapters for results and optional results.
+
+#[cfg(test)]
+mod tests;
+
+use core::fmt::{
+    Alignment, Binary, Debug, Display, Formatter, LowerExp, LowerHex, Octal, Pointer, Result,
+    UpperExp, UpperHex, Write,
+};
+
+use crate::pad::pad;
+
+/// The type returned from [`FmtOrStatus::fmt_status_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatStatus<'t, T, E>(&'t Option<core::result::Result<T, E>>, [&'t str; 3]);
+/// The type returned from [`FmtResult::fmt_ok_or_err`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatResult<'t, T, E>(&'t core::result::Result<T, E>);
+/// The type returned from [`FmtResult::fmt_ok_or_else`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatResultOrElse<'t, T, E, F>(&'t core::result::Result<T, E>, F);
+
+impl<'t, T, E> Copy for MaybeFormatResult<'t, T, E> {}
+impl<'t, T, E> Clone for MaybeFormatResult<'t, T, E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'t, T, E, F: Copy> Copy for MaybeFormatResultOrElse<'t, T, E, F> {}
+impl<'t, T, E, F: Clone> Clone for MaybeFormatResultOrElse<'t, T, E, F> {
+    fn clone(&self) -> Self {
+        Self(self.0, self.1.clone())
+    }
+}
+
+/// An extension trait for [`Result<T, E>`](core::result::Result), like [`FmtOr`] is for
+/// [`Option<T>`].
+///
+/// The methods on this trait return types which implement all the same format traits as `T`. An
+/// `Ok` value is formatted as `T`, while an `Err` is replaced by something rendered with
+/// [`Display`], regardless of which format trait is used.
+///
+/// [`FmtOr`]: crate::FmtOr
+#[allow(clippy::needless_lifetimes)] // They're nice to see in docs
+pub trait FmtResult<T, E> {
+    /// Format the `Ok` value, or display the error instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtResult;
+    ///
+    /// let foo: Result<u32, &str> = Ok(0x42);
+    /// let bar: Result<u32, &str> = Err("no value");
+    ///
+    /// assert_eq!(
+    ///     "value = 0x42",
+    ///     format!("value = {:#x}", foo.fmt_ok_or_err())
+    /// );
+    /// assert_eq!(
+    ///     "value = no value",
+    ///     format!("value = {:#x}", bar.fmt_ok_or_err())
+    /// );
+    /// ```
+    fn fmt_ok_or_err<'t>(&'t self) -> MaybeFormatResult<'t, T, E>
+    where
+        E: Display;
+    /// Format the `Ok` value, or run the closure on the error to get a value to display instead.
+    ///
+    /// As with [`FmtOr::fmt_or_else`], the closure runs during each formatting operation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtResult;
+    ///
+    /// let bar: Result<u32, u16> = Err(404);
+    ///
+    /// assert_eq!(
+    ///     "error 404",
+    ///     format!("{:x}", bar.fmt_ok_or_else(|e| format!("error {}", e)))
+    /// );
+    /// ```
+    ///
+    /// [`FmtOr::fmt_or_else`]: crate::FmtOr::fmt_or_else
+    fn fmt_ok_or_else<'t, U, F>(&'t self, f: F) -> MaybeFormatResultOrElse<'t, T, E, F>
+    where
+        U: Display,
+        F: Fn(&E) -> U;
+}
+
+impl<T, E> FmtResult<T, E> for core::result::Result<T, E> {
+    #[inline]
+    fn fmt_ok_or_err(&self) -> MaybeFormatResult<'_, T, E>
+    where
+        E: Display,
+    {
+        MaybeFormatResult(self)
+    }
+    #[inline]
+    fn fmt_ok_or_else<U, F>(&self, f: F) -> MaybeFormatResultOrElse<'_, T, E, F>
+    where
+        U: Display,
+        F: Fn(&E) -> U,
+    {
+        MaybeFormatResultOrElse(self, f)
+    }
+}
+
+macro_rules! impl_fmt_traits {
+    ($($Trait:ident),*$(,)?) => {$(
+
+impl<'t, T, E> $Trait for MaybeFormatResult<'t, T, E>
+where
+    T: $Trait,
+    E: Display,
+{
+    #[inline]
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Ok(t) => <T as $Trait>::fmt(t, out),
+            Err(e) => Display::fmt(e, out),
+        }
+    }
+}
+
+impl<'t, T, E, F, U> $Trait for MaybeFormatResultOrElse<'t, T, E, F>
+where
+    T: $Trait,
+    F: Fn(&E) -> U,
+    U: Display,
+{
+    #[inline]
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Ok(t) => <T as $Trait>::fmt(t, out),
+            Err(e) => Display::fmt(&(self.1)(e), out),
+        }
+    }
+}
+
+    )*}
+} // macro_rules! impl_fmt_traits
+
+impl_fmt_traits!(Binary, Debug, Display, LowerExp, LowerHex, Octal, Pointer, UpperExp, UpperHex);
+
+/// An extension trait for [`Option<Result<T, E>>`], such as the state of a job which may not have finished.
+#[allow(clippy::needless_lifetimes)] // They're nice to see in docs
+pub trait FmtOrStatus<T, E> {
+    /// Format the value or the error after a status glyph, or display `none_glyph` instead.
+    ///
+    /// `Some(Ok(t))` is shown as `ok_glyph` followed by `t`, `Some(Err(e))` as `err_glyph`
+    /// followed by `e`, and `None` as `none_glyph` by itself. The glyph and value are padded
+    /// together to the requested width, while `none_glyph` is formatted with the full format spec
+    /// like the fallback of [`FmtOr::fmt_or`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrStatus;
+    ///
+    /// let done: Option<Result<u32, &str>> = Some(Ok(3));
+    /// let failed: Option<Result<u32, &str>> = Some(Err("timeout"));
+    /// let pending: Option<Result<u32, &str>> = None;
+    ///
+    /// assert_eq!("✓3", format!("{}", done.fmt_status_or("✓", "✗", "–")));
+    /// assert_eq!("✗timeout", format!("{}", failed.fmt_status_or("✓", "✗", "–")));
+    /// assert_eq!("–", format!("{}", pending.fmt_status_or("✓", "✗", "–")));
+    /// ```
+    ///
+    /// [`FmtOr::fmt_or`]: crate::FmtOr::fmt_or
+    fn fmt_status_or<'t>(
+        &'t self,
+        ok_glyph: &'t str,
+        err_glyph: &'t str,
+        none_glyph: &'t str,
+    ) -> MaybeFormatStatus<'t, T, E>
+    where
+        T: Display,
+        E: Display;
+}
+
+impl<T, E> FmtOrStatus<T, E> for Option<core::result::Result<T, E>> {
+    #[inline]
+    fn fmt_status_or<'t>(
+        &'t self,
+        ok_glyph: &'t str,
+        err_glyph: &'t str,
+        none_glyph: &'t str,
+    ) -> MaybeFormatStatus<'t, T, E>
+    where
+        T: Display,
+        E: Display,
+    {
+        MaybeFormatStatus(self, [ok_glyph, err_glyph, none_glyph])
+    }
+}
+
+impl<'t, T, E> Display for MaybeFormatStatus<'t, T, E>
+where
+    T: Display,
+    E: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        let [ok_glyph, err_glyph, none_glyph] = self.1;
+        match self.0 {
+            Some(Ok(t)) => pad(out, Alignment::Left, |w: &mut dyn Write| {
+                w.write_str(ok_glyph)?;
+                write!(w, "{}", t)
+            }),
+            Some(Err(e)) => pad(out, Alignment::Left, |w: &mut dyn Write| {
+                w.write_str(err_glyph)?;
+                write!(w, "{}", e)
+            }),
+            None => Display::fmt(none_glyph, out),
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use crate::{FmtOrStatus, FmtResult};
+
+#[test]
+fn test_status() {
+    let fmt = |opt: Option<Result<f32, String>>| format!("{}", opt.fmt_status_or("✓ ", "✗ ", "–"));
+    assert_eq!("✓ 1.5", fmt(Some(Ok(1.5))));
+    assert_eq!("✗ disk full", fmt(Some(Err("disk full".to_string()))));
+    assert_eq!("–", fmt(None));
+}
+
+#[test]
+fn test_status_width() {
+    let done: Option<Result<u8, &str>> = Some(Ok(7));
+    let pending: Option<Result<u8, &str>> = None;
+    assert_eq!("✓7  ", format!("{:4}", done.fmt_status_or("✓", "✗", "–")));
+    assert_eq!(
+        "   –",
+        format!("{:>4}", pending.fmt_status_or("✓", "✗", "–"))
+    );
+}
+
+#[test]
+fn test_ok_or_err() {
+    let ok: Result<u32, &str> = Ok(10);
+    let err: Result<u32, &str> = Err("bad");
+    assert_eq!("10", format!("{}", ok.fmt_ok_or_err()));
+    assert_eq!("0xa", format!("{:#x}", ok.fmt_ok_or_err()));
+    assert_eq!("00001010", format!("{:08b}", ok.fmt_ok_or_err()));
+    assert_eq!("12", format!("{:o}", ok.fmt_ok_or_err()));
+    assert_eq!("1e1", format!("{:e}", ok.fmt_ok_or_err()));
+    assert_eq!("bad", format!("{:#x}", err.fmt_ok_or_err()));
+    assert_eq!("bad", format!("{:?}", err.fmt_ok_or_err()));
+    assert_eq!("  bad", format!("{:>5b}", err.fmt_ok_or_err()));
+
+    let boxed: Result<Box<u8>, &str> = Err("null");
+    assert_eq!("null", format!("{:p}", boxed.fmt_ok_or_err()));
+}
+
+#[test]
+fn test_ok_or_else() {
+    let ok: Result<u32, u16> = Ok(255);
+    let err: Result<u32, u16> = Err(404);
+    let describe = |e: &u16| format!("HTTP {}", e);
+    assert_eq!("FF", format!("{:X}", ok.fmt_ok_or_else(describe)));
+    assert_eq!("HTTP 404", format!("{:X}", err.fmt_ok_or_else(describe)));
+    assert_eq!(
+        "HTTP 404  ",
+        format!("{:10?}", err.fmt_ok_or_else(describe))
+    );
+}
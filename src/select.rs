@@ -0,0 +1,113 @@
+//! Picking the formatting trait at runtime.
+
+#[cfg(test)]
+mod tests;
+
+use core::fmt::{
+    Binary, Debug, Display, Formatter, LowerExp, LowerHex, Octal, Result, UpperExp, UpperHex, Write,
+};
+
+/// A formatting trait for [`FmtOr::write_fmt_or`](crate::FmtOr::write_fmt_or), so the trait can
+/// be picked at runtime, such as from configuration.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Fmt {
+    /// [`Display`], as with `{}`.
+    Display,
+    /// [`Debug`], as with `{:?}`.
+    Debug,
+    /// [`LowerHex`], as with `{:x}`.
+    LowerHex,
+    /// [`UpperHex`], as with `{:X}`.
+    UpperHex,
+    /// [`Octal`], as with `{:o}`.
+    Octal,
+    /// [`Binary`], as with `{:b}`.
+    Binary,
+    /// [`LowerExp`], as with `{:e}`.
+    LowerExp,
+    /// [`UpperExp`], as with `{:E}`.
+    UpperExp,
+}
+
+impl Fmt {
+    /// Writes `t` into `out` with the selected trait.
+    pub(crate) fn write<W, T>(self, out: &mut W, t: &T) -> Result
+    where
+        W: Write + ?Sized,
+        T: Display + Debug + LowerHex + UpperHex + Octal + Binary + LowerExp + UpperExp,
+    {
+        match self {
+            Fmt::Display => write!(out, "{}", t),
+            Fmt::Debug => write!(out, "{:?}", t),
+            Fmt::LowerHex => write!(out, "{:x}", t),
+            Fmt::UpperHex => write!(out, "{:X}", t),
+            Fmt::Octal => write!(out, "{:o}", t),
+            Fmt::Binary => write!(out, "{:b}", t),
+            Fmt::LowerExp => write!(out, "{:e}", t),
+            Fmt::UpperExp => write!(out, "{:E}", t),
+        }
+    }
+}
+
+/// A radix or notation for [`FmtOr::fmt_or_dyn`](crate::FmtOr::fmt_or_dyn), so it can be picked
+/// at runtime, such as from a settings file.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum FmtKind {
+    /// Decimal, as with `{}`.
+    Decimal,
+    /// Lower case hex, as with `{:x}`.
+    LowerHex,
+    /// Upper case hex, as with `{:X}`.
+    UpperHex,
+    /// Octal, as with `{:o}`.
+    Octal,
+    /// Binary, as with `{:b}`.
+    Binary,
+    /// Lower case scientific notation, as with `{:e}`.
+    LowerExp,
+    /// Upper case scientific notation, as with `{:E}`.
+    UpperExp,
+}
+
+/// The type returned from [`FmtOr::fmt_or_dyn`](crate::FmtOr::fmt_or_dyn)
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatDyn<'t, T, U>(pub(crate) &'t Option<T>, pub(crate) FmtKind, pub(crate) U);
+
+impl<'t, T, U: Copy> Copy for MaybeFormatDyn<'t, T, U> {}
+impl<'t, T, U: Clone> Clone for MaybeFormatDyn<'t, T, U> {
+    fn clone(&self) -> Self {
+        Self(self.0, self.1, self.2.clone())
+    }
+}
+
+impl<'t, T, U> MaybeFormatDyn<'t, T, U> {
+    /// Returns the radix or notation the value is formatted with.
+    #[inline]
+    pub fn kind(&self) -> FmtKind {
+        self.1
+    }
+}
+
+impl<'t, T, U> Display for MaybeFormatDyn<'t, T, U>
+where
+    T: Display + LowerHex + UpperHex + Octal + Binary + LowerExp + UpperExp,
+    U: Display,
+{
+    #[inline]
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        let t = match self.0 {
+            Some(t) => t,
+            None => return Display::fmt(&self.2, out),
+        };
+        // The flags of the format spec, such as `#` and the width, carry over to the picked trait.
+        match self.1 {
+            FmtKind::Decimal => Display::fmt(t, out),
+            FmtKind::LowerHex => LowerHex::fmt(t, out),
+            FmtKind::UpperHex => UpperHex::fmt(t, out),
+            FmtKind::Octal => Octal::fmt(t, out),
+            FmtKind::Binary => Binary::fmt(t, out),
+            FmtKind::LowerExp => LowerExp::fmt(t, out),
+            FmtKind::UpperExp => UpperExp::fmt(t, out),
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use crate::{Fmt, FmtKind, FmtOr};
+
+fn write(opt: Option<u32>, fmt: Fmt) -> String {
+    let mut buf = String::new();
+    opt.write_fmt_or(&mut buf, fmt, "n/a").unwrap();
+    buf
+}
+
+#[test]
+fn test_write_fmt_or() {
+    assert_eq!("42", write(Some(42), Fmt::Display));
+    assert_eq!("2a", write(Some(42), Fmt::LowerHex));
+    assert_eq!("101010", write(Some(42), Fmt::Binary));
+    assert_eq!("n/a", write(None, Fmt::Display));
+    assert_eq!("n/a", write(None, Fmt::LowerHex));
+    assert_eq!("n/a", write(None, Fmt::Binary));
+}
+
+#[test]
+fn test_write_fmt_or_every_trait() {
+    let expected = [
+        (Fmt::Display, "42"),
+        (Fmt::Debug, "42"),
+        (Fmt::LowerHex, "2a"),
+        (Fmt::UpperHex, "2A"),
+        (Fmt::Octal, "52"),
+        (Fmt::Binary, "101010"),
+        (Fmt::LowerExp, "4.2e1"),
+        (Fmt::UpperExp, "4.2E1"),
+    ];
+    for &(fmt, s) in &expected {
+        assert_eq!(s, write(Some(42), fmt), "{:?}", fmt);
+    }
+}
+
+#[test]
+fn test_write_fmt_or_appends() {
+    let mut buf = String::from("id=");
+    Some(255u8)
+        .write_fmt_or(&mut buf, Fmt::UpperHex, "?")
+        .unwrap();
+    buf.push_str(", parent=");
+    None::<u8>
+        .write_fmt_or(&mut buf, Fmt::UpperHex, "?")
+        .unwrap();
+    assert_eq!("id=FF, parent=?", buf);
+}
+
+#[test]
+fn test_fmt_or_dyn_every_kind() {
+    let expected = [
+        (FmtKind::Decimal, "42"),
+        (FmtKind::LowerHex, "2a"),
+        (FmtKind::UpperHex, "2A"),
+        (FmtKind::Octal, "52"),
+        (FmtKind::Binary, "101010"),
+        (FmtKind::LowerExp, "4.2e1"),
+        (FmtKind::UpperExp, "4.2E1"),
+    ];
+    for &(kind, s) in &expected {
+        assert_eq!(
+            s,
+            format!("{}", Some(42u32).fmt_or_dyn(kind, "n/a")),
+            "{:?}",
+            kind
+        );
+        assert_eq!(
+            "n/a",
+            format!("{}", None::<u32>.fmt_or_dyn(kind, "n/a")),
+            "{:?}",
+            kind
+        );
+    }
+}
+
+#[test]
+fn test_fmt_or_dyn_spec() {
+    let some = Some(10u32);
+    assert_eq!(
+        "0b1010",
+        format!("{:#}", some.fmt_or_dyn(FmtKind::Binary, "?"))
+    );
+    assert_eq!(
+        "00001010",
+        format!("{:08}", some.fmt_or_dyn(FmtKind::Binary, "?"))
+    );
+    assert_eq!(
+        "       a",
+        format!("{:>8}", some.fmt_or_dyn(FmtKind::LowerHex, "?"))
+    );
+    assert_eq!(
+        "       ?",
+        format!("{:>8}", None::<u32>.fmt_or_dyn(FmtKind::LowerHex, "?"))
+    );
+    assert_eq!(FmtKind::Octal, some.fmt_or_dyn(FmtKind::Octal, "?").kind());
+}
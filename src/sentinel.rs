@@ -0,0 +1,131 @@
+//! Fallbacks chosen by the formatting trait in use.
+
+#[cfg(test)]
+mod tests;
+
+use core::fmt::{
+    Binary, Debug, Display, Formatter, LowerExp, LowerHex, Octal, Pointer, Result, UpperExp,
+    UpperHex,
+};
+
+/// The type returned from [`FmtOr::fmt_or_sentinel`]
+///
+/// [`FmtOr::fmt_or_sentinel`]: crate::FmtOr::fmt_or_sentinel
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatSentinel<'t, T>(pub(crate) &'t Option<T>, pub(crate) Sentinels<'t>);
+
+/// The strings shown for a missing value, chosen by the formatting trait in use.
+///
+/// Each formatting trait can be given its own sentinel, such as `(nil)` for `{:p}`. Traits without
+/// one show the default given to [`new`](Sentinels::new). The sentinels are formatted like strings,
+/// so width and alignment apply to them.
+///
+/// # Example
+///
+/// ```rust
+/// use fmtor::{FmtOr, Sentinels};
+///
+/// const C_LIKE: Sentinels = Sentinels::new("0").pointer("(nil)").lower_exp("NaN");
+///
+/// let ptr: Option<&u32> = None;
+/// let count: Option<u32> = None;
+///
+/// assert_eq!("(nil)", format!("{:p}", ptr.fmt_or_sentinel(C_LIKE)));
+/// assert_eq!("0", format!("{:x}", count.fmt_or_sentinel(C_LIKE)));
+/// assert_eq!("NaN", format!("{:e}", count.fmt_or_sentinel(C_LIKE)));
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Sentinels<'s> {
+    default: &'s str,
+    binary: Option<&'s str>,
+    debug: Option<&'s str>,
+    display: Option<&'s str>,
+    lower_exp: Option<&'s str>,
+    lower_hex: Option<&'s str>,
+    octal: Option<&'s str>,
+    pointer: Option<&'s str>,
+    upper_exp: Option<&'s str>,
+    upper_hex: Option<&'s str>,
+}
+
+macro_rules! sentinel_setters {
+    ($($field:ident: $Trait:ident),*$(,)?) => {$(
+        #[doc = concat!("Sets the sentinel shown when formatting with [`", stringify!($Trait), "`].")]
+        #[inline]
+        pub const fn $field(self, sentinel: &'s str) -> Self {
+            Self {
+                $field: Some(sentinel),
+                ..self
+            }
+        }
+    )*};
+}
+
+impl<'s> Sentinels<'s> {
+    /// Creates sentinels which show `default` for every formatting trait.
+    #[inline]
+    pub const fn new(default: &'s str) -> Self {
+        Self {
+            default,
+            binary: None,
+            debug: None,
+            display: None,
+            lower_exp: None,
+            lower_hex: None,
+            octal: None,
+            pointer: None,
+            upper_exp: None,
+            upper_hex: None,
+        }
+    }
+
+    sentinel_setters!(
+        binary: Binary,
+        debug: Debug,
+        display: Display,
+        lower_exp: LowerExp,
+        lower_hex: LowerHex,
+        octal: Octal,
+        pointer: Pointer,
+        upper_exp: UpperExp,
+        upper_hex: UpperHex,
+    );
+}
+
+impl<'t, T> Copy for MaybeFormatSentinel<'t, T> {}
+impl<'t, T> Clone for MaybeFormatSentinel<'t, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+macro_rules! impl_fmt_traits {
+    ($($Trait:ident => $field:ident),*$(,)?) => {$(
+
+impl<'t, T> $Trait for MaybeFormatSentinel<'t, T>
+where
+    T: $Trait,
+{
+    #[inline]
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) => <T as $Trait>::fmt(t, out),
+            None => Display::fmt(self.1.$field.unwrap_or(self.1.default), out),
+        }
+    }
+}
+
+    )*}
+} // macro_rules! impl_fmt_traits
+
+impl_fmt_traits!(
+    Binary => binary,
+    Debug => debug,
+    Display => display,
+    LowerExp => lower_exp,
+    LowerHex => lower_hex,
+    Octal => octal,
+    Pointer => pointer,
+    UpperExp => upper_exp,
+    UpperHex => upper_hex,
+);
@@ -0,0 +1,38 @@
+use crate::{FmtOr, Sentinels};
+
+#[test]
+fn test_sentinel() {
+    let sentinels = Sentinels::new("0").pointer("(nil)");
+    let ptr = None::<&u32>;
+    let wrapper = ptr.fmt_or_sentinel(sentinels);
+    assert_eq!("(nil)", format!("{:p}", wrapper));
+    assert_eq!("0", format!("{:x}", wrapper));
+    assert_eq!("0", format!("{}", wrapper));
+    assert_eq!("  (nil)", format!("{:>7p}", wrapper));
+
+    let all = Sentinels::new("?")
+        .binary("b")
+        .debug("d")
+        .display("s")
+        .lower_exp("e")
+        .lower_hex("x")
+        .octal("o")
+        .upper_exp("E")
+        .upper_hex("X");
+    let none = None::<u32>;
+    let wrapper = none.fmt_or_sentinel(all);
+    assert_eq!(
+        "b d s e x o E X",
+        format!(
+            "{:b} {:?} {} {:e} {:x} {:o} {:E} {:X}",
+            wrapper, wrapper, wrapper, wrapper, wrapper, wrapper, wrapper, wrapper
+        )
+    );
+    assert_eq!("?", format!("{:p}", None::<&u8>.fmt_or_sentinel(all)));
+
+    assert_eq!("ff", format!("{:x}", Some(255).fmt_or_sentinel(all)));
+    assert_eq!(
+        "",
+        format!("{}", none.fmt_or_sentinel(Sentinels::default()))
+    );
+}
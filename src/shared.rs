@@ -0,0 +1,118 @@
+//! Fallbacks rendered once and shared between many wrappers.
+
+#[cfg(test)]
+mod tests;
+
+use alloc::string::String;
+use core::fmt::{
+    Binary, Debug, Display, Formatter, LowerExp, LowerHex, Octal, Pointer, Result, UpperExp,
+    UpperHex, Write,
+};
+
+/// The type returned from [`FmtOrShared::fmt_or_shared`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatShared<'t, 's, T>(&'t Option<T>, &'s SharedFallback);
+
+/// A fallback rendered to a [`String`] once, to be reused by many wrappers.
+///
+/// This is intended for large tables, where most cells are missing and share the same expensive
+/// fallback. Every [`fmt_or_shared`](FmtOrShared::fmt_or_shared) wrapper borrowing it writes the
+/// rendered text instead of running the fallback's [`Display`] impl again.
+///
+/// The fallback is rendered without a format spec. The rendered text is then formatted like a
+/// string, so width, alignment and precision still apply to it.
+///
+/// # Example
+///
+/// ```rust
+/// use fmtor::{FmtOrShared, SharedFallback};
+///
+/// let shared = SharedFallback::new(format_args!("<{} missing>", "value"));
+/// let cells = [Some(1), None, Some(3), None];
+///
+/// let row: Vec<_> = cells.iter().map(|c| format!("{}", c.fmt_or_shared(&shared))).collect();
+/// assert_eq!(["1", "<value missing>", "3", "<value missing>"], row[..]);
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct SharedFallback {
+    rendered: String,
+}
+
+impl SharedFallback {
+    /// Renders the fallback once, ready to be shared.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the fallback's [`Display`] impl returns an error, like [`ToString::to_string`]
+    /// does.
+    ///
+    /// [`ToString::to_string`]: alloc::string::ToString::to_string
+    pub fn new<U: Display>(fallback: U) -> Self {
+        let mut rendered = String::new();
+        write!(rendered, "{}", fallback).expect("a Display implementation returned an error");
+        Self { rendered }
+    }
+    /// Returns the rendered fallback.
+    pub fn as_str(&self) -> &str {
+        &self.rendered
+    }
+}
+
+/// An extension trait for formatting [`Option<T>`] with a [`SharedFallback`].
+///
+/// Requires the `alloc` feature.
+#[allow(clippy::needless_lifetimes)] // They're nice to see in docs
+pub trait FmtOrShared<T> {
+    /// Format the value, if there is one, or write the already rendered [`SharedFallback`] instead.
+    ///
+    /// This behaves like [`fmt_or`](crate::FmtOr::fmt_or) with the original fallback, but its
+    /// [`Display`] impl is not run again for every missing value. The returned wrapper is
+    /// [`Copy`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::{FmtOrShared, SharedFallback};
+    ///
+    /// let shared = SharedFallback::new("n/a");
+    ///
+    /// assert_eq!("2a", format!("{:x}", Some(42).fmt_or_shared(&shared)));
+    /// assert_eq!("  n/a", format!("{:>5x}", None::<u32>.fmt_or_shared(&shared)));
+    /// ```
+    fn fmt_or_shared<'t, 's>(&'t self, shared: &'s SharedFallback) -> MaybeFormatShared<'t, 's, T>;
+}
+
+impl<T> FmtOrShared<T> for Option<T> {
+    #[inline]
+    fn fmt_or_shared<'s>(&self, shared: &'s SharedFallback) -> MaybeFormatShared<'_, 's, T> {
+        MaybeFormatShared(self, shared)
+    }
+}
+
+impl<'t, 's, T> Copy for MaybeFormatShared<'t, 's, T> {}
+impl<'t, 's, T> Clone for MaybeFormatShared<'t, 's, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+macro_rules! impl_fmt_traits {
+    ($($Trait:ident),*$(,)?) => {$(
+
+impl<'t, 's, T> $Trait for MaybeFormatShared<'t, 's, T>
+where
+    T: $Trait,
+{
+    #[inline]
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) => <T as $Trait>::fmt(t, out),
+            None => Display::fmt(self.1.as_str(), out),
+        }
+    }
+}
+
+    )*}
+} // macro_rules! impl_fmt_traits
+
+impl_fmt_traits!(Binary, Debug, Display, LowerExp, LowerHex, Octal, Pointer, UpperExp, UpperHex);
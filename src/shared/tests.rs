@@ -0,0 +1,55 @@
+use crate::{FmtOr, FmtOrShared, SharedFallback};
+
+#[test]
+fn test_shared() {
+    let shared = SharedFallback::new("n/a");
+    let cells = [Some(0x42u32), None, Some(7), None];
+    for cell in &cells {
+        for (want, got) in &[
+            (
+                format!("{}", cell.fmt_or("n/a")),
+                format!("{}", cell.fmt_or_shared(&shared)),
+            ),
+            (
+                format!("{:x}", cell.fmt_or("n/a")),
+                format!("{:x}", cell.fmt_or_shared(&shared)),
+            ),
+            (
+                format!("{:>6}", cell.fmt_or("n/a")),
+                format!("{:>6}", cell.fmt_or_shared(&shared)),
+            ),
+            (
+                format!("{:#b}", cell.fmt_or("n/a")),
+                format!("{:#b}", cell.fmt_or_shared(&shared)),
+            ),
+        ] {
+            assert_eq!(want, got);
+        }
+    }
+    let wrapper = None::<u8>.fmt_or_shared(&shared);
+    let copy = wrapper;
+    assert_eq!(format!("{}", wrapper), format!("{}", copy));
+}
+
+#[test]
+fn test_shared_renders_once() {
+    use std::cell::Cell;
+    use std::fmt::{Display, Formatter, Result};
+
+    struct Counted<'c>(&'c Cell<u32>);
+    impl Display for Counted<'_> {
+        fn fmt(&self, out: &mut Formatter) -> Result {
+            self.0.set(self.0.get() + 1);
+            out.write_str("missing")
+        }
+    }
+
+    let calls = Cell::new(0);
+    let shared = SharedFallback::new(Counted(&calls));
+    assert_eq!("missing", shared.as_str());
+    for _ in 0..10 {
+        assert_eq!("missing", format!("{}", None::<u32>.fmt_or_shared(&shared)));
+    }
+    assert_eq!(1, calls.get());
+    assert_eq!("", SharedFallback::default().as_str());
+}
@@ -0,0 +1,406 @@
+//! Adapters for optional slices.
+
+#[cfg(test)]
+mod tests;
+
+use core::fmt::{
+    Alignment, Binary, Debug, Display, Formatter, LowerExp, LowerHex, Octal, Pointer, Result,
+    UpperExp, UpperHex, Write,
+};
+
+use crate::pad::pad;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// The type returned from [`FmtOrSlice::fmt_runs_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatRuns<'t, T, U>(Option<&'t [T]>, &'t str, U);
+/// The type returned from [`FmtOrSlice::fmt_debug_brackets_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatDebugBrackets<'t, T, U>(Option<&'t [T]>, Brackets, U);
+/// The type returned from [`FmtOrSlice::fmt_bool_row_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatBoolRow<'t, T, U>(Option<&'t [T]>, &'t str, U);
+/// The type returned from [`fmt_first_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatFirst<'t, T, U>(&'t [Option<T>], U);
+/// The type returned from [`fmt_join`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatJoin<'t, I>(I, &'t str);
+/// The type returned from [`fmt_slice_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatSlice<'t, T, U>(&'t Option<&'t [T]>, &'t str, U);
+
+/// An extension trait for [`Option<S>`] where `S` is any slice-like type, such as `&[T]`, `[T; N]` or `Vec<T>`.
+///
+/// Unless noted otherwise, the wrappers returned from these methods implement [`Display`] only. Like [`FmtOr::fmt_or`],
+/// the fallback is formatted with the full format spec, while the elements of a present slice
+/// are rendered as a single piece of text which is then padded to the requested width.
+///
+/// [`FmtOr::fmt_or`]: crate::FmtOr::fmt_or
+#[allow(clippy::needless_lifetimes)] // They're nice to see in docs
+pub trait FmtOrSlice<T>: sealed::Sealed {
+    /// Format the elements with consecutive equal elements compressed into runs, or display the
+    /// given value instead.
+    ///
+    /// Each run is shown as its element followed by ` ×n` when it repeats, and runs are
+    /// separated by `sep`. The fallback is also used for an empty slice.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrSlice;
+    ///
+    /// let cells = Some(["a", "a", "b", "c", "c", "c"]);
+    ///
+    /// assert_eq!("a ×2, b, c ×3", format!("{}", cells.fmt_runs_or(", ", "none")));
+    /// ```
+    fn fmt_runs_or<'t, U>(&'t self, sep: &'t str, fallback: U) -> MaybeFormatRuns<'t, T, U>
+    where
+        T: Display + PartialEq,
+        U: Display;
+    /// Format the elements as a [`Debug`] list inside the chosen [`Brackets`], or display the
+    /// given value instead.
+    ///
+    /// Unlike the other wrappers in this trait, the returned wrapper implements [`Debug`] rather
+    /// than [`Display`], and the elements are formatted with `{:?}`, or `{:#?}` when the
+    /// alternate flag is given. The fallback is still rendered with [`Display`], like
+    /// [`FmtOr::fmt_or`]. An empty slice is shown as empty brackets.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::{Brackets, FmtOrSlice};
+    ///
+    /// let names = Some(["a", "b"]);
+    ///
+    /// assert_eq!(r#"{"a", "b"}"#, format!("{:?}", names.fmt_debug_brackets_or(Brackets::Curly, "-")));
+    /// assert_eq!(r#"<"a", "b">"#, format!("{:?}", names.fmt_debug_brackets_or(Brackets::Angle, "-")));
+    /// ```
+    ///
+    /// [`FmtOr::fmt_or`]: crate::FmtOr::fmt_or
+    fn fmt_debug_brackets_or<'t, U>(
+        &'t self,
+        style: Brackets,
+        fallback: U,
+    ) -> MaybeFormatDebugBrackets<'t, T, U>
+    where
+        T: Debug,
+        U: Display;
+    /// Format the booleans as a row of `✓` and `✗` glyphs separated by `sep`, or display the
+    /// given value instead.
+    ///
+    /// The fallback is also used for an empty slice.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrSlice;
+    ///
+    /// let support = Some([true, false, true]);
+    ///
+    /// assert_eq!("✓ ✗ ✓", format!("{}", support.fmt_bool_row_or(" ", "n/a")));
+    /// ```
+    fn fmt_bool_row_or<'t, U>(&'t self, sep: &'t str, fallback: U) -> MaybeFormatBoolRow<'t, T, U>
+    where
+        T: Copy + Into<bool>,
+        U: Display;
+}
+
+impl<S> sealed::Sealed for Option<S> {}
+
+impl<T, S: AsRef<[T]>> FmtOrSlice<T> for Option<S> {
+    #[inline]
+    fn fmt_runs_or<'t, U>(&'t self, sep: &'t str, fallback: U) -> MaybeFormatRuns<'t, T, U>
+    where
+        T: Display + PartialEq,
+        U: Display,
+    {
+        MaybeFormatRuns(as_slice(self), sep, fallback)
+    }
+    #[inline]
+    fn fmt_debug_brackets_or<'t, U>(
+        &'t self,
+        style: Brackets,
+        fallback: U,
+    ) -> MaybeFormatDebugBrackets<'t, T, U>
+    where
+        T: Debug,
+        U: Display,
+    {
+        MaybeFormatDebugBrackets(as_slice(self), style, fallback)
+    }
+    #[inline]
+    fn fmt_bool_row_or<'t, U>(&'t self, sep: &'t str, fallback: U) -> MaybeFormatBoolRow<'t, T, U>
+    where
+        T: Copy + Into<bool>,
+        U: Display,
+    {
+        MaybeFormatBoolRow(as_slice(self), sep, fallback)
+    }
+}
+
+#[inline]
+fn as_slice<T, S: AsRef<[T]>>(opt: &Option<S>) -> Option<&[T]> {
+    opt.as_ref().map(AsRef::as_ref)
+}
+
+impl<'t, T, U> Display for MaybeFormatRuns<'t, T, U>
+where
+    T: Display + PartialEq,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        let slice = match self.0 {
+            Some(slice) if !slice.is_empty() => slice,
+            _ => return Display::fmt(&self.2, out),
+        };
+        pad(out, Alignment::Left, |w: &mut dyn Write| {
+            let mut rest = slice;
+            let mut first = true;
+            while let Some(head) = rest.first() {
+                let run = rest.iter().take_while(|t| *t == head).count();
+                if !first {
+                    w.write_str(self.1)?;
+                }
+                first = false;
+                write!(w, "{}", head)?;
+                if run > 1 {
+                    write!(w, " ×{}", run)?;
+                }
+                rest = &rest[run..];
+            }
+            Ok(())
+        })
+    }
+}
+
+/// The bracket characters used by [`FmtOrSlice::fmt_debug_brackets_or`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Brackets {
+    /// `[` and `]`
+    Square,
+    /// `{` and `}`
+    Curly,
+    /// `<` and `>`
+    Angle,
+    /// `(` and `)`
+    Round,
+}
+
+impl Brackets {
+    /// Returns the opening and closing characters.
+    pub fn chars(self) -> (char, char) {
+        match self {
+            Brackets::Square => ('[', ']'),
+            Brackets::Curly => ('{', '}'),
+            Brackets::Angle => ('<', '>'),
+            Brackets::Round => ('(', ')'),
+        }
+    }
+}
+
+impl<'t, T, U> Debug for MaybeFormatDebugBrackets<'t, T, U>
+where
+    T: Debug,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        let slice = match self.0 {
+            Some(slice) => slice,
+            None => return Display::fmt(&self.2, out),
+        };
+        let (open, close) = self.1.chars();
+        let alternate = out.alternate();
+        pad(out, Alignment::Left, |w: &mut dyn Write| {
+            w.write_char(open)?;
+            for (i, t) in slice.iter().enumerate() {
+                if i > 0 {
+                    w.write_str(", ")?;
+                }
+                if alternate {
+                    write!(w, "{:#?}", t)?;
+                } else {
+                    write!(w, "{:?}", t)?;
+                }
+            }
+            w.write_char(close)
+        })
+    }
+}
+
+impl<'t, T, U> Display for MaybeFormatBoolRow<'t, T, U>
+where
+    T: Copy + Into<bool>,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        let slice = match self.0 {
+            Some(slice) if !slice.is_empty() => slice,
+            _ => return Display::fmt(&self.2, out),
+        };
+        pad(out, Alignment::Left, |w: &mut dyn Write| {
+            for (i, &b) in slice.iter().enumerate() {
+                if i > 0 {
+                    w.write_str(self.1)?;
+                }
+                w.write_char(if b.into() { '✓' } else { '✗' })?;
+            }
+            Ok(())
+        })
+    }
+}
+
+/// Format the first present value out of several candidates, or display the given value if they
+/// are all missing.
+///
+/// The slice is only scanned when the wrapper is formatted, and nothing is allocated. An empty
+/// slice behaves like one where every value is missing. Like [`FmtOr::fmt_or`], the returned
+/// wrapper implements all of the formatting traits the values do, and the fallback is formatted
+/// with the full format spec.
+///
+/// # Example
+///
+/// ```rust
+/// let sources = [None, Some(5), Some(9)];
+///
+/// assert_eq!("5", format!("{}", fmtor::fmt_first_or(&sources, "unset")));
+/// assert_eq!("unset", format!("{:x}", fmtor::fmt_first_or(&[None::<u8>; 2], "unset")));
+/// ```
+///
+/// [`FmtOr::fmt_or`]: crate::FmtOr::fmt_or
+pub fn fmt_first_or<'t, T, U>(opts: &'t [Option<T>], fallback: U) -> MaybeFormatFirst<'t, T, U>
+where
+    U: Display,
+{
+    MaybeFormatFirst(opts, fallback)
+}
+
+/// Format the present values out of several options, separated by `sep`.
+///
+/// Missing values are skipped, and `sep` is only written between two values that are written, so
+/// there is never a leading or trailing separator. If every value is missing, nothing is written.
+/// The options are iterated when the wrapper is formatted, so `opts` must be [`Clone`], as slices
+/// and most iterators over borrowed options are. Each value is formatted with the full format spec.
+///
+/// # Example
+///
+/// ```rust
+/// let tags = [Some("red"), None, Some("blue")];
+///
+/// assert_eq!("red, blue", format!("{}", fmtor::fmt_join(&tags, ", ")));
+/// assert_eq!("0x1|0x3", format!("{:#x}", fmtor::fmt_join(&[Some(1), None, Some(3)], "|")));
+/// ```
+pub fn fmt_join<'t, I>(opts: I, sep: &'t str) -> MaybeFormatJoin<'t, I>
+where
+    I: IntoIterator + Clone,
+{
+    MaybeFormatJoin(opts, sep)
+}
+
+/// Format the elements of a slice separated by `sep`, or display the given value if there is no
+/// slice.
+///
+/// Each element is formatted with whichever trait and flags are used, like [`fmt_join`], and the
+/// fallback is rendered with [`Display`], like [`FmtOr::fmt_or`]. An empty slice writes nothing,
+/// rather than the fallback, so it stays distinct from a missing one.
+///
+/// # Example
+///
+/// ```rust
+/// let ids = Some(&[10, 11, 12][..]);
+///
+/// assert_eq!("a, b, c", format!("{:x}", fmtor::fmt_slice_or(&ids, ", ", "none")));
+/// assert_eq!("none", format!("{:x}", fmtor::fmt_slice_or(&None::<&[u8]>, ", ", "none")));
+/// assert_eq!("", format!("{:x}", fmtor::fmt_slice_or(&Some(&[] as &[u8]), ", ", "none")));
+/// ```
+///
+/// [`FmtOr::fmt_or`]: crate::FmtOr::fmt_or
+pub fn fmt_slice_or<'t, T, U>(
+    opt: &'t Option<&'t [T]>,
+    sep: &'t str,
+    fallback: U,
+) -> MaybeFormatSlice<'t, T, U>
+where
+    U: Display,
+{
+    MaybeFormatSlice(opt, sep, fallback)
+}
+
+impl<'t, T, U: Copy> Copy for MaybeFormatSlice<'t, T, U> {}
+impl<'t, T, U: Clone> Clone for MaybeFormatSlice<'t, T, U> {
+    fn clone(&self) -> Self {
+        Self(self.0, self.1, self.2.clone())
+    }
+}
+
+impl<'t, I: Copy> Copy for MaybeFormatJoin<'t, I> {}
+impl<'t, I: Clone> Clone for MaybeFormatJoin<'t, I> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), self.1)
+    }
+}
+
+macro_rules! impl_fmt_traits {
+    ($($Trait:ident),*$(,)?) => {$(
+
+impl<'t, T, U> $Trait for MaybeFormatFirst<'t, T, U>
+where
+    T: $Trait,
+    U: Display,
+{
+    #[inline]
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0.iter().find_map(Option::as_ref) {
+            Some(t) => <T as $Trait>::fmt(t, out),
+            None => Display::fmt(&self.1, out),
+        }
+    }
+}
+
+impl<'t, 'o, I, T> $Trait for MaybeFormatJoin<'t, I>
+where
+    I: IntoIterator<Item = &'o Option<T>> + Clone,
+    T: $Trait + 'o,
+{
+    #[inline]
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        let mut present = self.0.clone().into_iter().flatten();
+        if let Some(first) = present.next() {
+            <T as $Trait>::fmt(first, out)?;
+        }
+        for t in present {
+            out.write_str(self.1)?;
+            <T as $Trait>::fmt(t, out)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'t, T, U> $Trait for MaybeFormatSlice<'t, T, U>
+where
+    T: $Trait,
+    U: Display,
+{
+    #[inline]
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        let slice = match self.0 {
+            Some(slice) => slice,
+            None => return Display::fmt(&self.2, out),
+        };
+        for (i, t) in slice.iter().enumerate() {
+            if i > 0 {
+                out.write_str(self.1)?;
+            }
+            <T as $Trait>::fmt(t, out)?;
+        }
+        Ok(())
+    }
+}
+
+    )*}
+} // macro_rules! impl_fmt_traits
+
+impl_fmt_traits!(Binary, Debug, Display, LowerExp, LowerHex, Octal, Pointer, UpperExp, UpperHex);
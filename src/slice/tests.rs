@@ -0,0 +1,143 @@
+use crate::{fmt_first_or, fmt_join, fmt_slice_or, Brackets, FmtOrSlice};
+
+#[test]
+fn test_runs() {
+    let runs = Some(&[1, 1, 2, 3, 3, 3, 1][..]);
+    assert_eq!(
+        "1 ×2, 2, 3 ×3, 1",
+        format!("{}", runs.fmt_runs_or(", ", "none"))
+    );
+    let single = Some(vec!["x"; 4]);
+    assert_eq!("x ×4", format!("{}", single.fmt_runs_or(", ", "none")));
+    let distinct = Some(["a", "b"]);
+    assert_eq!("a|b", format!("{}", distinct.fmt_runs_or("|", "none")));
+}
+
+#[test]
+fn test_runs_fallback() {
+    let empty: Option<&[u8]> = Some(&[]);
+    let missing: Option<&[u8]> = None;
+    assert_eq!("none", format!("{}", empty.fmt_runs_or(", ", "none")));
+    assert_eq!("none", format!("{}", missing.fmt_runs_or(", ", "none")));
+    assert_eq!(
+        "  none",
+        format!("{:>6}", missing.fmt_runs_or(", ", "none"))
+    );
+}
+
+#[test]
+fn test_debug_brackets() {
+    let list = Some(&["x", "y"][..]);
+    assert_eq!(
+        r#"["x", "y"]"#,
+        format!("{:?}", list.fmt_debug_brackets_or(Brackets::Square, "none"))
+    );
+    assert_eq!(
+        r#"("x", "y")"#,
+        format!("{:?}", list.fmt_debug_brackets_or(Brackets::Round, "none"))
+    );
+    let nested = Some([Some(1), None]);
+    assert_eq!(
+        "<Some(1), None>",
+        format!(
+            "{:?}",
+            nested.fmt_debug_brackets_or(Brackets::Angle, "none")
+        )
+    );
+    assert_eq!(
+        "{Some(\n    1,\n), None}",
+        format!(
+            "{:#?}",
+            nested.fmt_debug_brackets_or(Brackets::Curly, "none")
+        )
+    );
+    let empty: Option<Vec<u8>> = Some(Vec::new());
+    assert_eq!(
+        "{}",
+        format!("{:?}", empty.fmt_debug_brackets_or(Brackets::Curly, "none"))
+    );
+    let missing: Option<&[u8]> = None;
+    assert_eq!(
+        "none",
+        format!(
+            "{:?}",
+            missing.fmt_debug_brackets_or(Brackets::Curly, "none")
+        )
+    );
+}
+
+#[test]
+fn test_bool_row() {
+    let row = Some(&[true, false, false, true][..]);
+    assert_eq!("✓|✗|✗|✓", format!("{}", row.fmt_bool_row_or("|", "n/a")));
+    assert_eq!("✓✗✗✓", format!("{}", row.fmt_bool_row_or("", "n/a")));
+    assert_eq!(
+        "✓ ✗ ✗ ✓  ",
+        format!("{:9}", row.fmt_bool_row_or(" ", "n/a"))
+    );
+    let empty: Option<Vec<bool>> = Some(Vec::new());
+    assert_eq!("n/a", format!("{}", empty.fmt_bool_row_or("|", "n/a")));
+    let missing: Option<&[bool]> = None;
+    assert_eq!(
+        "  n/a",
+        format!("{:>5}", missing.fmt_bool_row_or("|", "n/a"))
+    );
+}
+
+#[test]
+fn test_first() {
+    let opts = [None, Some(5), Some(9)];
+    assert_eq!("5", format!("{:x}", fmt_first_or(&opts, "-")));
+    assert_eq!("0x0005", format!("{:#06x}", fmt_first_or(&opts, "-")));
+    assert_eq!("101", format!("{:b}", fmt_first_or(&opts, "-")));
+    assert_eq!(
+        "Some(9)",
+        format!("{:?}", fmt_first_or(&[None, Some(Some(9))], "-"))
+    );
+    assert_eq!("-", format!("{}", fmt_first_or(&[None::<u8>, None], "-")));
+    assert_eq!(
+        "  -",
+        format!("{:>3x}", fmt_first_or(&[] as &[Option<u8>], "-"))
+    );
+}
+
+#[test]
+fn test_join() {
+    let opts = [Some(1), None, Some(3)];
+    assert_eq!("1, 3", format!("{}", fmt_join(&opts, ", ")));
+    assert_eq!(
+        "a, c",
+        format!("{:x}", fmt_join(&[Some(10), None, Some(12)], ", "))
+    );
+    assert_eq!("01, 03", format!("{:02}", fmt_join(&opts, ", ")));
+    assert_eq!("", format!("{}", fmt_join(&[None::<u8>, None], ", ")));
+    assert_eq!("", format!("{}", fmt_join(&[] as &[Option<u8>], ", ")));
+    assert_eq!("3", format!("{}", fmt_join(&[None, Some(3)], ", ")));
+
+    let list = [None, Some("x"), Some("y")];
+    assert_eq!("x/y", format!("{}", fmt_join(list.iter(), "/")));
+    assert_eq!("y", format!("{}", fmt_join(list.iter().skip(2), "/")));
+}
+
+#[test]
+fn test_slice_or() {
+    let some = Some(&[1, 2, 3][..]);
+    assert_eq!("1, 2, 3", format!("{:x}", fmt_slice_or(&some, ", ", "-")));
+    assert_eq!(
+        "0x1|0x2|0x3",
+        format!("{:#x}", fmt_slice_or(&some, "|", "-"))
+    );
+    assert_eq!("01 02 03", format!("{:02}", fmt_slice_or(&some, " ", "-")));
+    let big = Some(&[255u8, 16][..]);
+    assert_eq!("ff, 10", format!("{:x}", fmt_slice_or(&big, ", ", "-")));
+    assert_eq!(
+        "-",
+        format!("{:x}", fmt_slice_or(&None::<&[u8]>, ", ", "-"))
+    );
+    assert_eq!(
+        "  -",
+        format!("{:>3}", fmt_slice_or(&None::<&[u8]>, ", ", "-"))
+    );
+    let empty = Some(&[] as &[u8]);
+    assert_eq!("", format!("{}", fmt_slice_or(&empty, ", ", "-")));
+}
@@ -3,7 +3,7 @@ use std::fmt::{
     UpperHex,
 };
 
-use crate::FmtOr;
+use crate::{FmtOr, MaybeFormat, MaybeFormatOr, MaybeFormatOrElse, Null, NullUpper, Resolved};
 
 struct Baz;
 impl Display for Baz {
@@ -112,3 +112,1437 @@ tests!(
         0x42 as *mut Baz => "0x42",
     }
 );
+
+/// A fallback which is only ever built through `Default`.
+#[derive(Default)]
+struct Placeholder;
+impl Display for Placeholder {
+    fn fmt(&self, out: &mut Formatter) -> Result {
+        Display::fmt("<none>", out)
+    }
+}
+
+#[test]
+fn test_fmt_or_default_display() {
+    let some = Some(10);
+    let none = None::<u32>;
+    assert_eq!(
+        "10",
+        format!("{}", some.fmt_or_default_display::<Placeholder>())
+    );
+    assert_eq!(
+        "a",
+        format!("{:x}", some.fmt_or_default_display::<Placeholder>())
+    );
+    assert_eq!(
+        "0b1010",
+        format!("{:#b}", some.fmt_or_default_display::<String>())
+    );
+    assert_eq!(
+        "<none>",
+        format!("{}", none.fmt_or_default_display::<Placeholder>())
+    );
+    assert_eq!(
+        "<none>",
+        format!("{:X}", none.fmt_or_default_display::<Placeholder>())
+    );
+    assert_eq!(
+        "<none>  ",
+        format!("{:8e}", none.fmt_or_default_display::<Placeholder>())
+    );
+    assert_eq!("", format!("{:o}", none.fmt_or_default_display::<String>()));
+    let missing: Option<&u32> = None;
+    assert_eq!(
+        "<none>",
+        format!("{:p}", missing.fmt_or_default_display::<Placeholder>())
+    );
+}
+
+#[test]
+fn test_typed_none() {
+    let missing = format!("{}", None::<u32>.fmt_or_typed_none());
+    assert_eq!("<missing u32>", missing);
+    let missing = format!("{:x}", None::<u16>.fmt_or_typed_none());
+    assert!(missing.starts_with("<missing ") && missing.contains("u16"));
+    assert_eq!(
+        "<missing u8>    ",
+        format!("{:16}", None::<u8>.fmt_or_typed_none())
+    );
+}
+
+#[test]
+fn test_spec_passthrough() {
+    let some = Some(1.5);
+    let none = None::<f64>;
+    assert_eq!(
+        format!("{:+08.2}", 1.5),
+        format!("{:+08.2}", some.fmt_or(""))
+    );
+    assert_eq!(
+        format!("{:+08.2}", 1.5),
+        format!("{:+08.2}", some.fmt_or_empty())
+    );
+    assert_eq!("   ", format!("{:3}", none.fmt_or_empty()));
+    assert_eq!("  x", format!("{:>3}", none.fmt_or("x")));
+    assert_eq!(
+        format!("{:>3}", none.fmt_or("x")),
+        format!("{:>3}", none.fmt_or_else(|| "x"))
+    );
+}
+
+macro_rules! default_tests {
+    ($(
+        $test_name:ident: $Trait:ident ($fmt_str:expr) { $(
+            $value:expr => $none:expr
+        ),*$(,)? }
+    )*) => { $(
+        #[test]
+        fn $test_name() {
+            fn test_inner<T: $Trait + Default>(value: T, none: &'static str) {
+                let some = Some(value);
+                let want = format!($fmt_str, some.as_ref().unwrap());
+                assert_eq!(want, format!($fmt_str, some.fmt_or_default()));
+                assert_eq!(none, format!($fmt_str, None::<T>.fmt_or_default()));
+            }
+
+            $(
+                test_inner($value, $none);
+            )*
+        }
+    )*};
+}
+
+default_tests!(
+    test_default_display: Display("{:>4}") {
+        7 => "   0",
+        String::from("x") => "    ",
+    }
+    test_default_debug: Debug("{:?}") {
+        7 => "0",
+        Some(7) => "None",
+    }
+    test_default_binary: Binary("{:#010b}") {
+        7u8 => "0b00000000",
+    }
+    test_default_octal: Octal("{:#o}") {
+        42 => "0o0",
+    }
+    test_default_upperhex: UpperHex("{:#X}") {
+        255 => "0x0",
+    }
+    test_default_lowerhex: LowerHex("{:04x}") {
+        255u16 => "0000",
+    }
+    test_default_upperexp: UpperExp("{:.2E}") {
+        1500.0 => "0.00E0",
+    }
+    test_default_lowerexp: LowerExp("{:e}") {
+        10 => "0e0",
+    }
+);
+
+#[test]
+fn test_same() {
+    let some = Some(0x2au32);
+    let none = None::<u32>;
+    for fmt in &[
+        |o: &Option<u32>, u| format!("{:X}", o.fmt_or_same(u)),
+        |o: &Option<u32>, u| format!("{:#010x}", o.fmt_or_same(u)),
+        |o: &Option<u32>, u| format!("{:>8b}", o.fmt_or_same(u)),
+        |o: &Option<u32>, u| format!("{:+^9}", o.fmt_or_same(u)),
+        |o: &Option<u32>, u| format!("{:#o}", o.fmt_or_same(u)),
+    ] {
+        assert_eq!(fmt(&some, 0), fmt(&none, 0x2a));
+    }
+    assert_eq!("FF", format!("{:X}", none.fmt_or_same(0xFF)));
+    assert_eq!("0x000000ff", format!("{:#010x}", none.fmt_or_same(0xFF)));
+
+    let some = Some(1.5f64);
+    let none = None::<f64>;
+    assert_eq!(
+        format!("{:>10.3}", some.fmt_or_same(0.0)),
+        format!("{:>10.3}", none.fmt_or_same(1.5))
+    );
+    assert_eq!(
+        format!("{:+.2e}", some.fmt_or_same(0.0)),
+        format!("{:+.2e}", none.fmt_or_same(1.5))
+    );
+    assert_eq!("Some(1)", format!("{:?}", None.fmt_or_same(Some(1))));
+}
+
+#[test]
+fn test_null() {
+    assert_eq!("null", format!("{:p}", None::<&u32>.fmt_or(Null)));
+    assert_eq!("NULL", format!("{:p}", None::<&u32>.fmt_or(NullUpper)));
+    assert_eq!("null  ", format!("{:6x}", None::<u32>.fmt_or(Null)));
+    assert_eq!("  NULL", format!("{:>6?}", None::<u32>.fmt_or(NullUpper)));
+    assert_eq!("7", format!("{}", Some(7).fmt_or(Null)));
+    assert_eq!(
+        "null",
+        format!("{}", None::<u8>.fmt_or_default_display::<Null>())
+    );
+}
+
+#[test]
+fn test_or_else_cached() {
+    use std::cell::Cell;
+
+    let calls = Cell::new(0u32);
+    let count = || {
+        calls.set(calls.get() + 1);
+        "missing"
+    };
+    let none = None::<u32>;
+    let wrapper = none.fmt_or_else_cached(count);
+    assert_eq!("   missing", format!("{:>10}", wrapper));
+    assert_eq!(1, calls.get());
+    assert_eq!("missing", format!("{:x}", wrapper));
+    assert_eq!(1, calls.get());
+
+    let some = Some(0xau32);
+    let wrapper = some.fmt_or_else_cached(count);
+    assert_eq!("         a", format!("{:>10x}", wrapper));
+    assert_eq!(1, calls.get());
+
+    let owned = String::from("moved");
+    assert_eq!(
+        "moved",
+        format!("{:?}", none.fmt_or_else_cached(move || owned))
+    );
+}
+
+#[test]
+fn test_inspect() {
+    let some = Some("x");
+    let none = None::<u8>;
+    assert_eq!(
+        "MaybeFormat(Some(\"x\"))",
+        format!("{:?}", some.fmt_or_empty().inspect())
+    );
+    assert_eq!(
+        "MaybeFormat(None)",
+        format!("{:?}", none.fmt_or_empty().inspect())
+    );
+    assert_eq!(
+        "MaybeFormatOr(None, 1.5)",
+        format!("{:?}", none.fmt_or(1.5).inspect())
+    );
+    assert_eq!(
+        "MaybeFormatOrElse(None, \"<closure>\")",
+        format!("{:?}", none.fmt_or_else(|| "-").inspect())
+    );
+    assert_eq!(
+        "MaybeFormatOr(\n    Some(\n        \"x\",\n    ),\n    \"-\",\n)",
+        format!("{:#?}", some.fmt_or("-").inspect())
+    );
+
+    // The wrappers themselves still forward `Debug` to the value.
+    assert_eq!("\"x\"", format!("{:?}", some.fmt_or("-")));
+    assert_eq!("-", format!("{:?}", none.fmt_or_else(|| "-")));
+}
+
+struct Missing;
+
+macro_rules! missing {
+    ($($Trait:ident => $text:expr),*$(,)?) => {$(
+        impl $Trait for Missing {
+            fn fmt(&self, out: &mut Formatter) -> Result {
+                out.pad($text)
+            }
+        }
+    )*};
+}
+
+missing!(
+    Binary => "-b",
+    Debug => "-?",
+    Display => "-",
+    LowerExp => "-e",
+    LowerHex => "-x",
+    Octal => "-o",
+    Pointer => "-p",
+    UpperExp => "-E",
+    UpperHex => "-X",
+);
+
+#[test]
+fn test_dyn_all() {
+    let fallback: &dyn crate::AllFmt = &Missing;
+    let none = None::<u32>;
+    assert_eq!("-", format!("{}", none.fmt_or_dyn_all(fallback)));
+    assert_eq!("-x", format!("{:x}", none.fmt_or_dyn_all(fallback)));
+    assert_eq!("-b", format!("{:b}", none.fmt_or_dyn_all(fallback)));
+    assert_eq!("  -E", format!("{:>4E}", none.fmt_or_dyn_all(fallback)));
+    assert_eq!("-p", format!("{:p}", None::<&u8>.fmt_or_dyn_all(fallback)));
+
+    let some = Some(10u32);
+    assert_eq!("10", format!("{}", some.fmt_or_dyn_all(fallback)));
+    assert_eq!("a", format!("{:x}", some.fmt_or_dyn_all(fallback)));
+    assert_eq!("1010", format!("{:b}", some.fmt_or_dyn_all(fallback)));
+}
+
+#[test]
+fn test_or_with() {
+    let write_width = |out: &mut Formatter| {
+        out.write_str("<")?;
+        match out.width() {
+            Some(width) => write!(out, "{}", width)?,
+            None => out.write_str("none")?,
+        }
+        out.write_str(">")
+    };
+    let none = None::<u32>;
+    assert_eq!("<8>", format!("{:8}", none.fmt_or_with(write_width)));
+    assert_eq!("<12>", format!("{:>12x}", none.fmt_or_with(write_width)));
+    assert_eq!("<none>", format!("{:b}", none.fmt_or_with(write_width)));
+    assert_eq!(
+        "       a",
+        format!("{:8x}", Some(10).fmt_or_with(write_width))
+    );
+
+    let alternate = |out: &mut Formatter| out.write_str(if out.alternate() { "#" } else { "-" });
+    assert_eq!("#", format!("{:#x}", none.fmt_or_with(alternate)));
+    assert_eq!("-", format!("{:x}", none.fmt_or_with(alternate)));
+}
+
+#[test]
+fn test_or_num_zero_pad() {
+    // Regression: missing rows of a numeric column must line up with present ones.
+    assert_eq!(
+        format!("{:08}", Some(5).fmt_or_num(0)),
+        format!("{:08}", None::<i32>.fmt_or_num(5))
+    );
+    assert_eq!("00000005", format!("{:08}", None::<i32>.fmt_or_num(5)));
+    assert_eq!(
+        format!("{:+}", Some(5).fmt_or_num(0)),
+        format!("{:+}", None::<i32>.fmt_or_num(5))
+    );
+    // `fmt_or` displays the fallback, so a string fallback ignores the zero flag.
+    assert_eq!("5       ", format!("{:08}", None::<i32>.fmt_or("5")));
+    assert_eq!("10", format!("{:x}", None::<i32>.fmt_or(10)));
+    assert_eq!("a", format!("{:x}", None::<i32>.fmt_or_num(10)));
+}
+
+#[test]
+fn test_map() {
+    use std::cell::Cell;
+
+    struct Point {
+        x: u32,
+    }
+
+    let some = Some(Point { x: 3 });
+    let none = None::<Point>;
+    assert_eq!("3", format!("{:x}", some.fmt_map(|p| p.x)));
+    assert_eq!("03", format!("{:02x}", some.fmt_map(|p| p.x)));
+    assert_eq!("", format!("{:x}", none.fmt_map(|p| p.x)));
+    assert_eq!("-", format!("{:x}", none.fmt_map(|p| p.x).or("-")));
+    assert_eq!("3", format!("{:x}", some.fmt_map(|p| p.x).or("-")));
+    assert_eq!("?", format!("{:x}", none.fmt_map(|p| p.x).or_else(|| "?")));
+
+    let calls = Cell::new(0);
+    let wrapper = some.fmt_map(|p| {
+        calls.set(calls.get() + 1);
+        p.x * 2
+    });
+    assert_eq!(0, calls.get());
+    assert_eq!("6", format!("{}", wrapper));
+    assert_eq!(1, calls.get());
+}
+
+#[test]
+fn test_inner() {
+    let some = Some(5);
+    let none = None::<u32>;
+    assert_eq!(&some, some.fmt_or_empty().inner());
+    assert!(std::ptr::eq(&some, some.fmt_or("-").inner()));
+    assert_eq!(&none, none.fmt_or("-").inner());
+    assert_eq!(&"-", none.fmt_or("-").fallback());
+    assert_eq!(&some, some.fmt_or_else(|| "-").inner());
+
+    // The option outlives the wrapper it was read back from.
+    let inner = {
+        let wrapper = some.fmt_or("-");
+        wrapper.inner()
+    };
+    assert_eq!(Some(5), *inner);
+}
+
+#[test]
+fn test_wrap() {
+    let some = Some(5);
+    let none = None::<u32>;
+    assert_eq!("(5)", format!("{}", some.fmt_wrap("(", ")")));
+    assert_eq!("", format!("{}", none.fmt_wrap("(", ")")));
+    assert_eq!("", format!("{:>8}", none.fmt_wrap("(", ")")));
+    assert_eq!("(   5)", format!("{:>4}", some.fmt_wrap("(", ")")));
+    assert_eq!("[0b101]", format!("{:#b}", some.fmt_wrap("[", "]")));
+    assert_eq!("[x=7]", format!("[{}]", Some(7).fmt_wrap("x=", "")));
+}
+
+#[test]
+fn test_present() {
+    assert_eq!(" - \"x\"", format!("{:?}", Some("x").fmt_present(" - ")));
+    assert_eq!("", format!("{:?}", None::<&str>.fmt_present(" - ")));
+    assert_eq!("", format!("{:>6}", None::<&str>.fmt_present(" - ")));
+    assert_eq!(": 0x1f", format!("{:#x}", Some(31).fmt_present(": ")));
+}
+
+/// A sink which is not a `String`, like a serial port.
+#[derive(Default)]
+struct Capture(String);
+
+impl std::fmt::Write for Capture {
+    fn write_str(&mut self, s: &str) -> Result {
+        self.0.push_str(s);
+        Ok(())
+    }
+}
+
+#[test]
+fn test_write_or() {
+    let mut out = Capture::default();
+    Some(7).write_or(&mut out, "-").unwrap();
+    None::<u32>.write_or(&mut out, "-").unwrap();
+    Some("x").write_or(&mut out, "-").unwrap();
+    assert_eq!("7-x", out.0);
+
+    let mut out = Capture::default();
+    None::<u32>
+        .write_or_fmt(&mut out, format_args!("<{} missing>", 2))
+        .unwrap();
+    Some(1.5)
+        .write_or_fmt(&mut out, format_args!("{}", "unused"))
+        .unwrap();
+    assert_eq!("<2 missing>1.5", out.0);
+
+    let dyn_out: &mut dyn std::fmt::Write = &mut Capture::default();
+    None::<u8>.write_or(dyn_out, "dyn").unwrap();
+}
+
+#[test]
+fn test_or_error() {
+    use std::fmt::Write;
+
+    let mut out = Capture::default();
+    let results = [
+        write!(out, "{:x},", Some(255).fmt_or_error()),
+        write!(out, "{:x},", None::<u32>.fmt_or_error()),
+        write!(out, "{:>4}", Some(1).fmt_or_error()),
+    ];
+    assert_eq!([Ok(()), Err(std::fmt::Error), Ok(())], results);
+    // Everything up to the missing value was written.
+    assert_eq!("ff,   1", out.0);
+    assert_eq!(
+        Err(std::fmt::Error),
+        write!(out, "{}", None.fmt_or_error().or_opt(&None::<u8>))
+    );
+    assert_eq!(
+        Ok(()),
+        write!(out, "{}", None.fmt_or_error().or_opt(&Some(2)))
+    );
+}
+
+#[test]
+fn test_or_debug() {
+    #[derive(Debug)]
+    enum Status {
+        Missing,
+    }
+
+    let none = None::<u32>;
+    assert_eq!(
+        "Missing",
+        format!("{:?}", none.fmt_or_debug(Status::Missing))
+    );
+    assert_eq!(
+        "Missing",
+        format!("{:x}", none.fmt_or_debug(Status::Missing))
+    );
+    assert_eq!("\"-\"", format!("{}", none.fmt_or_debug("-")));
+    assert_eq!(
+        "(\n    3,\n    Missing,\n)",
+        format!("{:#?}", none.fmt_or_debug((3, Status::Missing)))
+    );
+    assert_eq!(
+        "Some(1)",
+        format!("{:?}", Some(Some(1)).fmt_or_debug(Status::Missing))
+    );
+    assert_eq!(
+        "0x1",
+        format!("{:#x}", Some(1).fmt_or_debug(Status::Missing))
+    );
+}
+
+#[test]
+fn test_deref() {
+    use std::rc::Rc;
+    use std::sync::{Mutex, RwLock};
+
+    let boxed: Box<Option<u32>> = Box::new(Some(7));
+    assert_eq!("7", format!("{}", boxed.fmt_or("-")));
+    assert_eq!("111", format!("{:b}", boxed.fmt_or_empty()));
+
+    let rc = Rc::new(None::<u32>);
+    assert_eq!("-", format!("{:x}", rc.fmt_or("-")));
+
+    let lock = RwLock::new(Some(10u8));
+    let guard = lock.read().unwrap();
+    assert_eq!("a", format!("{:x}", guard.fmt_or("-")));
+    drop(guard);
+
+    let mutex = Mutex::new(None::<u8>);
+    assert_eq!("n/a", format!("{}", mutex.lock().unwrap().fmt_or("n/a")));
+
+    let by_ref: &Option<u32> = &Some(3);
+    assert_eq!("3", format!("{}", by_ref.fmt_or("-")));
+}
+
+#[test]
+fn test_eq_hash() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::HashSet;
+    use std::hash::{Hash, Hasher};
+
+    fn hash<H: Hash>(h: &H) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        h.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    let (one, also_one, two) = (Some(1), Some(1), Some(2));
+    assert!(one.fmt_or("x") == also_one.fmt_or("x"));
+    assert!(one.fmt_or("x") != one.fmt_or("y"));
+    assert!(one.fmt_or("x") != two.fmt_or("x"));
+    assert_eq!(hash(&one.fmt_or("x")), hash(&also_one.fmt_or("x")));
+    assert!(one.fmt_or_empty() == also_one.fmt_or_empty());
+    assert_eq!(hash(&one.fmt_or_empty()), hash(&also_one.fmt_or_empty()));
+
+    let none = None::<u32>;
+    let keys: HashSet<_> = [one.fmt_or("x"), also_one.fmt_or("x"), none.fmt_or("x")]
+        .iter()
+        .copied()
+        .collect();
+    assert_eq!(2, keys.len());
+}
+
+const TIMEOUT: Option<u32> = Some(30);
+const RETRIES: Option<u32> = None;
+
+const TABLE: [(&str, MaybeFormatOr<'static, u32, &str>); 2] = [
+    ("timeout", MaybeFormatOr::new(&TIMEOUT, "none")),
+    ("retries", MaybeFormatOr::new(&RETRIES, "none")),
+];
+
+fn unlimited() -> &'static str {
+    "unlimited"
+}
+
+#[test]
+fn test_const_new() {
+    let rows: Vec<_> = TABLE
+        .iter()
+        .map(|(key, value)| format!("{}={:x}", key, value))
+        .collect();
+    assert_eq!(["timeout=1e", "retries=none"], rows[..]);
+
+    const EMPTY: MaybeFormat<'static, u32> = MaybeFormat::new(&RETRIES);
+    assert_eq!("", format!("{}", EMPTY));
+
+    const LAZY: MaybeFormatOrElse<'static, u32, fn() -> &'static str> =
+        MaybeFormatOrElse::new(&RETRIES, unlimited);
+    assert_eq!("unlimited", format!("{}", LAZY));
+}
+
+#[test]
+fn test_or_args() {
+    let (line, file) = (12, "main.rs");
+    let none = None::<u32>;
+    assert_eq!(
+        "missing at main.rs:12",
+        format!(
+            "{}",
+            none.fmt_or_args(format_args!("missing at {}:{}", file, line))
+        )
+    );
+    assert_eq!(
+        "missing at main.rs:12",
+        format!(
+            "{:x}",
+            none.fmt_or_args(format_args!("missing at {}:{}", file, line))
+        )
+    );
+    assert_eq!(
+        "ff",
+        format!("{:x}", Some(255).fmt_or_args(format_args!("line {}", line)))
+    );
+    assert_eq!(
+        "<0c>",
+        format!("{}", none.fmt_or_args(format_args!("<{:02x}>", line)))
+    );
+
+    // The wrapper is `Copy` even when the value is not.
+    let missing = None::<String>;
+    let wrapper = missing.fmt_or_args(format_args!("-"));
+    let copy = wrapper;
+    assert_eq!(format!("{}", wrapper), format!("{}", copy));
+}
+
+#[test]
+fn test_option_ref() {
+    let value = 0x2au32;
+    let some = Some(&value);
+    let none = None::<&u32>;
+    assert_eq!("2a", format!("{:x}", some.fmt_or("-")));
+    assert_eq!("0x2A", format!("{:#X}", some.fmt_or("-")));
+    assert_eq!("42", format!("{}", some.fmt_or("-")));
+    assert_eq!(format!("{:p}", &value), format!("{:p}", some.fmt_or("-")));
+    assert_ne!(
+        format!("{:x}", some.fmt_or("-")),
+        format!("{:p}", some.fmt_or("-"))
+    );
+    assert_eq!("-", format!("{:x}", none.fmt_or("-")));
+    assert_eq!("-", format!("{:p}", none.fmt_or("-")));
+
+    // Through two references, `{:p}` shows the address of the inner reference.
+    let by_ref = &value;
+    let nested = Some(&by_ref);
+    assert_eq!(
+        format!("{:p}", &by_ref),
+        format!("{:p}", nested.fmt_or("-"))
+    );
+    assert_eq!("2a", format!("{:x}", nested.fmt_or("-")));
+}
+
+mod macro_hygiene {
+    // No `FmtOr` import here: the macro must not need one.
+    use crate::fmtor;
+
+    #[test]
+    fn test_fmtor_macro() {
+        let some = Some(0x2au32);
+        let none = None::<u32>;
+        assert_eq!("2a", format!("{:x}", fmtor!(some)));
+        assert_eq!("", format!("{:x}", fmtor!(none)));
+        assert_eq!("2a", format!("{:x}", fmtor!(some, "n/a")));
+        assert_eq!("n/a", format!("{:x}", fmtor!(none, "n/a")));
+        assert_eq!("(2a)", format!("{:x}", fmtor!(some => "(", ")")));
+        assert_eq!("", format!("{:x}", fmtor!(none => "(", ")")));
+        assert_eq!("n/a", format!("{}", fmtor!(none, "n/a",)));
+
+        // A temporary option, used within the statement.
+        assert_eq!("7", format!("{}", fmtor!(Some(7), "-")));
+        assert_eq!(":8", format!("{}", fmtor!(none.or(Some(8)) => ":", "")));
+        let args = format!("{}", format_args!("{}", fmtor!(some, "-")));
+        assert_eq!("42", args);
+
+        // Anything which derefs to an option.
+        let by_ref: &Option<u32> = &some;
+        let boxed = Box::new(none);
+        assert_eq!("2a", format!("{:x}", fmtor!(by_ref)));
+        assert_eq!("n/a", format!("{:x}", fmtor!(boxed, "n/a")));
+        assert_eq!("(2a)", format!("{:x}", fmtor!(by_ref => "(", ")")));
+        assert_eq!("", format!("{:x}", fmtor!(boxed)));
+    }
+}
+
+#[test]
+fn test_or_else_once() {
+    let none = None::<u32>;
+    let owned = String::from("moved out");
+    let wrapper = none.fmt_or_else_once(move || owned);
+    assert_eq!("  moved out", format!("{:>11}", wrapper));
+    // The closure is gone, so a second format falls back to nothing.
+    assert_eq!("", format!("{}", wrapper));
+
+    let mut slot = Some(String::from("taken"));
+    assert_eq!(
+        "taken",
+        format!("{:x}", none.fmt_or_else_once(|| slot.take().unwrap()))
+    );
+    assert_eq!(None, slot);
+
+    // A present value never consumes the closure.
+    let some = Some(10u32);
+    let owned = String::from("unused");
+    let wrapper = some.fmt_or_else_once(move || owned);
+    assert_eq!("a", format!("{:x}", wrapper));
+    assert_eq!("10", format!("{}", wrapper));
+}
+
+#[test]
+fn test_empty_padding() {
+    let some = Some(42u32);
+    let none = None::<u32>;
+    assert_eq!("      42", format!("{:>8}", some.fmt_or_empty()));
+    assert_eq!("        ", format!("{:>8}", none.fmt_or_empty()));
+    assert_eq!("42      ", format!("{:<8}", some.fmt_or_empty()));
+    assert_eq!("        ", format!("{:<8}", none.fmt_or_empty()));
+    assert_eq!("   42   ", format!("{:^8}", some.fmt_or_empty()));
+    assert_eq!("        ", format!("{:^8}", none.fmt_or_empty()));
+    assert_eq!("******42", format!("{:*>8}", some.fmt_or_empty()));
+    assert_eq!("********", format!("{:*>8}", none.fmt_or_empty()));
+    for spec in [
+        format!("{:>8}|{:>8}", some.fmt_or_empty(), none.fmt_or_empty()),
+        format!("{:*^8x}|{:*^8x}", some.fmt_or_empty(), none.fmt_or_empty()),
+    ] {
+        let (a, b) = spec.split_once('|').unwrap();
+        assert_eq!(a.chars().count(), b.chars().count());
+    }
+}
+
+#[test]
+fn test_or_char() {
+    let some = Some(0x2au32);
+    let none = None::<u32>;
+    assert_eq!("2a", format!("{:x}", some.fmt_or_char('-')));
+    assert_eq!("-", format!("{:x}", none.fmt_or_char('-')));
+    assert_eq!("-", format!("{:#b}", none.fmt_or_char('-')));
+    assert_eq!("   -", format!("{:>4}", none.fmt_or_char('-')));
+    assert_eq!("·   ", format!("{:4e}", none.fmt_or_char('·')));
+    assert_eq!("*∅*", format!("{:*^3X}", none.fmt_or_char('∅')));
+    assert_eq!(
+        format!("{:>6}", none.fmt_or('-')),
+        format!("{:>6}", none.fmt_or_char('-'))
+    );
+}
+
+#[test]
+fn test_empty_or() {
+    for opt in [Some(0x2au32), None] {
+        assert_eq!(
+            format!("{:>6x}", opt.fmt_or("n/a")),
+            format!("{:>6x}", opt.fmt_or_empty().or("n/a"))
+        );
+        assert_eq!(
+            format!("{:#b}", opt.fmt_or("n/a")),
+            format!("{:#b}", opt.fmt_or_empty().or("n/a"))
+        );
+        assert_eq!(
+            format!("{:X}", opt.fmt_or_else(|| 7)),
+            format!("{:X}", opt.fmt_or_empty().or_else(|| 7))
+        );
+        assert_eq!(
+            format!("{:^7}", opt.fmt_or_else(|| "lazy")),
+            format!("{:^7}", opt.fmt_or_empty().or_else(|| "lazy"))
+        );
+    }
+}
+
+#[test]
+fn test_or_ref() {
+    let fallback = String::from("not set");
+    let none = None::<u32>;
+    let wrapper = none.fmt_or_ref(&fallback);
+    let copy = wrapper;
+    assert_eq!("not set", format!("{:x}", wrapper));
+    assert_eq!("  not set", format!("{:>9}", copy));
+    assert_eq!("2a", format!("{:x}", Some(42).fmt_or_ref(&fallback)));
+    assert_eq!("-", format!("{}", none.fmt_or_ref("-")));
+    assert_eq!("not set", fallback);
+}
+
+#[test]
+fn test_set_or_unset() {
+    struct Password;
+    let password = Some(Password);
+    let missing = None::<Password>;
+    assert_eq!(
+        "set",
+        format!("{:x}", password.fmt_set_or_unset("set", "unset"))
+    );
+    assert_eq!(
+        "set",
+        format!("{:?}", password.fmt_set_or_unset("set", "unset"))
+    );
+    assert_eq!(
+        "unset",
+        format!("{:#b}", missing.fmt_set_or_unset("set", "unset"))
+    );
+    assert_eq!(
+        "  set",
+        format!("{:>5}", password.fmt_set_or_unset("set", "unset"))
+    );
+    assert_eq!(
+        "un",
+        format!("{:.2}", missing.fmt_set_or_unset("set", "unset"))
+    );
+}
+
+#[test]
+fn test_precision() {
+    let some = Some(String::from("abcdef"));
+    let none = None::<String>;
+    assert_eq!("abc", format!("{:.3}", some.fmt_or("n/a")));
+    assert_eq!("n/", format!("{:.2}", none.fmt_or("n/a")));
+    assert_eq!("abc  ", format!("{:5.3}", some.fmt_or_empty()));
+    assert_eq!(
+        "lon",
+        format!("{:.3}", none.fmt_or_else(|| "long fallback"))
+    );
+    assert_eq!("ab", format!("{:.2}", some.fmt_or_else(|| "unused")));
+
+    let ratio = Some(3.14259f64);
+    assert_eq!("3.14", format!("{:.2}", ratio.fmt_or("n/a")));
+    assert_eq!("  3.1", format!("{:5.1}", ratio.fmt_or_empty()));
+    assert_eq!("3.143e0", format!("{:.3e}", ratio.fmt_or("n/a")));
+    assert_eq!("0.000", format!("{:.3}", None::<f64>.fmt_or_num(0.0)));
+}
+
+#[test]
+fn test_wrapper_ref() {
+    fn render<D: core::fmt::Display>(d: D) -> String {
+        format!("{:>4}", d)
+    }
+    let wrapper = &None::<u32>.fmt_or_empty();
+    assert_eq!("", format!("{}", wrapper));
+    assert_eq!("", format!("{:x}", &wrapper));
+    assert_eq!("    ", render(wrapper));
+    let chain = &Some(42).fmt_or("-").or_opt(&None);
+    assert_eq!("  42", render(chain));
+    let cells: [&dyn core::fmt::LowerHex; 2] = [&Some(10).fmt_or("-"), &None::<u8>.fmt_or("-")];
+    assert_eq!("a -", format!("{:x} {:x}", cells[0], cells[1]));
+}
+
+#[test]
+fn test_or_num_sign() {
+    for n in [0i32, 7, -7] {
+        let some = Some(n);
+        let none = None::<i32>;
+        assert_eq!(
+            format!("{:+}", some.fmt_or_num(0)),
+            format!("{:+}", none.fmt_or_num(n))
+        );
+        assert_eq!(
+            format!("{:08}", some.fmt_or_num(0)),
+            format!("{:08}", none.fmt_or_num(n))
+        );
+        assert_eq!(
+            format!("{:+08x}", some.fmt_or_num(0)),
+            format!("{:+08x}", none.fmt_or_num(n))
+        );
+    }
+    assert_eq!("+0000000", format!("{:+08}", None::<i32>.fmt_or_num(0)));
+    assert_eq!("+0", format!("{:+}", None::<i32>.fmt_or_num(0)));
+    assert_eq!("+00000ff", format!("{:+08x}", None::<i32>.fmt_or_num(255)));
+    assert_eq!("-001.50", format!("{:07.2}", None::<f64>.fmt_or_num(-1.5)));
+}
+
+#[test]
+fn test_or_else_ctx() {
+    let none = None::<u32>;
+    let wrapper = none.fmt_or_else_ctx(|f| if f.alternate() { "0x?" } else { "?" });
+    assert_eq!("?", format!("{:x}", wrapper));
+    assert_eq!("0x?", format!("{:#x}", wrapper));
+    assert_eq!("  0x?", format!("{:>#5x}", wrapper));
+    assert_eq!("ff", format!("{:x}", Some(255).fmt_or_else_ctx(|_| "?")));
+
+    let widths = none.fmt_or_else_ctx(|f| f.width().unwrap_or(0));
+    assert_eq!("0", format!("{}", widths));
+    assert_eq!("3  ", format!("{:<3}", widths));
+}
+
+#[test]
+fn test_display_boxed() {
+    use core::fmt::Display;
+
+    let some = Some(0x2au32);
+    let none = None::<u32>;
+    let fallback = String::from("lazy");
+    let cells: [Box<dyn Display + '_>; 4] = [
+        Box::new(some.fmt_or_empty().display()),
+        Box::new(none.fmt_or_empty().display()),
+        Box::new(none.fmt_or(String::from("-")).display()),
+        Box::new(none.fmt_or_else(|| fallback.as_str()).display()),
+    ];
+    let rendered: Vec<_> = cells.iter().map(|c| format!("{:>4}", c)).collect();
+    assert_eq!(["  42", "    ", "   -", "lazy"], rendered[..]);
+}
+
+#[test]
+fn test_is_present() {
+    for opt in [Some(1u8), None] {
+        assert_eq!(opt.is_some(), opt.fmt_or_empty().is_present());
+        assert_eq!(opt.is_none(), opt.fmt_or_empty().is_absent());
+        assert_eq!(opt.is_some(), opt.fmt_or("-").is_present());
+        assert_eq!(opt.is_none(), opt.fmt_or("-").is_absent());
+        assert_eq!(opt.is_some(), opt.fmt_or_else(|| "-").is_present());
+        assert_eq!(opt.is_none(), opt.fmt_or_else(|| "-").is_absent());
+    }
+
+    let port = Some(8080);
+    let host = "localhost";
+    let port = port.fmt_or_empty();
+    let sep = if port.is_present() { ":" } else { "" };
+    assert_eq!("localhost:8080", format!("{}{}{}", host, sep, port));
+}
+
+#[test]
+fn test_with_default() {
+    const MISSING: &str = "-";
+
+    let some = Some(0x2au32);
+    let none = None::<u32>;
+    assert_eq!(
+        "42",
+        format!("{}", some.fmt_or_empty().with_default(MISSING))
+    );
+    assert_eq!(
+        "2a",
+        format!("{:x}", some.fmt_or_empty().with_default(MISSING))
+    );
+    assert_eq!(
+        "-",
+        format!("{}", none.fmt_or_empty().with_default(MISSING))
+    );
+    assert_eq!(
+        "-",
+        format!("{:x}", none.fmt_or_empty().with_default(MISSING))
+    );
+    assert_eq!(
+        "   -",
+        format!("{:>4b}", none.fmt_or_empty().with_default(MISSING))
+    );
+    assert_eq!(
+        "-",
+        none.fmt_or_empty().with_default(MISSING).default_marker()
+    );
+}
+
+#[test]
+fn test_or_debug_pretty() {
+    struct Report<'t>(&'t Option<(u32, bool)>);
+
+    impl std::fmt::Debug for Report<'_> {
+        fn fmt(&self, out: &mut std::fmt::Formatter<'_>) -> Result {
+            out.debug_struct("Report")
+                .field("reason", &self.0.fmt_or_debug((404, false)))
+                .finish()
+        }
+    }
+
+    let present = Some((404, false));
+    let expected = "Report {\n    reason: (\n        404,\n        false,\n    ),\n}";
+    assert_eq!(expected, format!("{:#?}", Report(&present)));
+    assert_eq!(expected, format!("{:#?}", Report(&None)));
+    assert_eq!(
+        format!("{:?}", Report(&present)),
+        format!("{:?}", Report(&None))
+    );
+}
+
+#[test]
+fn test_pointers() {
+    use std::ptr::{self, NonNull};
+
+    let address = format!("{:p}", NonNull::<u64>::dangling());
+    let dangling = Some(NonNull::<u64>::dangling());
+    assert_eq!(address, format!("{:p}", dangling.fmt_or("(null)")));
+    assert_eq!(address, format!("{:p}", dangling.fmt_or_empty()));
+    assert_eq!(
+        "(null)",
+        format!("{:p}", None::<NonNull<u64>>.fmt_or("(null)"))
+    );
+    assert_eq!("", format!("{:p}", None::<NonNull<u64>>.fmt_or_empty()));
+
+    let value = 5u8;
+    let raw = Some(&value as *const u8);
+    assert_eq!(
+        format!("{:p}", &value),
+        format!("{:p}", raw.fmt_or("(null)"))
+    );
+    assert_eq!(
+        "(null)",
+        format!("{:p}", None::<*const u8>.fmt_or("(null)"))
+    );
+    // A present null pointer is still a value, not a missing one.
+    assert_eq!(
+        "0x0",
+        format!("{:p}", Some(ptr::null::<u8>()).fmt_or("(null)"))
+    );
+    assert_eq!(
+        "0x0",
+        format!("{:p}", Some(ptr::null_mut::<u8>()).fmt_or("(null)"))
+    );
+}
+
+#[test]
+fn test_or_hex() {
+    let zero = Some(0u32);
+    let none = None::<u32>;
+    for spec in 0..4 {
+        let (some, missing) = match spec {
+            0 => (
+                format!("{:#x}", zero.fmt_or_hex(0)),
+                format!("{:#x}", none.fmt_or_hex(0)),
+            ),
+            1 => (
+                format!("{:#X}", zero.fmt_or_hex(0)),
+                format!("{:#X}", none.fmt_or_hex(0)),
+            ),
+            2 => (
+                format!("{:#06x}", zero.fmt_or_hex(0)),
+                format!("{:#06x}", none.fmt_or_hex(0)),
+            ),
+            _ => (
+                format!("{:>6x}", zero.fmt_or_hex(0)),
+                format!("{:>6x}", none.fmt_or_hex(0)),
+            ),
+        };
+        assert_eq!(some, missing);
+    }
+    assert_eq!("0x0", format!("{:#x}", none.fmt_or_hex(0)));
+    assert_eq!("0xAB", format!("{:#X}", none.fmt_or_hex(0xABu8)));
+    // With `fmt_or`, the fallback is displayed and the prefix is lost.
+    assert_eq!("0", format!("{:#x}", none.fmt_or("0")));
+}
+
+#[test]
+fn test_error_propagation() {
+    use std::cell::Cell;
+    use std::fmt::{Display, Formatter, LowerHex, Write};
+
+    struct Failing<'c>(&'c Cell<u32>);
+
+    impl Display for Failing<'_> {
+        fn fmt(&self, _: &mut Formatter<'_>) -> Result {
+            self.0.set(self.0.get() + 1);
+            Err(std::fmt::Error)
+        }
+    }
+
+    impl LowerHex for Failing<'_> {
+        fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+            Display::fmt(self, out)
+        }
+    }
+
+    let calls = Cell::new(0);
+    let mut out = Capture::default();
+
+    let present = Some(Failing(&calls));
+    assert!(write!(out, "a{}b", present.fmt_or("-")).is_err());
+    assert!(write!(out, "{:x}", present.fmt_or_empty()).is_err());
+    assert!(write!(out, "{:>4}", present.fmt_or_else(|| "-")).is_err());
+    assert_eq!(3, calls.get());
+
+    let missing = None::<u32>;
+    assert!(write!(out, "c{}d", missing.fmt_or(Failing(&calls))).is_err());
+    assert!(write!(out, "{:x}", missing.fmt_or_else(|| Failing(&calls))).is_err());
+    assert!(write!(out, "{:>4}", missing.fmt_or(Failing(&calls))).is_err());
+    assert_eq!(6, calls.get());
+
+    // Output stops at the first error.
+    assert_eq!("ac", out.0);
+}
+
+#[test]
+fn test_empty_fast_path() {
+    use std::fmt::Write;
+
+    /// Counts the calls to `write_str`, including empty writes.
+    #[derive(Default)]
+    struct Calls(usize, String);
+
+    impl std::fmt::Write for Calls {
+        fn write_str(&mut self, s: &str) -> Result {
+            self.0 += 1;
+            self.1.push_str(s);
+            Ok(())
+        }
+    }
+
+    let none = None::<u32>;
+    let mut out = Calls::default();
+    write!(out, "{}", none.fmt_or_empty()).unwrap();
+    write!(out, "{:x}", none.fmt_or_empty()).unwrap();
+    assert_eq!(0, out.0);
+
+    write!(out, "{:>4}", none.fmt_or_empty()).unwrap();
+    assert_eq!("    ", out.1);
+    assert_eq!("   ", format!("{:^3}", none.fmt_or_empty()));
+    assert_eq!("", format!("{:.2}", none.fmt_or_empty()));
+}
+
+#[test]
+fn test_trait_objects() {
+    use std::fmt::{Display, LowerHex};
+
+    let cells: Vec<Option<Box<dyn Display>>> = vec![
+        Some(Box::new(1)),
+        None,
+        Some(Box::new('c')),
+        Some(Box::new(2.5)),
+        Some(Box::new(String::from("s"))),
+    ];
+    let row: Vec<String> = cells
+        .iter()
+        .map(|c| format!("{:>3}", c.fmt_or("-")))
+        .collect();
+    assert_eq!(["  1", "  -", "  c", "2.5", "  s"], row[..]);
+
+    let value = 7u8;
+    let borrowed: [Option<&dyn Display>; 2] = [Some(&value), None];
+    assert_eq!("7", format!("{}", borrowed[0].fmt_or_empty()));
+    assert_eq!("none", format!("{}", borrowed[1].fmt_or("none")));
+
+    let hex: Option<&dyn LowerHex> = Some(&255u32);
+    assert_eq!("ff", format!("{:x}", hex.fmt_or("-")));
+}
+
+#[test]
+fn test_or_eager() {
+    use std::cell::Cell;
+
+    let runs = Cell::new(0);
+    let make = || {
+        runs.set(runs.get() + 1);
+        "made"
+    };
+
+    let some = Some(0x2au32);
+    let wrapper = some.fmt_or_eager(make);
+    assert_eq!("2a", format!("{:x}", wrapper));
+    assert_eq!("  2a", format!("{:>4x}", wrapper));
+    assert_eq!(0, runs.get());
+
+    let none = None::<u32>;
+    let wrapper = none.fmt_or_eager(make);
+    assert_eq!(1, runs.get());
+    assert_eq!("made", format!("{:x}", wrapper));
+    assert_eq!("  made", format!("{:>6}", wrapper));
+    assert_eq!("made", format!("{}", wrapper.or_opt(&None)));
+    assert_eq!(1, runs.get());
+}
+
+#[test]
+fn test_or_else_copy() {
+    fn lazy() -> &'static str {
+        "fn"
+    }
+
+    let none = None::<u32>;
+    let first = none.fmt_or_else(|| "x");
+    let second = first;
+    assert_eq!("x", format!("{}", first));
+    assert_eq!("  x", format!("{:>3x}", second));
+
+    let first = none.fmt_or_else(lazy as fn() -> &'static str);
+    let second = first;
+    assert_eq!(format!("{}", first), format!("{}", second));
+}
+
+#[test]
+fn test_or_debug_none() {
+    struct Config {
+        port: Option<u16>,
+        hosts: Option<[&'static str; 2]>,
+    }
+
+    impl std::fmt::Debug for Config {
+        fn fmt(&self, out: &mut std::fmt::Formatter<'_>) -> Result {
+            out.debug_struct("Config")
+                .field("port", &self.port.fmt_or_debug_none())
+                .field("hosts", &self.hosts.fmt_or_debug_none())
+                .finish()
+        }
+    }
+
+    let missing = Config {
+        port: None,
+        hosts: None,
+    };
+    assert_eq!(
+        "Config { port: None, hosts: None }",
+        format!("{:?}", missing)
+    );
+    let present = Config {
+        port: Some(80),
+        hosts: Some(["a", "b"]),
+    };
+    assert_eq!(
+        r#"Config { port: 80, hosts: ["a", "b"] }"#,
+        format!("{:?}", present)
+    );
+    assert_eq!(
+        "Config {\n    port: 80,\n    hosts: [\n        \"a\",\n        \"b\",\n    ],\n}",
+        format!("{:#?}", present)
+    );
+    assert_eq!("", format!("{}", missing.port.fmt_or_debug_none()));
+    assert_eq!("50", format!("{:x}", present.port.fmt_or_debug_none()));
+}
+
+#[test]
+fn test_or_num_binary_octal() {
+    assert_eq!(
+        format!("{:#010b}", Some(0u8).fmt_or_num(0)),
+        format!("{:#010b}", None::<u8>.fmt_or_num(0))
+    );
+    assert_eq!("0b00000000", format!("{:#010b}", None::<u8>.fmt_or_num(0)));
+    assert_eq!("0o0000", format!("{:#06o}", None::<u8>.fmt_or_num(0)));
+    for row in [Some(0b1010_0101u8), None, Some(1)] {
+        assert_eq!(10, format!("{:#010b}", row.fmt_or_num(0)).len());
+        assert_eq!(6, format!("{:#06o}", row.fmt_or_num(0)).len());
+        assert_eq!(8, format!("{:08b}", row.fmt_or_num(0)).len());
+    }
+    // `fmt_or` displays the fallback, so the prefix is lost.
+    assert_eq!("0000000000", format!("{:#010b}", None::<u8>.fmt_or(0)));
+    assert_eq!("0         ", format!("{:#010b}", None::<u8>.fmt_or("0")));
+}
+
+#[test]
+fn test_display_or() {
+    fn dotted(v: &u64, f: &mut std::fmt::Formatter<'_>) -> Result {
+        write!(f, "{}.{}.{}", v >> 32, (v >> 16) & 0xffff, v & 0xffff)
+    }
+
+    let version = Some((1u64 << 32) | (20 << 16) | 3);
+    assert_eq!("1.20.3", format!("{}", version.fmt_display_or(dotted, "-")));
+    assert_eq!("-", format!("{}", None.fmt_display_or(dotted, "-")));
+    assert_eq!("  -", format!("{:>3}", None.fmt_display_or(dotted, "-")));
+    // The closure decides what to do with the format spec.
+    let padded = |v: &u64, f: &mut std::fmt::Formatter<'_>| f.pad(&v.to_string());
+    assert_eq!(" 7", format!("{:>2}", Some(7).fmt_display_or(padded, "-")));
+}
+
+#[test]
+fn test_cow() {
+    use std::borrow::Cow;
+
+    /// Panics if cloned, so formatting must only borrow.
+    struct NoClone(u32);
+
+    impl Clone for NoClone {
+        fn clone(&self) -> Self {
+            panic!("formatting cloned the value")
+        }
+    }
+
+    impl std::fmt::LowerHex for NoClone {
+        fn fmt(&self, out: &mut std::fmt::Formatter<'_>) -> Result {
+            std::fmt::LowerHex::fmt(&self.0, out)
+        }
+    }
+
+    let opt = Some(NoClone(255));
+    let borrowed: Cow<'_, Option<NoClone>> = Cow::Borrowed(&opt);
+    let owned: Cow<'_, Option<NoClone>> = Cow::Owned(Some(NoClone(255)));
+    assert_eq!("ff", format!("{:x}", borrowed.fmt_or("-")));
+    assert_eq!(
+        format!("{:x}", borrowed.fmt_or_empty()),
+        format!("{:x}", owned.fmt_or_empty())
+    );
+
+    let missing: Cow<'_, Option<NoClone>> = Cow::Owned(None);
+    assert_eq!("-", format!("{:x}", missing.fmt_or("-")));
+    assert_eq!(
+        format!("{:x}", Cow::Borrowed(&None::<NoClone>).fmt_or("-")),
+        format!("{:x}", missing.fmt_or("-"))
+    );
+}
+
+#[test]
+fn test_or_num_exp() {
+    let zero = Some(0.0f64);
+    let none = None::<f64>;
+    for (some, missing) in [
+        (
+            format!("{:e}", zero.fmt_or_num(1.0)),
+            format!("{:e}", none.fmt_or_num(0.0)),
+        ),
+        (
+            format!("{:E}", zero.fmt_or_num(1.0)),
+            format!("{:E}", none.fmt_or_num(0.0)),
+        ),
+        (
+            format!("{:.2e}", zero.fmt_or_num(1.0)),
+            format!("{:.2e}", none.fmt_or_num(0.0)),
+        ),
+    ] {
+        assert_eq!(some, missing);
+    }
+    assert_eq!("0e0", format!("{:e}", none.fmt_or_num(0.0)));
+    assert_eq!("1.50E3", format!("{:.2E}", none.fmt_or_num(1500.0f32)));
+    assert_eq!(
+        "+1.000e-3",
+        format!("{:+.3e}", None::<f32>.fmt_or_num(0.001f32))
+    );
+    // `fmt_or` displays the fallback instead.
+    assert_eq!("0", format!("{:e}", none.fmt_or(0.0)));
+}
+
+#[test]
+fn test_resolve() {
+    assert_eq!(Resolved::Present(&5), Some(5).fmt_or("x").resolve());
+    assert_eq!(Resolved::Absent(&"x"), None::<u32>.fmt_or("x").resolve());
+    assert_eq!(Resolved::Present(&5), Some(5).fmt_or_empty().resolve());
+    assert_eq!(Resolved::Absent(""), None::<u32>.fmt_or_empty().resolve());
+    assert_eq!(
+        Resolved::Present(&5),
+        Some(5)
+            .fmt_or_else(|| -> &str { panic!("the value is present") })
+            .resolve()
+    );
+    let calls = std::cell::Cell::new(0);
+    let wrapper = None::<u32>.fmt_or_else(|| {
+        calls.set(calls.get() + 1);
+        calls.get()
+    });
+    assert_eq!(Resolved::Absent(1), wrapper.resolve());
+    assert_eq!(Resolved::Absent(2), wrapper.resolve());
+}
+
+#[test]
+fn test_multibyte_fallback_width() {
+    for sentinel in &["∅", "—"] {
+        let none = None::<u32>;
+        assert_eq!(
+            format!("{:^5}", sentinel),
+            format!("{:^5}", none.fmt_or(sentinel))
+        );
+        assert_eq!(
+            format!("{:>5}", sentinel),
+            format!("{:>5}", none.fmt_or(sentinel))
+        );
+        assert_eq!(
+            format!("{:*<5}", sentinel),
+            format!("{:*<5}", none.fmt_or_else(|| sentinel))
+        );
+        assert_eq!(5, format!("{:^5}", none.fmt_or(sentinel)).chars().count());
+    }
+    assert_eq!("  ∅  ", format!("{:^5}", None::<u32>.fmt_or_char('∅')));
+    assert_eq!("—", format!("{}", None::<u32>.fmt_or_char('—')));
+    // Padding a composite wrapper counts chars too, not bytes.
+    let pair = (None::<u32>, None::<u32>);
+    assert_eq!(
+        "∅/∅  ",
+        format!(
+            "{:5}",
+            crate::FmtOrTuple::fmt_or_tuple(&pair, ("∅", "∅"), "/")
+        )
+    );
+}
+
+#[test]
+fn test_fmt_or_map_opt() {
+    let primary: Option<u32> = Some(255);
+    let missing: Option<u32> = None;
+    let other = Some("backup");
+    let to_display = |b: &&str| b.len();
+
+    assert_eq!(
+        "255",
+        format!("{}", primary.fmt_or_map_opt(&other, to_display))
+    );
+    assert_eq!(
+        "ff",
+        format!("{:x}", primary.fmt_or_map_opt(&other, to_display))
+    );
+    assert_eq!(
+        "6",
+        format!("{:x}", missing.fmt_or_map_opt(&other, to_display))
+    );
+    assert_eq!(
+        "backup",
+        format!("{}", missing.fmt_or_map_opt(&other, |b: &&str| *b))
+    );
+    assert_eq!(
+        "",
+        format!("{:x}", missing.fmt_or_map_opt(&None::<&str>, to_display))
+    );
+    assert_eq!(
+        "   ",
+        format!("{:3}", missing.fmt_or_map_opt(&None::<&str>, to_display))
+    );
+}
+
+#[test]
+fn test_ord() {
+    let options = vec![Some(3), None, Some(1), Some(2), None];
+    let mut wrappers: Vec<_> = options.iter().map(FmtOr::fmt_or_empty).collect();
+    wrappers.sort();
+    let mut sorted = options.clone();
+    sorted.sort();
+
+    let formatted: Vec<_> = wrappers.iter().map(|w| format!("{}", w)).collect();
+    assert_eq!(vec!["", "", "1", "2", "3"], formatted);
+    let expected: Vec<_> = sorted.iter().map(FmtOr::fmt_or_empty).collect();
+    assert_eq!(expected, wrappers);
+    assert!(None::<u32>.fmt_or_empty() < Some(0).fmt_or_empty());
+
+    // The options decide first, then the fallbacks.
+    assert!(None::<u32>.fmt_or("b") < Some(0).fmt_or("a"));
+    assert!(None::<u32>.fmt_or("a") < None::<u32>.fmt_or("b"));
+    assert_eq!(
+        core::cmp::Ordering::Equal,
+        Some(1).fmt_or("a").cmp(&Some(1).fmt_or("a"))
+    );
+}
+
+#[test]
+fn test_fmt_or_else_borrowed_return() {
+    let name = String::from("x");
+    let some = Some(1u32);
+    let none = None::<u32>;
+    assert_eq!("1", format!("{}", some.fmt_or_else(|| name.as_str())));
+    assert_eq!("x", format!("{}", none.fmt_or_else(|| name.as_str())));
+    assert_eq!("x", format!("{}", none.fmt_or_else(|| &name)));
+
+    // The borrowed data and the option can have unrelated lifetimes.
+    fn label<'a, 'n>(
+        opt: &'a Option<u32>,
+        name: &'n str,
+    ) -> MaybeFormatOrElse<'a, u32, impl Fn() -> &'n str + 'n> {
+        opt.fmt_or_else(move || name)
+    }
+    let formatted = {
+        let short_lived = Some(2u32);
+        format!("{}", label(&short_lived, &name))
+    };
+    assert_eq!("2", formatted);
+    assert_eq!("x", format!("{}", label(&none, &name)));
+
+    struct Row<'r> {
+        id: Option<u32>,
+        placeholder: &'r str,
+    }
+    let rows = [
+        Row {
+            id: Some(7),
+            placeholder: &name,
+        },
+        Row {
+            id: None,
+            placeholder: &name,
+        },
+    ];
+    let rendered: Vec<_> = rows
+        .iter()
+        .map(|row| format!("{:x}", row.id.fmt_or_else(|| row.placeholder)))
+        .collect();
+    assert_eq!(vec!["7", "x"], rendered);
+}
+
+#[test]
+fn test_fmt_either() {
+    // Reproduces `fmt_or("x")`, passing the format spec on to both branches.
+    let present = |t: &u32, f: &mut Formatter| Display::fmt(t, f);
+    let absent = |f: &mut Formatter| Display::fmt("x", f);
+    for opt in &[Some(42u32), None] {
+        for &width in &[0, 5] {
+            assert_eq!(
+                format!("{:>w$}", opt.fmt_or("x"), w = width),
+                format!("{:>w$}", opt.fmt_either(present, absent), w = width)
+            );
+        }
+    }
+
+    // Renders the branches differently, which no other method does.
+    let upper = |s: &&str, f: &mut Formatter| write!(f, "{}", s.to_uppercase());
+    let lower = |f: &mut Formatter| f.write_str("missing");
+    assert_eq!(
+        "ALICE",
+        format!("{}", Some("alice").fmt_either(upper, lower))
+    );
+    assert_eq!("missing", format!("{}", None.fmt_either(upper, lower)));
+}
@@ -0,0 +1,718 @@
+//! Adapters for optional strings.
+
+#[cfg(test)]
+mod tests;
+
+use core::fmt::{Alignment, Display, Formatter, Result, Write};
+#[cfg(feature = "unicode-normalization")]
+use unicode_normalization::UnicodeNormalization;
+
+use crate::num::GroupLeft;
+use crate::pad::pad;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// The type returned from [`FmtOrStr::fmt_truncate_words_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatTruncateWords<'t, S, U>(&'t Option<S>, usize, &'t str, U);
+/// The type returned from [`FmtOrStr::fmt_group_left_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatGroupLeftStr<'t, S, U>(&'t Option<S>, &'t [usize], &'t str, U);
+/// The type returned from [`FmtOrStr::fmt_ellipsis_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatEllipsis<'t, S, U>(&'t Option<S>, usize, Ellipsis, U);
+/// The type returned from [`FmtOrStr::fmt_json_value_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatJsonString<'t, S>(&'t Option<S>);
+/// The type returned from [`FmtOrStr::fmt_strip_ansi_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatStripAnsi<'t, S, U>(&'t Option<S>, U);
+/// The type returned from [`FmtOrStr::fmt_wrap_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatWrapLines<'t, S, U>(&'t Option<S>, usize, U);
+/// The type returned from [`FmtOrStr::fmt_collapse_ws_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatCollapseWs<'t, S, U>(&'t Option<S>, U);
+/// The type returned from [`FmtOrStr::fmt_printf_safe_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatPrintfSafe<'t, S, U>(&'t Option<S>, U);
+/// The type returned from [`FmtOrStr::fmt_log_safe_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatLogSafe<'t, S, U>(&'t Option<S>, usize, U);
+/// The type returned from [`FmtOrStr::fmt_or_nonempty`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatNonEmpty<'t, S, U>(&'t Option<S>, U);
+/// The type returned from [`FmtOrStr::fmt_nfc_or`]
+#[cfg(feature = "unicode-normalization")]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatNfc<'t, S, U>(&'t Option<S>, U);
+
+/// An extension trait for [`Option<S>`] where `S` is any string type, such as `&str` or `String`.
+///
+/// Unless noted otherwise, the wrappers returned from these methods implement [`Display`] only.
+/// Like [`FmtOr::fmt_or`], the fallback is formatted with the full format spec, while a present
+/// string is rendered as a single piece of text which is then padded to the requested width.
+///
+/// Lengths are always counted in `char`s.
+///
+/// This trait is sealed and implemented for [`Option<S>`] only, so that methods behind features
+/// can be added to it without breaking other implementations.
+///
+/// [`FmtOr::fmt_or`]: crate::FmtOr::fmt_or
+#[allow(clippy::needless_lifetimes)] // They're nice to see in docs
+pub trait FmtOrStr<S: AsRef<str>>: sealed::Sealed {
+    /// Format the string cut down to at most `max_chars` at a word boundary, or display the
+    /// given value instead.
+    ///
+    /// If the string is too long, it is cut at the last whitespace that keeps it within
+    /// `max_chars`, trailing whitespace is dropped, and `ellipsis` is appended. The ellipsis does not
+    /// count towards `max_chars`. A single word longer than `max_chars` is cut mid-word.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrStr;
+    ///
+    /// let title = Some("The quick brown fox");
+    ///
+    /// assert_eq!("The quick...", format!("{}", title.fmt_truncate_words_or(12, "...", "")));
+    /// ```
+    fn fmt_truncate_words_or<'t, U>(
+        &'t self,
+        max_chars: usize,
+        ellipsis: &'t str,
+        fallback: U,
+    ) -> MaybeFormatTruncateWords<'t, S, U>
+    where
+        U: Display;
+    /// Format the string with its characters in groups counted from the left, or display the
+    /// given value instead.
+    ///
+    /// This is the string counterpart of [`FmtOrInt::fmt_group_left_or`], for digit strings
+    /// such as account or phone numbers which may have leading zeros.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrStr;
+    ///
+    /// let phone = Some("0123456789");
+    ///
+    /// assert_eq!("012-345-6789", format!("{}", phone.fmt_group_left_or(&[3, 3, 4], "-", "")));
+    /// ```
+    ///
+    /// [`FmtOrInt::fmt_group_left_or`]: crate::FmtOrInt::fmt_group_left_or
+    fn fmt_group_left_or<'t, U>(
+        &'t self,
+        groups: &'t [usize],
+        sep: &'t str,
+        fallback: U,
+    ) -> MaybeFormatGroupLeftStr<'t, S, U>
+    where
+        U: Display;
+    /// Format the string shortened to at most `max_chars` with an ellipsis at the chosen
+    /// position, or display the given value instead.
+    ///
+    /// The `…` counts towards `max_chars`. With [`Ellipsis::Middle`], the end of the string gets
+    /// the extra character when the remaining space doesn't split evenly, since the end of a path
+    /// is usually the most interesting part.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::{Ellipsis, FmtOrStr};
+    ///
+    /// let path = Some("/home/user/file.txt");
+    ///
+    /// assert_eq!("/home/…ile.txt", format!("{}", path.fmt_ellipsis_or(14, Ellipsis::Middle, "")));
+    /// assert_eq!("…ser/file.txt", format!("{}", path.fmt_ellipsis_or(13, Ellipsis::Start, "")));
+    /// assert_eq!("/home/use…", format!("{}", path.fmt_ellipsis_or(10, Ellipsis::End, "")));
+    /// ```
+    fn fmt_ellipsis_or<'t, U>(
+        &'t self,
+        max_chars: usize,
+        position: Ellipsis,
+        fallback: U,
+    ) -> MaybeFormatEllipsis<'t, S, U>
+    where
+        U: Display;
+    /// Format the string as a quoted and escaped JSON string, or `null` if there is no value.
+    ///
+    /// See [`FmtOrNum::fmt_json_value_or`] for numbers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrStr;
+    ///
+    /// assert_eq!(r#""say \"hi\"\n""#, format!("{}", Some("say \"hi\"\n").fmt_json_value_or()));
+    /// assert_eq!("null", format!("{}", None::<&str>.fmt_json_value_or()));
+    /// ```
+    ///
+    /// [`FmtOrNum::fmt_json_value_or`]: crate::FmtOrNum::fmt_json_value_or
+    fn fmt_json_value_or<'t>(&'t self) -> MaybeFormatJsonString<'t, S>;
+    /// Format the string with ANSI escape sequences removed, or display the given value instead.
+    ///
+    /// Control sequences such as the SGR color codes `\x1b[31m` are removed entirely, as are
+    /// two-character escapes such as `\x1b7`. An escape sequence which is cut off at the end of the
+    /// string is dropped, and a control sequence interrupted by a character which can't be part of
+    /// it ends just before that character. The fallback is passed through unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrStr;
+    ///
+    /// let colored = Some("\x1b[1;31merror\x1b[0m: oops");
+    ///
+    /// assert_eq!("error: oops", format!("{}", colored.fmt_strip_ansi_or("")));
+    /// ```
+    fn fmt_strip_ansi_or<'t, U>(&'t self, fallback: U) -> MaybeFormatStripAnsi<'t, S, U>
+    where
+        U: Display;
+    /// Format the string broken into lines of at most `width` chars, or display the given value instead.
+    ///
+    /// A `\n` is inserted after every `width` chars, counting from the start of each line so that
+    /// existing line breaks are kept. No line break is added at the very end, and a `width` of zero
+    /// leaves the string unchanged. Since the result spans several lines, the format spec is
+    /// ignored for a present value; the fallback is formatted with the full format spec and not
+    /// wrapped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrStr;
+    ///
+    /// let key = Some("0123456789abcdef");
+    ///
+    /// assert_eq!("012345\n6789ab\ncdef", format!("{}", key.fmt_wrap_or(6, "")));
+    /// ```
+    fn fmt_wrap_or<'t, U>(&'t self, width: usize, fallback: U) -> MaybeFormatWrapLines<'t, S, U>
+    where
+        U: Display;
+    /// Format the string with each run of whitespace replaced by a single space, or display the
+    /// given value instead.
+    ///
+    /// Whitespace at the start and end is removed, and tabs and line breaks count as whitespace,
+    /// so the result is always a single line.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrStr;
+    ///
+    /// let prose = Some("  hello,\n\t  world  ");
+    ///
+    /// assert_eq!("hello, world", format!("{}", prose.fmt_collapse_ws_or("")));
+    /// ```
+    fn fmt_collapse_ws_or<'t, U>(&'t self, fallback: U) -> MaybeFormatCollapseWs<'t, S, U>
+    where
+        U: Display;
+    /// Format the string with every `%` doubled, or display the given value instead.
+    ///
+    /// This makes the string safe to pass as a `printf`-style format string, such as to a C
+    /// logging function, where `%%` stands for a literal `%`. The fallback is passed through
+    /// unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrStr;
+    ///
+    /// let msg = Some("100% of %d");
+    ///
+    /// assert_eq!("100%% of %%d", format!("{}", msg.fmt_printf_safe_or("")));
+    /// ```
+    fn fmt_printf_safe_or<'t, U>(&'t self, fallback: U) -> MaybeFormatPrintfSafe<'t, S, U>
+    where
+        U: Display;
+    /// Format the string as a single line of at most `max_chars` chars, or display the given value
+    /// instead.
+    ///
+    /// Every control char, including line breaks and tabs, is replaced by a space, so a value can
+    /// never start a new log line or smuggle in escape sequences. If the result would be longer than
+    /// `max_chars`, it is cut short and ends with `…`, which counts towards `max_chars`. The
+    /// fallback is passed through unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrStr;
+    ///
+    /// let user = Some("admin\nlevel=INFO msg=forged");
+    ///
+    /// assert_eq!("admin level=INFO…", format!("{}", user.fmt_log_safe_or(17, "-")));
+    /// assert_eq!("-", format!("{}", None::<&str>.fmt_log_safe_or(17, "-")));
+    /// ```
+    fn fmt_log_safe_or<'t, U>(
+        &'t self,
+        max_chars: usize,
+        fallback: U,
+    ) -> MaybeFormatLogSafe<'t, S, U>
+    where
+        U: Display;
+    /// Format the string, or display the given value instead if there is no string or it is empty.
+    ///
+    /// `Some("")` is treated the same as [`None`], which suits strings read from forms or the
+    /// environment, where an empty value usually means unset. A present string is formatted like
+    /// a `&str`, so precision truncates it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrStr;
+    ///
+    /// assert_eq!("x", format!("{}", Some("x").fmt_or_nonempty("-")));
+    /// assert_eq!("-", format!("{}", Some("").fmt_or_nonempty("-")));
+    /// assert_eq!("-", format!("{}", None::<&str>.fmt_or_nonempty("-")));
+    /// ```
+    fn fmt_or_nonempty<'t, U>(&'t self, fallback: U) -> MaybeFormatNonEmpty<'t, S, U>
+    where
+        U: Display;
+    /// Format the string in Unicode Normalization Form C, or display the given value instead.
+    ///
+    /// Decomposed sequences such as `e` followed by a combining acute accent are written as
+    /// their composed form `é`, so equal strings display the same. The string is normalized as
+    /// it is written, without allocating. The fallback is not normalized.
+    ///
+    /// Requires the `unicode-normalization` feature.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrStr;
+    ///
+    /// assert_eq!("caf\u{e9}", format!("{}", Some("cafe\u{301}").fmt_nfc_or("-")));
+    /// assert_eq!("-", format!("{}", None::<&str>.fmt_nfc_or("-")));
+    /// ```
+    #[cfg(feature = "unicode-normalization")]
+    fn fmt_nfc_or<'t, U>(&'t self, fallback: U) -> MaybeFormatNfc<'t, S, U>
+    where
+        U: Display;
+}
+
+impl<S: AsRef<str>> sealed::Sealed for Option<S> {}
+
+impl<S: AsRef<str>> FmtOrStr<S> for Option<S> {
+    #[inline]
+    fn fmt_truncate_words_or<'t, U>(
+        &'t self,
+        max_chars: usize,
+        ellipsis: &'t str,
+        fallback: U,
+    ) -> MaybeFormatTruncateWords<'t, S, U>
+    where
+        U: Display,
+    {
+        MaybeFormatTruncateWords(self, max_chars, ellipsis, fallback)
+    }
+    #[inline]
+    fn fmt_group_left_or<'t, U>(
+        &'t self,
+        groups: &'t [usize],
+        sep: &'t str,
+        fallback: U,
+    ) -> MaybeFormatGroupLeftStr<'t, S, U>
+    where
+        U: Display,
+    {
+        MaybeFormatGroupLeftStr(self, groups, sep, fallback)
+    }
+    #[inline]
+    fn fmt_ellipsis_or<U>(
+        &self,
+        max_chars: usize,
+        position: Ellipsis,
+        fallback: U,
+    ) -> MaybeFormatEllipsis<'_, S, U>
+    where
+        U: Display,
+    {
+        MaybeFormatEllipsis(self, max_chars, position, fallback)
+    }
+    #[inline]
+    fn fmt_json_value_or(&self) -> MaybeFormatJsonString<'_, S> {
+        MaybeFormatJsonString(self)
+    }
+    #[inline]
+    fn fmt_strip_ansi_or<U>(&self, fallback: U) -> MaybeFormatStripAnsi<'_, S, U>
+    where
+        U: Display,
+    {
+        MaybeFormatStripAnsi(self, fallback)
+    }
+    #[inline]
+    fn fmt_wrap_or<U>(&self, width: usize, fallback: U) -> MaybeFormatWrapLines<'_, S, U>
+    where
+        U: Display,
+    {
+        MaybeFormatWrapLines(self, width, fallback)
+    }
+    #[inline]
+    fn fmt_collapse_ws_or<U>(&self, fallback: U) -> MaybeFormatCollapseWs<'_, S, U>
+    where
+        U: Display,
+    {
+        MaybeFormatCollapseWs(self, fallback)
+    }
+    #[inline]
+    fn fmt_printf_safe_or<U>(&self, fallback: U) -> MaybeFormatPrintfSafe<'_, S, U>
+    where
+        U: Display,
+    {
+        MaybeFormatPrintfSafe(self, fallback)
+    }
+    #[inline]
+    fn fmt_log_safe_or<U>(&self, max_chars: usize, fallback: U) -> MaybeFormatLogSafe<'_, S, U>
+    where
+        U: Display,
+    {
+        MaybeFormatLogSafe(self, max_chars, fallback)
+    }
+    #[inline]
+    fn fmt_or_nonempty<U>(&self, fallback: U) -> MaybeFormatNonEmpty<'_, S, U>
+    where
+        U: Display,
+    {
+        MaybeFormatNonEmpty(self, fallback)
+    }
+    #[inline]
+    #[cfg(feature = "unicode-normalization")]
+    fn fmt_nfc_or<U>(&self, fallback: U) -> MaybeFormatNfc<'_, S, U>
+    where
+        U: Display,
+    {
+        MaybeFormatNfc(self, fallback)
+    }
+}
+
+/// Returns the byte index just after the first `n` chars of `s`, or `None` if `s` has no more than `n` chars.
+fn char_boundary(s: &str, n: usize) -> Option<usize> {
+    s.char_indices().nth(n).map(|(i, _)| i)
+}
+
+impl<'t, S, U> Display for MaybeFormatTruncateWords<'t, S, U>
+where
+    S: AsRef<str>,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        let s = match self.0 {
+            Some(s) => s.as_ref(),
+            None => return Display::fmt(&self.3, out),
+        };
+        let cut = match char_boundary(s, self.1) {
+            Some(cut) => cut,
+            None => return out.pad(s),
+        };
+        let head = &s[..cut];
+        let at_boundary = s[cut..].starts_with(char::is_whitespace);
+        let kept = if at_boundary {
+            head.trim_end()
+        } else {
+            match head.rfind(char::is_whitespace) {
+                Some(ws) => head[..ws].trim_end(),
+                None => head,
+            }
+        };
+        let kept = if kept.is_empty() { head } else { kept };
+        pad(out, Alignment::Left, |w: &mut dyn Write| {
+            w.write_str(kept)?;
+            w.write_str(self.2)
+        })
+    }
+}
+
+impl<'t, S, U> Display for MaybeFormatGroupLeftStr<'t, S, U>
+where
+    S: AsRef<str>,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(s) => pad(out, Alignment::Left, |w: &mut dyn Write| {
+                GroupLeft::new(w, self.1, self.2).write_str(s.as_ref())
+            }),
+            None => Display::fmt(&self.3, out),
+        }
+    }
+}
+
+/// Where [`FmtOrStr::fmt_ellipsis_or`] removes text from a string that is too long.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Ellipsis {
+    /// Keep the end of the string, e.g. `…file.txt`
+    Start,
+    /// Keep both ends of the string, e.g. `/home/…/file.txt`
+    Middle,
+    /// Keep the start of the string, e.g. `/home/us…`
+    End,
+}
+
+/// Returns the byte index where the last `n` chars of `s` begin, or `None` if `s` has no more than `n` chars.
+fn char_boundary_from_end(s: &str, n: usize) -> Option<usize> {
+    if n == 0 {
+        return Some(s.len());
+    }
+    s.char_indices().rev().nth(n - 1).map(|(i, _)| i)
+}
+
+impl<'t, S, U> Display for MaybeFormatEllipsis<'t, S, U>
+where
+    S: AsRef<str>,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        let s = match self.0 {
+            Some(s) => s.as_ref(),
+            None => return Display::fmt(&self.3, out),
+        };
+        if char_boundary(s, self.1).is_none() {
+            return out.pad(s);
+        }
+        let keep = self.1.saturating_sub(1);
+        let (head, tail) = match self.2 {
+            Ellipsis::Start => (0, keep),
+            Ellipsis::Middle => (keep / 2, keep - keep / 2),
+            Ellipsis::End => (keep, 0),
+        };
+        // `s` has more than `self.1` chars, so both boundaries exist and don't overlap.
+        let head = &s[..char_boundary(s, head).unwrap_or(0)];
+        let tail = &s[char_boundary_from_end(s, tail).unwrap_or(0)..];
+        pad(out, Alignment::Left, |w: &mut dyn Write| {
+            if self.1 == 0 {
+                return Ok(());
+            }
+            w.write_str(head)?;
+            w.write_char('…')?;
+            w.write_str(tail)
+        })
+    }
+}
+
+/// Writes `s` as the contents of a JSON string, without the surrounding quotes.
+fn write_json_escaped(out: &mut dyn Write, s: &str) -> Result {
+    for c in s.chars() {
+        match c {
+            '"' => out.write_str("\\\"")?,
+            '\\' => out.write_str("\\\\")?,
+            '\n' => out.write_str("\\n")?,
+            '\r' => out.write_str("\\r")?,
+            '\t' => out.write_str("\\t")?,
+            '\u{8}' => out.write_str("\\b")?,
+            '\u{c}' => out.write_str("\\f")?,
+            c if c < ' ' => write!(out, "\\u{:04x}", c as u32)?,
+            c => out.write_char(c)?,
+        }
+    }
+    Ok(())
+}
+
+impl<'t, S> Display for MaybeFormatJsonString<'t, S>
+where
+    S: AsRef<str>,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(s) => {
+                out.write_char('"')?;
+                write_json_escaped(out, s.as_ref())?;
+                out.write_char('"')
+            }
+            None => out.write_str("null"),
+        }
+    }
+}
+
+const ESC: char = '\x1b';
+
+/// Writes `s` without any ANSI escape sequences.
+fn write_without_ansi(out: &mut dyn Write, s: &str) -> Result {
+    let mut rest = s;
+    while let Some(start) = rest.find(ESC) {
+        out.write_str(&rest[..start])?;
+        let mut chars = rest[start + 1..].chars();
+        let after = rest.len() - chars.as_str().len();
+        rest = match chars.next() {
+            // A control sequence: parameters, then intermediates, then one final char.
+            Some('[') => {
+                let body = chars.as_str();
+                let end = body
+                    .find(|c: char| !('\x30'..='\x3f').contains(&c))
+                    .unwrap_or(body.len());
+                let end = end
+                    + body[end..]
+                        .find(|c: char| !('\x20'..='\x2f').contains(&c))
+                        .unwrap_or(body.len() - end);
+                match body[end..].chars().next() {
+                    Some(c) if ('\x40'..='\x7e').contains(&c) => &body[end + c.len_utf8()..],
+                    _ => &body[end..],
+                }
+            }
+            // Any other escape is a single char.
+            Some(c) if ('\x30'..='\x7e').contains(&c) => chars.as_str(),
+            _ => &rest[after..],
+        };
+    }
+    out.write_str(rest)
+}
+
+impl<'t, S, U> Display for MaybeFormatStripAnsi<'t, S, U>
+where
+    S: AsRef<str>,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(s) => pad(out, Alignment::Left, |w: &mut dyn Write| {
+                write_without_ansi(w, s.as_ref())
+            }),
+            None => Display::fmt(&self.1, out),
+        }
+    }
+}
+
+impl<'t, S, U> Display for MaybeFormatWrapLines<'t, S, U>
+where
+    S: AsRef<str>,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        let s = match self.0 {
+            Some(s) => s.as_ref(),
+            None => return Display::fmt(&self.2, out),
+        };
+        if self.1 == 0 {
+            return out.write_str(s);
+        }
+        let mut line = 0;
+        for c in s.chars() {
+            if c == '\n' {
+                line = 0;
+            } else {
+                if line == self.1 {
+                    out.write_char('\n')?;
+                    line = 0;
+                }
+                line += 1;
+            }
+            out.write_char(c)?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes the words of `s` separated by single spaces.
+fn write_collapsed_ws(out: &mut dyn Write, s: &str) -> Result {
+    for (i, word) in s.split_whitespace().enumerate() {
+        if i > 0 {
+            out.write_char(' ')?;
+        }
+        out.write_str(word)?;
+    }
+    Ok(())
+}
+
+impl<'t, S, U> Display for MaybeFormatCollapseWs<'t, S, U>
+where
+    S: AsRef<str>,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(s) => pad(out, Alignment::Left, |w: &mut dyn Write| {
+                write_collapsed_ws(w, s.as_ref())
+            }),
+            None => Display::fmt(&self.1, out),
+        }
+    }
+}
+
+/// Writes `s` with every `%` doubled.
+fn write_printf_safe(out: &mut dyn Write, s: &str) -> Result {
+    for (i, part) in s.split('%').enumerate() {
+        if i > 0 {
+            out.write_str("%%")?;
+        }
+        out.write_str(part)?;
+    }
+    Ok(())
+}
+
+impl<'t, S, U> Display for MaybeFormatPrintfSafe<'t, S, U>
+where
+    S: AsRef<str>,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(s) => pad(out, Alignment::Left, |w: &mut dyn Write| {
+                write_printf_safe(w, s.as_ref())
+            }),
+            None => Display::fmt(&self.1, out),
+        }
+    }
+}
+
+/// Writes the chars of `s` with control chars replaced by spaces.
+fn write_without_control(out: &mut dyn Write, s: &str) -> Result {
+    for c in s.chars() {
+        out.write_char(if c.is_control() { ' ' } else { c })?;
+    }
+    Ok(())
+}
+
+impl<'t, S, U> Display for MaybeFormatLogSafe<'t, S, U>
+where
+    S: AsRef<str>,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        let s = match self.0 {
+            Some(s) => s.as_ref(),
+            None => return Display::fmt(&self.2, out),
+        };
+        pad(out, Alignment::Left, |w: &mut dyn Write| {
+            if char_boundary(s, self.1).is_none() {
+                return write_without_control(w, s);
+            }
+            if self.1 == 0 {
+                return Ok(());
+            }
+            let end = char_boundary(s, self.1 - 1).unwrap_or(s.len());
+            write_without_control(w, &s[..end])?;
+            w.write_char('…')
+        })
+    }
+}
+
+impl<'t, S, U> Display for MaybeFormatNonEmpty<'t, S, U>
+where
+    S: AsRef<str>,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(s) if !s.as_ref().is_empty() => Display::fmt(s.as_ref(), out),
+            _ => Display::fmt(&self.1, out),
+        }
+    }
+}
+
+#[cfg(feature = "unicode-normalization")]
+impl<'t, S, U> Display for MaybeFormatNfc<'t, S, U>
+where
+    S: AsRef<str>,
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(s) => pad(out, Alignment::Left, |w: &mut dyn Write| {
+                s.as_ref().nfc().try_for_each(|c| w.write_char(c))
+            }),
+            None => Display::fmt(&self.1, out),
+        }
+    }
+}
@@ -0,0 +1,231 @@
+use crate::{Ellipsis, FmtOrStr};
+
+#[test]
+fn test_truncate_words() {
+    let fmt = |opt: Option<&str>, max| format!("{}", opt.fmt_truncate_words_or(max, "…", "n/a"));
+    let text = Some("The quick brown fox jumps");
+    assert_eq!("The quick brown fox jumps", fmt(text, 25));
+    assert_eq!("The quick brown fox…", fmt(text, 24));
+    assert_eq!("The quick brown…", fmt(text, 18));
+    assert_eq!("The quick…", fmt(text, 10));
+    assert_eq!("The quick…", fmt(text, 9));
+    assert_eq!("Th…", fmt(text, 2));
+    assert_eq!("n/a", fmt(None, 10));
+}
+
+#[test]
+fn test_truncate_words_long_word() {
+    let word = Some("Donaudampfschifffahrt".to_string());
+    assert_eq!(
+        "Donau...",
+        format!("{}", word.fmt_truncate_words_or(5, "...", ""))
+    );
+    let wide = Some("ééééé ééé");
+    assert_eq!("éé~", format!("{}", wide.fmt_truncate_words_or(2, "~", "")));
+}
+
+#[test]
+fn test_truncate_words_width() {
+    let text = Some("alpha beta gamma");
+    assert_eq!(
+        "alpha...  ",
+        format!("{:10}", text.fmt_truncate_words_or(8, "...", ""))
+    );
+    assert_eq!(
+        "  alpha beta gamma",
+        format!("{:>18}", text.fmt_truncate_words_or(20, "...", ""))
+    );
+}
+
+#[test]
+fn test_group_left() {
+    let digits = Some("0012345678");
+    assert_eq!(
+        "001-234-5678",
+        format!("{}", digits.fmt_group_left_or(&[3, 3, 4], "-", "n/a"))
+    );
+    assert_eq!(
+        "00 12 34 56 78",
+        format!("{}", digits.fmt_group_left_or(&[2], " ", "n/a"))
+    );
+    assert_eq!(
+        "n/a",
+        format!("{}", None::<&str>.fmt_group_left_or(&[2], " ", "n/a"))
+    );
+}
+
+#[test]
+fn test_ellipsis() {
+    let fmt = |opt: Option<&str>, max, pos| format!("{}", opt.fmt_ellipsis_or(max, pos, "n/a"));
+    let path = Some("/home/user/projects/file.rs");
+    assert_eq!("…ects/file.rs", fmt(path, 13, Ellipsis::Start));
+    assert_eq!("/home/…file.rs", fmt(path, 14, Ellipsis::Middle));
+    assert_eq!("/home/u…file.rs", fmt(path, 15, Ellipsis::Middle));
+    assert_eq!("/home/user/p…", fmt(path, 13, Ellipsis::End));
+    assert_eq!("…", fmt(path, 1, Ellipsis::Middle));
+    assert_eq!("", fmt(path, 0, Ellipsis::End));
+    assert_eq!("short", fmt(Some("short"), 5, Ellipsis::Middle));
+    assert_eq!("n/a", fmt(None, 5, Ellipsis::Middle));
+}
+
+#[test]
+fn test_ellipsis_chars() {
+    let wide = Some("αβγδεζηθ");
+    assert_eq!(
+        "αβ…ζηθ",
+        format!("{}", wide.fmt_ellipsis_or(6, Ellipsis::Middle, ""))
+    );
+    assert_eq!(
+        "…ηθ  ",
+        format!("{:5}", wide.fmt_ellipsis_or(3, Ellipsis::Start, ""))
+    );
+}
+
+#[test]
+fn test_json_value() {
+    assert_eq!(
+        r#""plain""#,
+        format!("{}", Some("plain").fmt_json_value_or())
+    );
+    assert_eq!(
+        r#""a\"b\\c\td\u0001é""#,
+        format!("{}", Some("a\"b\\c\td\u{1}é").fmt_json_value_or())
+    );
+    assert_eq!(
+        r#""""#,
+        format!("{}", Some(String::new()).fmt_json_value_or())
+    );
+    assert_eq!("null", format!("{}", None::<&str>.fmt_json_value_or()));
+}
+
+#[test]
+fn test_strip_ansi() {
+    let fmt = |opt: Option<&str>| format!("{}", opt.fmt_strip_ansi_or("n/a"));
+    assert_eq!(
+        "red and bold",
+        fmt(Some("\x1b[31mred\x1b[0m and \x1b[1mbold\x1b[m"))
+    );
+    assert_eq!("plain text", fmt(Some("plain text")));
+    assert_eq!("cursor", fmt(Some("\x1b[2Kcur\x1b[10;20Hsor")));
+    assert_eq!("saved", fmt(Some("\x1b7saved\x1b8")));
+    assert_eq!("n/a", fmt(None));
+}
+
+#[test]
+fn test_strip_ansi_invalid() {
+    let fmt = |opt: Option<&str>| format!("{}", opt.fmt_strip_ansi_or("n/a"));
+    assert_eq!("cut ", fmt(Some("cut \x1b[31")));
+    assert_eq!("lone ", fmt(Some("lone \x1b")));
+    assert_eq!("é after", fmt(Some("\x1b[1é after")));
+    assert_eq!("xy", fmt(Some("x\x1b\x1b[0my")));
+    assert_eq!(
+        "ok  ",
+        format!("{:4}", Some("\x1b[32mok\x1b[0m").fmt_strip_ansi_or(""))
+    );
+}
+
+#[test]
+fn test_wrap() {
+    let fmt = |opt: Option<&str>, width| format!("{}", opt.fmt_wrap_or(width, "n/a"));
+    assert_eq!("abc\ndef\ngh", fmt(Some("abcdefgh"), 3));
+    assert_eq!("abc\ndef", fmt(Some("abcdef"), 3));
+    assert_eq!("ab\nc\nde\nf", fmt(Some("abc\ndef"), 2));
+    assert_eq!("αβ\nγδ\nε", fmt(Some("αβγδε"), 2));
+    assert_eq!("abcdef", fmt(Some("abcdef"), 0));
+    assert_eq!("", fmt(Some(""), 4));
+    assert_eq!("n/a", fmt(None, 2));
+    assert_eq!(
+        "  n/a",
+        format!("{:>5}", None::<&str>.fmt_wrap_or(2, "n/a"))
+    );
+}
+
+#[test]
+fn test_collapse_ws() {
+    let fmt = |opt: Option<&str>| format!("{}", opt.fmt_collapse_ws_or("n/a"));
+    assert_eq!("a b c d", fmt(Some("a\tb\n\nc    d")));
+    assert_eq!("trimmed", fmt(Some(" \r\n trimmed \t ")));
+    assert_eq!("one word", fmt(Some("one\u{3000}word")));
+    assert_eq!("", fmt(Some(" \n\t ")));
+    assert_eq!("n/a", fmt(None));
+    assert_eq!(
+        "[   x y]",
+        format!("[{:>6}]", Some("x \n y").fmt_collapse_ws_or("n/a"))
+    );
+}
+
+#[test]
+fn test_printf_safe() {
+    let fmt = |opt: Option<&str>| format!("{}", opt.fmt_printf_safe_or("%s"));
+    assert_eq!("count: %%d", fmt(Some("count: %d")));
+    assert_eq!("%%%%", fmt(Some("%%")));
+    assert_eq!("%%", fmt(Some("%")));
+    assert_eq!("plain", fmt(Some("plain")));
+    assert_eq!("", fmt(Some("")));
+    assert_eq!("%s", fmt(None));
+    assert_eq!(
+        "50%%  ",
+        format!("{:6}", Some("50%").fmt_printf_safe_or(""))
+    );
+}
+
+#[test]
+fn test_log_safe() {
+    let fmt = |opt: Option<&str>, max| format!("{}", opt.fmt_log_safe_or(max, "<none>"));
+    let value = Some("line one\r\nline\ttwo\x1b[31m\nthree");
+    assert_eq!("line one  line two [31m three", fmt(value, 40));
+    assert_eq!("line one  line two [31m three", fmt(value, 29));
+    assert_eq!("line one  line tw…", fmt(value, 18));
+    assert_eq!("…", fmt(value, 1));
+    assert_eq!("", fmt(value, 0));
+    assert_eq!("short", fmt(Some("short"), 5));
+    assert_eq!("<none>", fmt(None, 3));
+    assert_eq!(
+        "a b…  ",
+        format!("{:6}", Some("a\nbcd").fmt_log_safe_or(4, ""))
+    );
+}
+
+#[test]
+fn test_or_nonempty() {
+    assert_eq!("x", format!("{}", Some("x").fmt_or_nonempty("-")));
+    assert_eq!("-", format!("{}", Some("").fmt_or_nonempty("-")));
+    assert_eq!("-", format!("{}", None::<&str>.fmt_or_nonempty("-")));
+    assert_eq!("  x", format!("{:>3}", Some("x").fmt_or_nonempty("-")));
+    assert_eq!("  -", format!("{:>3}", Some("").fmt_or_nonempty("-")));
+    assert_eq!("ab", format!("{:.2}", Some("abc").fmt_or_nonempty("-")));
+    assert_eq!(" ", format!("{}", Some(" ").fmt_or_nonempty("-")));
+    let owned = Some(String::new());
+    assert_eq!("unset", format!("{}", owned.fmt_or_nonempty("unset")));
+}
+
+#[test]
+#[cfg(feature = "unicode-normalization")]
+fn test_nfc() {
+    let fmt = |opt: Option<&str>| format!("{}", opt.fmt_nfc_or("n/a"));
+    // `e` followed by U+0301 COMBINING ACUTE ACCENT composes to U+00E9.
+    assert_eq!("\u{e9}", fmt(Some("e\u{301}")));
+    assert_eq!(
+        "caf\u{e9} cr\u{e8}me",
+        fmt(Some("cafe\u{301} cre\u{300}me"))
+    );
+    assert_eq!("\u{e9}", fmt(Some("\u{e9}")));
+    assert_eq!("plain", fmt(Some("plain")));
+    assert_eq!("", fmt(Some("")));
+    assert_eq!("n/a", fmt(None));
+    // The fallback is passed through as it is.
+    assert_eq!(
+        "e\u{301}",
+        format!("{}", None::<&str>.fmt_nfc_or("e\u{301}"))
+    );
+}
+
+#[test]
+#[cfg(feature = "unicode-normalization")]
+fn test_nfc_width() {
+    // The width counts the composed char, not the two it was written as.
+    let decomposed = Some(String::from("e\u{301}"));
+    assert_eq!("\u{e9}  ", format!("{:3}", decomposed.fmt_nfc_or("-")));
+    assert_eq!("  \u{e9}", format!("{:>3}", decomposed.fmt_nfc_or("-")));
+    assert_eq!("  -", format!("{:>3}", None::<String>.fmt_nfc_or("-")));
+}
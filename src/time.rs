@@ -0,0 +1,287 @@
+//! Adapters for optional durations.
+
+#[cfg(test)]
+mod tests;
+
+use core::fmt::{Alignment, Display, Formatter, Result, Write};
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::time::SystemTime;
+
+use crate::pad::pad;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// The type returned from [`FmtOrDuration::fmt_iso_duration_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatIsoDuration<'t, U>(&'t Option<Duration>, U);
+/// The type returned from [`FmtOrDuration::fmt_duration_or`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatDurationUnit<'t, U>(&'t Option<Duration>, DurationUnit, U);
+/// The type returned from [`FmtOrMissingSince::fmt_or_missing_since`]
+#[cfg(feature = "std")]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatMissingSince<'t, T>(&'t Option<T>, Duration);
+
+/// An extension trait for [`Option<Duration>`].
+///
+/// [`Duration`] doesn't implement [`Display`], so these methods each pick a textual
+/// representation. The wrappers returned from these methods implement [`Display`] only. Like
+/// [`FmtOr::fmt_or`], the fallback is formatted with the full format spec, while a present
+/// duration is rendered as a single piece of text which is then padded to the requested width.
+///
+/// [`FmtOr::fmt_or`]: crate::FmtOr::fmt_or
+#[allow(clippy::needless_lifetimes)] // They're nice to see in docs
+pub trait FmtOrDuration: sealed::Sealed {
+    /// Format the duration in ISO 8601 form such as `PT1H2M3S`, or display the given value instead.
+    ///
+    /// Components which are zero are left out, except that a zero duration is `PT0S`. Hours are
+    /// not wrapped into days. Fractions of a second are only shown when the alternate flag is
+    /// given, as in `{:#}`, and are otherwise truncated.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use core::time::Duration;
+    /// use fmtor::FmtOrDuration;
+    ///
+    /// let elapsed = Some(Duration::from_millis(3_723_500));
+    ///
+    /// assert_eq!("PT1H2M3S", format!("{}", elapsed.fmt_iso_duration_or("")));
+    /// assert_eq!("PT1H2M3.5S", format!("{:#}", elapsed.fmt_iso_duration_or("")));
+    /// ```
+    fn fmt_iso_duration_or<'t, U>(&'t self, fallback: U) -> MaybeFormatIsoDuration<'t, U>
+    where
+        U: Display;
+    /// Format the duration as a whole number of `unit` followed by its symbol, such as `1500ms`,
+    /// or display the given value instead.
+    ///
+    /// The duration is truncated to the unit, so 1999 milliseconds in seconds is `1s`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use core::time::Duration;
+    /// use fmtor::{DurationUnit, FmtOrDuration};
+    ///
+    /// let elapsed = Some(Duration::from_millis(1500));
+    ///
+    /// assert_eq!("1500ms", format!("{}", elapsed.fmt_duration_or(DurationUnit::Millis, "-")));
+    /// assert_eq!("1s", format!("{}", elapsed.fmt_duration_or(DurationUnit::Secs, "-")));
+    /// assert_eq!("-", format!("{}", None.fmt_duration_or(DurationUnit::Millis, "-")));
+    /// ```
+    fn fmt_duration_or<'t, U>(
+        &'t self,
+        unit: DurationUnit,
+        fallback: U,
+    ) -> MaybeFormatDurationUnit<'t, U>
+    where
+        U: Display;
+}
+
+/// A unit for [`FmtOrDuration::fmt_duration_or`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum DurationUnit {
+    /// Nanoseconds, written as `ns`.
+    Nanos,
+    /// Microseconds, written as `µs`.
+    Micros,
+    /// Milliseconds, written as `ms`.
+    Millis,
+    /// Seconds, written as `s`.
+    Secs,
+    /// Minutes, written as `m`.
+    Mins,
+    /// Hours, written as `h`.
+    Hours,
+}
+
+impl DurationUnit {
+    /// Returns the length of the unit in nanoseconds, and its symbol.
+    fn nanos_and_symbol(self) -> (u128, &'static str) {
+        match self {
+            DurationUnit::Nanos => (1, "ns"),
+            DurationUnit::Micros => (1_000, "µs"),
+            DurationUnit::Millis => (1_000_000, "ms"),
+            DurationUnit::Secs => (1_000_000_000, "s"),
+            DurationUnit::Mins => (60_000_000_000, "m"),
+            DurationUnit::Hours => (3_600_000_000_000, "h"),
+        }
+    }
+}
+
+impl sealed::Sealed for Option<Duration> {}
+
+impl FmtOrDuration for Option<Duration> {
+    #[inline]
+    fn fmt_iso_duration_or<U>(&self, fallback: U) -> MaybeFormatIsoDuration<'_, U>
+    where
+        U: Display,
+    {
+        MaybeFormatIsoDuration(self, fallback)
+    }
+    #[inline]
+    fn fmt_duration_or<U>(&self, unit: DurationUnit, fallback: U) -> MaybeFormatDurationUnit<'_, U>
+    where
+        U: Display,
+    {
+        MaybeFormatDurationUnit(self, unit, fallback)
+    }
+}
+
+/// Writes nanoseconds as the digits after a decimal point, without trailing zeros.
+fn write_nanos_fraction(out: &mut dyn Write, nanos: u32) -> Result {
+    let mut digits = 9;
+    let mut nanos = nanos;
+    while nanos != 0 && nanos % 10 == 0 {
+        nanos /= 10;
+        digits -= 1;
+    }
+    write!(out, ".{:0width$}", nanos, width = digits)
+}
+
+impl<'t, U> Display for MaybeFormatIsoDuration<'t, U>
+where
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        let d = match self.0 {
+            Some(d) => d,
+            None => return Display::fmt(&self.1, out),
+        };
+        let fraction = out.alternate() && d.subsec_nanos() != 0;
+        pad(out, Alignment::Left, |w: &mut dyn Write| {
+            let total = d.as_secs();
+            let (h, m, s) = (total / 3600, total / 60 % 60, total % 60);
+            w.write_str("PT")?;
+            if h > 0 {
+                write!(w, "{}H", h)?;
+            }
+            if m > 0 {
+                write!(w, "{}M", m)?;
+            }
+            if s > 0 || fraction || total == 0 {
+                write!(w, "{}", s)?;
+                if fraction {
+                    write_nanos_fraction(w, d.subsec_nanos())?;
+                }
+                w.write_char('S')?;
+            }
+            Ok(())
+        })
+    }
+}
+
+impl<'t, U> Display for MaybeFormatDurationUnit<'t, U>
+where
+    U: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        let d = match self.0 {
+            Some(d) => d,
+            None => return Display::fmt(&self.2, out),
+        };
+        let (nanos, symbol) = self.1.nanos_and_symbol();
+        pad(out, Alignment::Left, |w: &mut dyn Write| {
+            write!(w, "{}{}", d.as_nanos() / nanos, symbol)
+        })
+    }
+}
+
+/// Writes a duration as its two largest nonzero units, such as `3m 12s` or `2d 5h`.
+///
+/// Durations under a second are written as `0s`.
+#[cfg(feature = "std")]
+fn write_relative(out: &mut dyn Write, d: Duration) -> Result {
+    const UNITS: [(u64, char); 4] = [(86400, 'd'), (3600, 'h'), (60, 'm'), (1, 's')];
+    let mut rest = d.as_secs();
+    let mut written = 0;
+    for &(size, unit) in UNITS.iter() {
+        let count = rest / size;
+        rest %= size;
+        if count == 0 && !(unit == 's' && written == 0) {
+            if written > 0 {
+                break;
+            }
+            continue;
+        }
+        if written > 0 {
+            out.write_char(' ')?;
+        }
+        write!(out, "{}{}", count, unit)?;
+        written += 1;
+        if written == 2 {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// An extension trait for [`Option<T>`] which reports how long a value has been missing.
+///
+/// Requires the `std` feature.
+#[cfg(feature = "std")]
+#[allow(clippy::needless_lifetimes)] // They're nice to see in docs
+pub trait FmtOrMissingSince<T> {
+    /// Format the value, if there is one, or display `missing for <duration>` instead, where the
+    /// duration is the time from `since` to `now`.
+    ///
+    /// The duration is shown as its two largest units, such as `missing for 3m 12s`. If `now` is
+    /// earlier than `since`, the duration is zero. The returned wrapper implements [`Display`]
+    /// only, and the whole text is padded to the requested width when the value is missing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::{Duration, SystemTime};
+    /// use fmtor::FmtOrMissingSince;
+    ///
+    /// let since = SystemTime::UNIX_EPOCH;
+    /// let now = since + Duration::from_secs(192);
+    /// let price: Option<u32> = None;
+    ///
+    /// assert_eq!("missing for 3m 12s", format!("{}", price.fmt_or_missing_since(since, now)));
+    /// assert_eq!("7", format!("{}", Some(7).fmt_or_missing_since(since, now)));
+    /// ```
+    fn fmt_or_missing_since<'t>(
+        &'t self,
+        since: SystemTime,
+        now: SystemTime,
+    ) -> MaybeFormatMissingSince<'t, T>
+    where
+        T: Display;
+}
+
+#[cfg(feature = "std")]
+impl<T> FmtOrMissingSince<T> for Option<T> {
+    #[inline]
+    fn fmt_or_missing_since(
+        &self,
+        since: SystemTime,
+        now: SystemTime,
+    ) -> MaybeFormatMissingSince<'_, T>
+    where
+        T: Display,
+    {
+        let elapsed = now.duration_since(since).unwrap_or_default();
+        MaybeFormatMissingSince(self, elapsed)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'t, T> Display for MaybeFormatMissingSince<'t, T>
+where
+    T: Display,
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(t) => Display::fmt(t, out),
+            None => pad(out, Alignment::Left, |w: &mut dyn Write| {
+                w.write_str("missing for ")?;
+                write_relative(w, self.1)
+            }),
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use core::time::Duration;
+
+use crate::{DurationUnit, FmtOrDuration};
+
+#[test]
+fn test_iso_duration() {
+    let fmt = |opt: Option<Duration>| format!("{}", opt.fmt_iso_duration_or("n/a"));
+    assert_eq!("PT1H2M3S", fmt(Some(Duration::from_secs(3723))));
+    assert_eq!("PT2H", fmt(Some(Duration::from_secs(7200))));
+    assert_eq!("PT1H5S", fmt(Some(Duration::from_secs(3605))));
+    assert_eq!("PT50H", fmt(Some(Duration::from_secs(50 * 3600))));
+    assert_eq!("PT0S", fmt(Some(Duration::from_secs(0))));
+    assert_eq!("PT0S", fmt(Some(Duration::from_millis(250))));
+    assert_eq!("n/a", fmt(None));
+}
+
+#[test]
+fn test_iso_duration_fraction() {
+    let fmt = |opt: Option<Duration>| format!("{:#}", opt.fmt_iso_duration_or("n/a"));
+    assert_eq!("PT0.25S", fmt(Some(Duration::from_millis(250))));
+    assert_eq!("PT0.000000001S", fmt(Some(Duration::from_nanos(1))));
+    assert_eq!("PT1M0.5S", fmt(Some(Duration::from_millis(60_500))));
+    assert_eq!("PT1M", fmt(Some(Duration::from_secs(60))));
+    assert_eq!("PT0S", fmt(Some(Duration::from_secs(0))));
+    assert_eq!("n/a", fmt(None));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_missing_since() {
+    use crate::FmtOrMissingSince;
+    use std::time::SystemTime;
+
+    let since = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+    let fmt = |opt: Option<u32>, secs| {
+        let now = since + Duration::from_secs(secs);
+        format!("{}", opt.fmt_or_missing_since(since, now))
+    };
+    assert_eq!("missing for 45s", fmt(None, 45));
+    assert_eq!("missing for 3m 12s", fmt(None, 192));
+    assert_eq!("missing for 2h", fmt(None, 7200 + 30));
+    assert_eq!("missing for 1d 1h", fmt(None, 90000));
+    assert_eq!("missing for 0s", fmt(None, 0));
+    assert_eq!("42", fmt(Some(42), 192));
+
+    let backwards = Some(7).fmt_or_missing_since(since, SystemTime::UNIX_EPOCH);
+    assert_eq!("7", format!("{}", backwards));
+    let backwards = None::<u8>.fmt_or_missing_since(since, SystemTime::UNIX_EPOCH);
+    assert_eq!("missing for 0s   ", format!("{:17}", backwards));
+}
+
+#[test]
+fn test_duration_unit() {
+    let some = Some(Duration::from_millis(1500));
+    let fmt = |opt: Option<Duration>, unit| format!("{}", opt.fmt_duration_or(unit, "n/a"));
+    assert_eq!("1500000000ns", fmt(some, DurationUnit::Nanos));
+    assert_eq!("1500000µs", fmt(some, DurationUnit::Micros));
+    assert_eq!("1500ms", fmt(some, DurationUnit::Millis));
+    assert_eq!("1s", fmt(some, DurationUnit::Secs));
+    assert_eq!("0m", fmt(some, DurationUnit::Mins));
+    assert_eq!(
+        "2h",
+        fmt(Some(Duration::from_secs(9000)), DurationUnit::Hours)
+    );
+    assert_eq!("n/a", fmt(None, DurationUnit::Millis));
+}
+
+#[test]
+fn test_duration_unit_width() {
+    let some = Some(Duration::from_millis(1500));
+    let none = None::<Duration>;
+    assert_eq!(
+        "  1500ms",
+        format!("{:>8}", some.fmt_duration_or(DurationUnit::Millis, "-"))
+    );
+    // The symbol µ is two bytes, but pads as one char.
+    let micro = Some(Duration::from_micros(1));
+    assert_eq!(
+        "  1µs   ",
+        format!("{:^8}", micro.fmt_duration_or(DurationUnit::Micros, "-"))
+    );
+    assert_eq!(
+        "-       ",
+        format!("{:8}", none.fmt_duration_or(DurationUnit::Millis, "-"))
+    );
+}
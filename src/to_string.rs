@@ -0,0 +1,90 @@
+//! Rendering options straight to a [`String`].
+
+#[cfg(test)]
+mod tests;
+
+use alloc::string::String;
+use core::fmt::{Display, Write};
+
+use crate::FmtOr;
+
+/// An extension trait for rendering [`Option<T>`] to a [`String`], where `T` implements [`Display`].
+///
+/// This is `opt.fmt_or(fallback).to_string()` without needing `std` or the [`ToString`] trait in
+/// scope.
+///
+/// [`ToString`]: alloc::string::ToString
+pub trait ToStringOr {
+    /// Render the value, if there is one, or the given fallback instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::ToStringOr;
+    ///
+    /// assert_eq!("42", Some(42).to_string_or("unset"));
+    /// assert_eq!("unset", None::<u32>.to_string_or("unset"));
+    /// ```
+    fn to_string_or(&self, fallback: &str) -> String;
+    /// Render the value, if there is one, or run the closure and render its result instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::ToStringOr;
+    ///
+    /// let retries = 3;
+    ///
+    /// assert_eq!("gave up after 3", None::<u32>.to_string_or_else(|| format!("gave up after {}", retries)));
+    /// ```
+    fn to_string_or_else<U, F>(&self, f: F) -> String
+    where
+        U: Display,
+        F: FnOnce() -> U;
+    /// Display the value, if there is one, or the given string into a new [`String`].
+    ///
+    /// This is the same as `format!("{}", opt.fmt_or(fallback))`, for when the string itself is
+    /// wanted rather than a value to format. It is [`to_string_or`](ToStringOr::to_string_or)
+    /// under the name of [`fmt_or`](crate::FmtOr::fmt_or).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::ToStringOr;
+    ///
+    /// let foo = Some(42);
+    /// let bar: Option<u32> = None;
+    ///
+    /// assert_eq!("42", foo.fmt_or_string("none"));
+    /// assert_eq!("none", bar.fmt_or_string("none"));
+    /// ```
+    fn fmt_or_string(&self, fallback: &str) -> String;
+}
+
+impl<T: Display> ToStringOr for Option<T> {
+    #[inline]
+    fn to_string_or(&self, fallback: &str) -> String {
+        render(self.fmt_or(fallback))
+    }
+    #[inline]
+    fn to_string_or_else<U, F>(&self, f: F) -> String
+    where
+        U: Display,
+        F: FnOnce() -> U,
+    {
+        match self {
+            Some(t) => render(t),
+            None => render(f()),
+        }
+    }
+    #[inline]
+    fn fmt_or_string(&self, fallback: &str) -> String {
+        render(self.fmt_or(fallback))
+    }
+}
+
+fn render<D: Display>(d: D) -> String {
+    let mut s = String::new();
+    write!(s, "{}", d).expect("a Display implementation returned an error");
+    s
+}
@@ -0,0 +1,27 @@
+use crate::ToStringOr;
+
+#[test]
+fn test_to_string_or() {
+    assert_eq!("42", Some(42).to_string_or("-"));
+    assert_eq!("-", None::<u32>.to_string_or("-"));
+    assert_eq!("", None::<u32>.to_string_or(""));
+    assert_eq!("a", Some('a').to_string_or("-"));
+}
+
+#[test]
+fn test_to_string_or_else() {
+    let owned = String::from("moved");
+    assert_eq!("moved", None::<u32>.to_string_or_else(move || owned));
+    assert_eq!(
+        "7",
+        Some(7).to_string_or_else(|| -> &str { panic!("the value is present") })
+    );
+}
+
+#[test]
+fn test_fmt_or_string() {
+    assert_eq!("42", Some(42).fmt_or_string("none"));
+    assert_eq!("none", None::<u32>.fmt_or_string("none"));
+    assert_eq!("", None::<u32>.fmt_or_string(""));
+    assert_eq!("a", Some('a').fmt_or_string("none"));
+}
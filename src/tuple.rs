@@ -0,0 +1,84 @@
+//! Adapters for tuples of options.
+
+#[cfg(test)]
+mod tests;
+
+use core::fmt::{Alignment, Display, Formatter, Result, Write};
+
+use crate::pad::pad;
+
+/// The type returned from [`FmtOrTuple::fmt_or_tuple`]
+#[must_use = "wrappers do nothing unless formatted"]
+pub struct MaybeFormatTuple<'t, O, U>(&'t O, U, &'t str);
+
+/// An extension trait for tuples of options, such as `(Option<A>, Option<B>)`.
+///
+/// This is implemented for tuples of two and three options.
+#[allow(clippy::needless_lifetimes)] // They're nice to see in docs
+pub trait FmtOrTuple: Sized {
+    /// Format each value, or display its own fallback instead, with `sep` between the elements.
+    ///
+    /// `fallbacks` is a tuple of the same length, holding the fallback of each element. Every
+    /// element and fallback is formatted with [`Display`], and the whole output is padded to the
+    /// requested width as a single piece of text.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fmtor::FmtOrTuple;
+    ///
+    /// let user: Option<&str> = Some("alice");
+    /// let pid: Option<u32> = None;
+    ///
+    /// assert_eq!("alice, -", format!("{}", (user, pid).fmt_or_tuple(("?", "-"), ", ")));
+    /// assert_eq!("alice/-/0", format!("{}", (user, pid, Some(0)).fmt_or_tuple(("?", "-", "-"), "/")));
+    /// ```
+    fn fmt_or_tuple<'t, U>(&'t self, fallbacks: U, sep: &'t str) -> MaybeFormatTuple<'t, Self, U>;
+}
+
+impl<'t, O, U: Copy> Copy for MaybeFormatTuple<'t, O, U> {}
+impl<'t, O, U: Clone> Clone for MaybeFormatTuple<'t, O, U> {
+    fn clone(&self) -> Self {
+        Self(self.0, self.1.clone(), self.2)
+    }
+}
+
+macro_rules! impl_tuple {
+    ($(($T0:ident $U0:ident $i0:tt $(, $T:ident $U:ident $i:tt)*)),*$(,)?) => {$(
+
+impl<$T0 $(, $T)*> FmtOrTuple for (Option<$T0>, $(Option<$T>),*) {
+    #[inline]
+    fn fmt_or_tuple<'t, U>(&'t self, fallbacks: U, sep: &'t str) -> MaybeFormatTuple<'t, Self, U> {
+        MaybeFormatTuple(self, fallbacks, sep)
+    }
+}
+
+impl<'t, $T0, $U0 $(, $T, $U)*> Display
+    for MaybeFormatTuple<'t, (Option<$T0>, $(Option<$T>),*), ($U0, $($U),*)>
+where
+    $T0: Display,
+    $U0: Display,
+    $($T: Display, $U: Display,)*
+{
+    fn fmt(&self, out: &mut Formatter<'_>) -> Result {
+        pad(out, Alignment::Left, |w: &mut dyn Write| {
+            match &(self.0).$i0 {
+                Some(t) => write!(w, "{}", t)?,
+                None => write!(w, "{}", (self.1).$i0)?,
+            }
+            $(
+                w.write_str(self.2)?;
+                match &(self.0).$i {
+                    Some(t) => write!(w, "{}", t)?,
+                    None => write!(w, "{}", (self.1).$i)?,
+                }
+            )*
+            Ok(())
+        })
+    }
+}
+
+    )*}
+} // macro_rules! impl_tuple
+
+impl_tuple!((A UA 0, B UB 1), (A UA 0, B UB 1, C UC 2));
@@ -0,0 +1,36 @@
+use crate::FmtOrTuple;
+
+#[test]
+fn test_pair() {
+    let pair = (Some("alice"), None::<u32>);
+    assert_eq!(
+        "alice, -",
+        format!("{}", pair.fmt_or_tuple(("?", "-"), ", "))
+    );
+    assert_eq!(
+        "alice, -  ",
+        format!("{:10}", pair.fmt_or_tuple(("?", "-"), ", "))
+    );
+    let pair = (None::<&str>, Some(7));
+    assert_eq!("?|7", format!("{}", pair.fmt_or_tuple(("?", "-"), "|")));
+    assert_eq!(
+        "n/a n/a",
+        format!(
+            "{}",
+            (None::<u8>, None::<u8>).fmt_or_tuple(("n/a", "n/a"), " ")
+        )
+    );
+}
+
+#[test]
+fn test_triple() {
+    let triple = (Some(1), None::<char>, Some("three"));
+    assert_eq!(
+        "[1; ?; three]",
+        format!("[{}]", triple.fmt_or_tuple(("-", '?', "-"), "; "))
+    );
+    assert_eq!(
+        "**1,?,three**",
+        format!("{:*^13}", triple.fmt_or_tuple((0, "?", 0), ","))
+    );
+}
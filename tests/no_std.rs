@@ -0,0 +1,31 @@
+//! Checks the public API without the standard library prelude.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::format;
+use fmtor::FmtOr;
+
+#[test]
+fn fmt_or() {
+    assert_eq!("2a", format!("{:x}", Some(42).fmt_or("-")));
+    assert_eq!("  -", format!("{:>3x}", None::<u32>.fmt_or("-")));
+}
+
+#[test]
+fn fmt_or_empty() {
+    assert_eq!("0b11", format!("{:#b}", Some(3).fmt_or_empty()));
+    assert_eq!("", format!("{:#b}", None::<u32>.fmt_or_empty()));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn alloc_helpers() {
+    use fmtor::ToStringOr;
+
+    assert_eq!("5", Some(5).to_string_or("unset"));
+    assert_eq!("unset", None::<u32>.to_string_or("unset"));
+    assert_eq!("lazy", None::<u32>.to_string_or_else(|| "lazy"));
+    assert_eq!("unset", None::<u32>.fmt_or_string("unset"));
+}